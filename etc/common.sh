@@ -34,7 +34,9 @@ export COMPONENTS="
     unic/ucd/block
     unic/ucd/case
     unic/ucd/common
+    unic/ucd/east_asian_width
     unic/ucd/ident
+    unic/ucd/joining
     unic/ucd/name
     unic/ucd/name_aliases
     unic/ucd/normal
@@ -0,0 +1,67 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::char;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use regex::Regex;
+
+use crate::source::utils::read;
+
+lazy_static! {
+    pub static ref BIDI_BRACKETS: BidiBrackets = {
+        read("external/unicode/ucd/data/BidiBrackets.txt")
+            .parse()
+            .unwrap()
+    };
+}
+
+pub struct BidiBrackets {
+    /// `Bidi_Paired_Bracket` of each paired bracket.
+    pub paired_bracket: BTreeMap<char, char>,
+
+    /// `Bidi_Paired_Bracket_Type` of each paired bracket, as abbreviated name.
+    pub paired_bracket_type: BTreeMap<char, String>,
+}
+
+impl FromStr for BidiBrackets {
+    type Err = ();
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref REGEX: Regex = Regex::new(
+                r"(?xm)^
+                  ([[:xdigit:]]{4,6}) # codepoint
+                  ;\s*
+                  ([[:xdigit:]]{4,6}) # paired bracket
+                  ;\s*
+                  ([oc])              # paired bracket type
+                ",
+            )
+            .unwrap();
+        }
+
+        let mut paired_bracket = BTreeMap::default();
+        let mut paired_bracket_type = BTreeMap::default();
+        for capture in REGEX.captures_iter(str) {
+            let chr = char::from_u32(u32::from_str_radix(&capture[1], 16).unwrap()).unwrap();
+            let pair = char::from_u32(u32::from_str_radix(&capture[2], 16).unwrap()).unwrap();
+            paired_bracket.insert(chr, pair);
+            paired_bracket_type.insert(chr, capture[3].to_owned());
+        }
+
+        Ok(BidiBrackets {
+            paired_bracket,
+            paired_bracket_type,
+        })
+    }
+}
+
@@ -0,0 +1,56 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::char;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use regex::Regex;
+
+use crate::source::utils::read;
+
+lazy_static! {
+    pub static ref BIDI_MIRRORING: BidiMirroring = {
+        read("external/unicode/ucd/data/BidiMirroring.txt")
+            .parse()
+            .unwrap()
+    };
+}
+
+pub struct BidiMirroring {
+    /// `Bidi_Mirroring_Glyph` of each character that has one.
+    pub map: BTreeMap<char, char>,
+}
+
+impl FromStr for BidiMirroring {
+    type Err = ();
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref REGEX: Regex = Regex::new(
+                r"(?xm)^
+                  ([[:xdigit:]]{4,6}) # codepoint
+                  ;\s*
+                  ([[:xdigit:]]{4,6}) # mirroring glyph
+                ",
+            )
+            .unwrap();
+        }
+
+        let mut map = BTreeMap::default();
+        for capture in REGEX.captures_iter(str) {
+            let chr = char::from_u32(u32::from_str_radix(&capture[1], 16).unwrap()).unwrap();
+            let glyph = char::from_u32(u32::from_str_radix(&capture[2], 16).unwrap()).unwrap();
+            map.insert(chr, glyph);
+        }
+
+        Ok(BidiMirroring { map })
+    }
+}
@@ -9,7 +9,7 @@
 // except according to those terms.
 
 use std::char;
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::str::FromStr;
 
 use regex::Regex;
@@ -17,18 +17,38 @@ use regex::Regex;
 use crate::source::utils::read;
 
 lazy_static! {
-    pub static ref COMPOSITION_EXCLUSIONS: CompositionExclusions = {
+    pub static ref FULL_COMPOSITION_EXCLUSIONS: FullCompositionExclusions = {
+        read("external/unicode/ucd/data/DerivedNormalizationProps.txt")
+            .parse()
+            .unwrap()
+    };
+    pub static ref NFKC_CASEFOLD: NFKCCasefold = {
+        read("external/unicode/ucd/data/DerivedNormalizationProps.txt")
+            .parse()
+            .unwrap()
+    };
+    pub static ref NFC_QUICK_CHECK: NFCQuickCheck = {
+        read("external/unicode/ucd/data/DerivedNormalizationProps.txt")
+            .parse()
+            .unwrap()
+    };
+    pub static ref EXPANDS_ON: ExpandsOn = {
+        read("external/unicode/ucd/data/DerivedNormalizationProps.txt")
+            .parse()
+            .unwrap()
+    };
+    pub static ref CHANGES_WHEN_NFKC_CASEFOLDED: ChangesWhenNFKCCasefolded = {
         read("external/unicode/ucd/data/DerivedNormalizationProps.txt")
             .parse()
             .unwrap()
     };
 }
 
-pub struct CompositionExclusions {
+pub struct FullCompositionExclusions {
     pub set: BTreeSet<char>,
 }
 
-impl FromStr for CompositionExclusions {
+impl FromStr for FullCompositionExclusions {
     type Err = ();
 
     fn from_str(str: &str) -> Result<Self, Self::Err> {
@@ -57,6 +77,179 @@ impl FromStr for CompositionExclusions {
             }
         }
 
-        Ok(CompositionExclusions { set: exclusions })
+        Ok(FullCompositionExclusions { set: exclusions })
+    }
+}
+
+pub struct NFKCCasefold {
+    pub map: BTreeMap<char, Box<[char]>>,
+}
+
+impl FromStr for NFKCCasefold {
+    type Err = ();
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref REGEX: Regex = Regex::new(
+                r"(?xm)^
+                  ([[:xdigit:]]{4,6})
+                  (?:\.\.([[:xdigit:]]{4,6}))?
+                  [[:space:]]*;
+                  \x20NFKC_CF;
+                  [[:space:]]*
+                  ([[:xdigit:]]{4,6}(?:\x20[[:xdigit:]]{4,6})*)?
+                  [[:space:]]*
+                \#",
+            )
+            .unwrap();
+        }
+
+        let mut map = BTreeMap::default();
+        for capture in REGEX.captures_iter(str) {
+            let low = u32::from_str_radix(&capture[1], 16).unwrap();
+            let high = capture
+                .get(2)
+                .map_or(low, |m| u32::from_str_radix(m.as_str(), 16).unwrap());
+            let mapping: Box<[char]> = capture.get(3).map_or_else(
+                || Box::new([]) as Box<[char]>,
+                |m| {
+                    m.as_str()
+                        .split(' ')
+                        .map(|s| char::from_u32(u32::from_str_radix(s, 16).unwrap()).unwrap())
+                        .collect()
+                },
+            );
+            for point in low..(high + 1) {
+                if let Some(char) = char::from_u32(point) {
+                    map.insert(char, mapping.clone());
+                }
+            }
+        }
+
+        Ok(NFKCCasefold { map })
+    }
+}
+
+#[derive(Default)]
+pub struct ExpandsOn {
+    pub nfd: BTreeSet<char>,
+    pub nfc: BTreeSet<char>,
+    pub nfkd: BTreeSet<char>,
+    pub nfkc: BTreeSet<char>,
+}
+
+impl FromStr for ExpandsOn {
+    type Err = ();
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref REGEX: Regex = Regex::new(
+                r"(?xm)^
+                  ([[:xdigit:]]{4,6})
+                  (?:\.\.([[:xdigit:]]{4,6}))?
+                  [[:space:]]*;
+                  \x20Expands_On_(NFD|NFC|NFKD|NFKC)\x20
+                \#",
+            )
+            .unwrap();
+        }
+
+        let mut expands_on = ExpandsOn::default();
+        for capture in REGEX.captures_iter(str) {
+            let low = u32::from_str_radix(&capture[1], 16).unwrap();
+            let high = capture
+                .get(2)
+                .map_or(low, |m| u32::from_str_radix(m.as_str(), 16).unwrap());
+            let set = match &capture[3] {
+                "NFD" => &mut expands_on.nfd,
+                "NFC" => &mut expands_on.nfc,
+                "NFKD" => &mut expands_on.nfkd,
+                "NFKC" => &mut expands_on.nfkc,
+                _ => unreachable!(),
+            };
+            for point in low..(high + 1) {
+                if let Some(char) = char::from_u32(point) {
+                    set.insert(char);
+                }
+            }
+        }
+
+        Ok(expands_on)
+    }
+}
+
+pub struct NFCQuickCheck {
+    pub map: BTreeMap<char, String>,
+}
+
+impl FromStr for NFCQuickCheck {
+    type Err = ();
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref REGEX: Regex = Regex::new(
+                r"(?xm)^
+                  ([[:xdigit:]]{4,6})
+                  (?:\.\.([[:xdigit:]]{4,6}))?
+                  [[:space:]]*;
+                  \x20NFC_QC;
+                  \x20([NM])
+                  [[:space:]]*
+                \#",
+            )
+            .unwrap();
+        }
+
+        let mut map = BTreeMap::default();
+        for capture in REGEX.captures_iter(str) {
+            let low = u32::from_str_radix(&capture[1], 16).unwrap();
+            let high = capture
+                .get(2)
+                .map_or(low, |m| u32::from_str_radix(m.as_str(), 16).unwrap());
+            for point in low..(high + 1) {
+                if let Some(char) = char::from_u32(point) {
+                    map.insert(char, capture[3].to_owned());
+                }
+            }
+        }
+
+        Ok(NFCQuickCheck { map })
+    }
+}
+
+pub struct ChangesWhenNFKCCasefolded {
+    pub set: BTreeSet<char>,
+}
+
+impl FromStr for ChangesWhenNFKCCasefolded {
+    type Err = ();
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref REGEX: Regex = Regex::new(
+                r"(?xm)^
+                  ([[:xdigit:]]{4,6})
+                  (?:\.\.([[:xdigit:]]{4,6}))?
+                  [[:space:]]*;
+                  \x20Changes_When_NFKC_Casefolded\x20
+                \#",
+            )
+            .unwrap();
+        }
+
+        let mut set = BTreeSet::default();
+        for capture in REGEX.captures_iter(str) {
+            let low = u32::from_str_radix(&capture[1], 16).unwrap();
+            let high = capture
+                .get(2)
+                .map_or(low, |m| u32::from_str_radix(m.as_str(), 16).unwrap());
+            for point in low..(high + 1) {
+                if let Some(char) = char::from_u32(point) {
+                    set.insert(char);
+                }
+            }
+        }
+
+        Ok(ChangesWhenNFKCCasefolded { set })
     }
 }
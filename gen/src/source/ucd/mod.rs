@@ -10,12 +10,18 @@
 
 pub mod test;
 
+pub mod bidi_brackets;
+pub mod bidi_mirroring;
 pub mod blocks;
+pub mod composition_exclusions;
 pub mod derived_age;
 pub mod derived_core_properties;
+pub mod derived_joining_type;
 pub mod derived_normalization_props;
+pub mod east_asian_width;
 pub mod grapheme_break_property;
 pub mod jamo;
+pub mod line_break;
 pub mod name_aliases;
 pub mod prop_list;
 pub mod readme;
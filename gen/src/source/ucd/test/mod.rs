@@ -9,5 +9,6 @@
 // except according to those terms.
 
 pub mod grapheme_break_test;
+pub mod line_break_test;
 pub mod normalization_test;
 pub mod word_break_test;
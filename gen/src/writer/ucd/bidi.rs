@@ -13,19 +13,24 @@ use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Display;
 use std::path::Path;
 
+use crate::source::ucd::bidi_brackets::BIDI_BRACKETS;
+use crate::source::ucd::bidi_mirroring::BIDI_MIRRORING;
 use crate::source::ucd::prop_list::PROP_LIST;
 use crate::source::ucd::readme::UNICODE_VERSION;
 use crate::source::ucd::unicode_data::UNICODE_DATA;
 
 use crate::writer::common::emit_unicode_version;
-use crate::writer::utils::tables::{ToRangeCharSet, ToRangeCharTable};
+use crate::writer::utils::tables::{ToDirectCharTable, ToRangeCharSet, ToRangeCharTable};
 use crate::writer::utils::write;
 
 pub fn generate(dir: &Path) {
     emit_unicode_version(dir, &UNICODE_VERSION);
     emit_bidi_class(dir);
     emit_bidi_mirrored(dir);
+    emit_bidi_mirroring_glyph(dir);
     emit_bidi_control(dir);
+    emit_bidi_paired_bracket(dir);
+    emit_bidi_paired_bracket_type(dir);
 }
 
 // Default `Bidi_Class` for unassigned codepoints.
@@ -80,6 +85,16 @@ fn emit_bidi_mirrored(dir: &Path) {
     write(dir, "bidi_mirrored.rsv", &set.to_range_char_set());
 }
 
+fn emit_bidi_mirroring_glyph(dir: &Path) {
+    write(
+        dir,
+        "bidi_mirroring_glyph.rsv",
+        &BIDI_MIRRORING
+            .map
+            .to_direct_char_table(|val, f| write!(f, "'{}'", val.escape_unicode())),
+    );
+}
+
 fn emit_bidi_control(dir: &Path) {
     write(
         dir,
@@ -87,3 +102,23 @@ fn emit_bidi_control(dir: &Path) {
         &PROP_LIST.bidi_control.to_range_char_set(),
     );
 }
+
+fn emit_bidi_paired_bracket(dir: &Path) {
+    write(
+        dir,
+        "bidi_paired_bracket.rsv",
+        &BIDI_BRACKETS
+            .paired_bracket
+            .to_direct_char_table(|val, f| write!(f, "'{}'", val.escape_unicode())),
+    );
+}
+
+fn emit_bidi_paired_bracket_type(dir: &Path) {
+    write(
+        dir,
+        "bidi_paired_bracket_type.rsv",
+        &BIDI_BRACKETS
+            .paired_bracket_type
+            .to_range_char_table(|val, f| write!(f, "BPT::{}", val)),
+    );
+}
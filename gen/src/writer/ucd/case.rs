@@ -11,6 +11,7 @@
 use std::path::Path;
 
 use crate::source::ucd::derived_core_properties::DERIVED_CORE_PROPERTIES;
+use crate::source::ucd::derived_normalization_props::CHANGES_WHEN_NFKC_CASEFOLDED;
 use crate::source::ucd::readme::UNICODE_VERSION;
 
 use crate::writer::common::emit_unicode_version;
@@ -28,6 +29,7 @@ pub fn generate(dir: &Path) {
     emit_changes_when_titlecased(dir);
     emit_changes_when_casefolded(dir);
     emit_changes_when_casemapped(dir);
+    emit_changes_when_nfkc_casefolded(dir);
 }
 
 fn emit_lowercase(dir: &Path) {
@@ -111,3 +113,11 @@ fn emit_changes_when_casemapped(dir: &Path) {
             .to_range_char_set(),
     );
 }
+
+fn emit_changes_when_nfkc_casefolded(dir: &Path) {
+    write(
+        dir,
+        "changes_when_nfkc_casefolded.rsv",
+        &CHANGES_WHEN_NFKC_CASEFOLDED.set.to_range_char_set(),
+    );
+}
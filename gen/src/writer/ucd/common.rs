@@ -24,6 +24,7 @@ pub fn generate(dir: &Path) {
     emit_unicode_version(dir, &UNICODE_VERSION);
     emit_alphabetic(dir);
     emit_white_space(dir);
+    emit_ideographic(dir);
     emit_alphanumeric(dir);
     emit_control(dir);
     emit_numeric(dir);
@@ -45,6 +46,14 @@ fn emit_white_space(dir: &Path) {
     );
 }
 
+fn emit_ideographic(dir: &Path) {
+    write(
+        dir,
+        "ideographic.rsv",
+        &PROP_LIST.ideographic.to_range_char_set(),
+    );
+}
+
 fn emit_alphanumeric(dir: &Path) {
     write(
         dir,
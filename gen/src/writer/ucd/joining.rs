@@ -0,0 +1,32 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt::Display;
+use std::path::Path;
+
+use crate::source::ucd::derived_joining_type::DERIVED_JOINING_TYPE_DATA;
+use crate::source::ucd::readme::UNICODE_VERSION;
+
+use crate::writer::common::emit_unicode_version;
+use crate::writer::utils::tables::ToRangeCharTable;
+use crate::writer::utils::write;
+
+pub fn generate(dir: &Path) {
+    emit_unicode_version(dir, &UNICODE_VERSION);
+    emit_joining_type(dir);
+}
+
+fn emit_joining_type(dir: &Path) {
+    write(
+        dir,
+        "joining_type.rsv",
+        &DERIVED_JOINING_TYPE_DATA.map.to_range_char_table(Display::fmt),
+    );
+}
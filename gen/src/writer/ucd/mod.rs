@@ -15,8 +15,10 @@ mod case;
 mod category;
 mod common;
 mod core;
+mod east_asian_width;
 mod hangul;
 mod ident;
+mod joining;
 mod name;
 mod name_aliases;
 mod normal;
@@ -33,8 +35,10 @@ pub fn generate() {
     category::generate(&clean_dir("unic/ucd/category/tables"));
     common::generate(&clean_dir("unic/ucd/common/tables"));
     core::generate(&clean_dir("unic/ucd/version/tables"));
+    east_asian_width::generate(&clean_dir("unic/ucd/east_asian_width/tables"));
     hangul::generate(&clean_dir("unic/ucd/hangul/tables"));
     ident::generate(&clean_dir("unic/ucd/ident/tables"));
+    joining::generate(&clean_dir("unic/ucd/joining/tables"));
     name::generate(&clean_dir("unic/ucd/name/tables"));
     name_aliases::generate(&clean_dir("unic/ucd/name_aliases/tables"));
     normal::generate(&clean_dir("unic/ucd/normal/tables"));
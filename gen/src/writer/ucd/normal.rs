@@ -12,7 +12,13 @@ use std::char;
 use std::collections::{BTreeMap, BTreeSet};
 use std::path::Path;
 
-use crate::source::ucd::derived_normalization_props::COMPOSITION_EXCLUSIONS;
+use crate::source::ucd::composition_exclusions::COMPOSITION_EXCLUSIONS;
+use crate::source::ucd::derived_normalization_props::{
+    EXPANDS_ON,
+    FULL_COMPOSITION_EXCLUSIONS,
+    NFC_QUICK_CHECK,
+    NFKC_CASEFOLD,
+};
 use crate::source::ucd::readme::UNICODE_VERSION;
 use crate::source::ucd::unicode_data::UNICODE_DATA;
 
@@ -27,6 +33,11 @@ pub fn generate(dir: &Path) {
     emit_canonical_decomposition_mapping(dir);
     emit_canonical_composition_mapping(dir);
     emit_compatibility_decomposition_mapping(dir);
+    emit_nfkc_casefold_mapping(dir);
+    emit_nfc_quick_check(dir);
+    emit_composition_exclusion(dir);
+    emit_full_composition_exclusion(dir);
+    emit_expands_on(dir);
 }
 
 fn emit_general_category_mark(dir: &Path) {
@@ -91,7 +102,7 @@ fn emit_canonical_composition_mapping(dir: &Path) {
             continue;
         }
         assert_eq!(decomposed.len(), 2);
-        if COMPOSITION_EXCLUSIONS.set.contains(&composed) {
+        if FULL_COMPOSITION_EXCLUSIONS.set.contains(&composed) {
             continue;
         }
         let lead = decomposed[0];
@@ -149,3 +160,66 @@ fn emit_compatibility_decomposition_mapping(dir: &Path) {
         }),
     );
 }
+
+fn emit_nfkc_casefold_mapping(dir: &Path) {
+    write(
+        dir,
+        "nfkc_casefold_mapping.rsv",
+        &NFKC_CASEFOLD.map.to_range_char_table(|val, f| {
+            write!(f, "&[")?;
+            for char in val.iter() {
+                write!(f, "'{}',", char.escape_unicode())?;
+            }
+            write!(f, "]")
+        }),
+    );
+}
+
+fn emit_nfc_quick_check(dir: &Path) {
+    write(
+        dir,
+        "nfc_quick_check.rsv",
+        &NFC_QUICK_CHECK
+            .map
+            .to_range_char_table(|val, f| write!(f, "NFC_QC::{}", val)),
+    );
+}
+
+fn emit_composition_exclusion(dir: &Path) {
+    write(
+        dir,
+        "composition_exclusion.rsv",
+        &COMPOSITION_EXCLUSIONS.set.to_range_char_set(),
+    );
+}
+
+fn emit_full_composition_exclusion(dir: &Path) {
+    write(
+        dir,
+        "full_composition_exclusion.rsv",
+        &FULL_COMPOSITION_EXCLUSIONS.set.to_range_char_set(),
+    );
+}
+
+fn emit_expands_on(dir: &Path) {
+    write(
+        dir,
+        "expands_on_nfd.rsv",
+        &EXPANDS_ON.nfd.to_range_char_set(),
+    );
+    write(
+        dir,
+        "expands_on_nfc.rsv",
+        &EXPANDS_ON.nfc.to_range_char_set(),
+    );
+    write(
+        dir,
+        "expands_on_nfkd.rsv",
+        &EXPANDS_ON.nfkd.to_range_char_set(),
+    );
+    write(
+        dir,
+        "expands_on_nfkc.rsv",
+        &EXPANDS_ON.nfkc.to_range_char_set(),
+    );
+}
@@ -8,9 +8,11 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::fmt::Display;
 use std::path::Path;
 
 use crate::source::ucd::grapheme_break_property::GRAPHEME_CLUSTER_BREAK_DATA;
+use crate::source::ucd::line_break::LINE_BREAK_DATA;
 use crate::source::ucd::readme::UNICODE_VERSION;
 use crate::source::ucd::sentence_break_property::SENTENCE_BREAK_DATA;
 use crate::source::ucd::word_break_property::WORD_BREAK_DATA;
@@ -24,6 +26,7 @@ pub fn generate(dir: &Path) {
     emit_grapheme_cluster_break(dir);
     emit_word_break(dir);
     emit_sentence_break(dir);
+    emit_line_break(dir);
 }
 
 fn emit_grapheme_cluster_break(dir: &Path) {
@@ -55,3 +58,11 @@ fn emit_sentence_break(dir: &Path) {
             .to_range_char_table(|v, f| write!(f, "SB::{}", v)),
     );
 }
+
+fn emit_line_break(dir: &Path) {
+    write(
+        dir,
+        "line_break.rsv",
+        &LINE_BREAK_DATA.map.to_range_char_table(Display::fmt),
+    );
+}
@@ -16,7 +16,7 @@ extern crate test;
 
 use test::Bencher;
 
-use unic_bidi::BidiInfo;
+use unic_bidi::{BidiInfo, LtrOrBidiInfo};
 
 const LTR_TEXTS: &[&str] = &["abc\ndef\nghi", "abc 123\ndef 456\nghi 789"];
 
@@ -30,6 +30,14 @@ fn bench_bidi_info_new(b: &mut Bencher, texts: &[&str]) {
     }
 }
 
+fn bench_ltr_or_bidi_info_new(b: &mut Bencher, texts: &[&str]) {
+    for text in texts {
+        b.iter(|| {
+            LtrOrBidiInfo::new(text, None);
+        });
+    }
+}
+
 fn bench_reorder_line(b: &mut Bencher, texts: &[&str]) {
     for text in texts {
         let bidi_info = BidiInfo::new(text, None);
@@ -61,3 +69,13 @@ fn bench_3_reorder_line_for_ltr_texts(b: &mut Bencher) {
 fn bench_4_reorder_line_for_bidi_texts(b: &mut Bencher) {
     bench_reorder_line(b, BIDI_TEXTS);
 }
+
+#[bench]
+fn bench_5_ltr_or_bidi_info_new_for_ltr_texts(b: &mut Bencher) {
+    bench_ltr_or_bidi_info_new(b, LTR_TEXTS);
+}
+
+#[bench]
+fn bench_6_ltr_or_bidi_info_new_for_bidi_texts(b: &mut Bencher) {
+    bench_ltr_or_bidi_info_new(b, BIDI_TEXTS);
+}
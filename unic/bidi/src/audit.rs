@@ -0,0 +1,433 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Auditing text, and in particular source code, for uses of bidirectional control characters
+//! that make it display differently from how it is read by a machine, as in the *Trojan Source*
+//! attacks (CVE-2021-42574).
+//!
+//! <https://www.unicode.org/reports/tr36/>
+
+use std::ops::Range;
+
+use unic_ucd_bidi::bidi_class::abbr_names::*;
+use unic_ucd_bidi::{is_bidi_control, BidiClass};
+
+use crate::bidi_info::BidiInfo;
+use crate::isolate::for_each_unbalanced_formatting;
+use crate::level::Level;
+
+/// Kind of an `AuditFinding`
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum AuditKind {
+    /// An embedding, override or isolate that is not terminated by the end of its line, and so
+    /// affects the display of the rest of the line.
+    Unterminated,
+
+    /// A bidirectional control character inside a string literal.
+    ControlInString,
+
+    /// A bidirectional control character inside a comment.
+    ControlInComment,
+
+    /// A bidirectional control character in code, outside string literals and comments.
+    ControlInCode,
+
+    /// A part of a line displayed in a different order than its logical order.
+    ///
+    /// This is expected of right-to-left text, and suspicious if the part has bidirectional
+    /// control characters.
+    Reordered,
+}
+
+/// A dangerous or suspicious use of bidirectional text found by `audit()` or `audit_source()`
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct AuditFinding {
+    /// What was found.
+    pub kind: AuditKind,
+
+    /// The part of the text the finding is about, as byte indices.
+    pub range: Range<usize>,
+
+    /// The `BidiClass` of each `Bidi_Control` character in the range, in order.
+    pub controls: Vec<BidiClass>,
+}
+
+impl AuditFinding {
+    fn new(text: &str, kind: AuditKind, range: Range<usize>) -> AuditFinding {
+        let controls = text[range.clone()]
+            .chars()
+            .filter(|&ch| is_bidi_control(ch))
+            .map(BidiClass::of)
+            .collect();
+        AuditFinding {
+            kind,
+            range,
+            controls,
+        }
+    }
+}
+
+/// Syntax of string literals and comments, for `audit_source()`
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SourceSyntax {
+    /// Starts of comments that end at the end of the line, such as `//`.
+    pub line_comments: Vec<String>,
+
+    /// Starts and ends of comments that can span lines, such as `/*` and `*/`.
+    pub block_comments: Vec<(String, String)>,
+
+    /// Characters that start and end string literals, such as `"`.
+    pub quotes: Vec<char>,
+
+    /// Characters that start and end literals within a line, but that are also used alone, such
+    /// as `'` in Rust lifetimes. They only start a literal if it is closed on the same line.
+    pub line_quotes: Vec<char>,
+
+    /// Character that escapes the next one in a string literal, such as `\`.
+    pub escape: Option<char>,
+}
+
+impl SourceSyntax {
+    /// The syntax of C and the languages that follow it, such as C++, Java, JavaScript and Rust.
+    pub fn c_like() -> SourceSyntax {
+        SourceSyntax {
+            line_comments: vec!["//".to_owned()],
+            block_comments: vec![("/*".to_owned(), "*/".to_owned())],
+            quotes: vec!['"'],
+            line_quotes: vec!['\''],
+            escape: Some('\\'),
+        }
+    }
+
+    /// The syntax of languages with comments starting with `#`, such as shell and Python.
+    pub fn hash_comments() -> SourceSyntax {
+        SourceSyntax {
+            line_comments: vec!["#".to_owned()],
+            block_comments: Vec::new(),
+            quotes: vec!['"', '\''],
+            line_quotes: Vec::new(),
+            escape: Some('\\'),
+        }
+    }
+}
+
+/// Audit a text for embeddings, overrides and isolates not terminated by the end of their line,
+/// and for parts of lines displayed in a different order than their logical order.
+///
+/// Lines are displayed left-to-right, as in code editors, and end at paragraph separators.
+///
+/// ```rust
+/// # use unic_bidi::{audit, AuditKind, BidiClass};
+/// let text = "access = \"user\u{202E} \u{2066}// admin\u{2069} \u{2066}\"";
+/// let findings = audit(text);
+/// assert_eq!(findings[0].kind, AuditKind::Unterminated);
+/// assert_eq!(findings[0].controls[0], BidiClass::RightToLeftOverride);
+/// ```
+pub fn audit(text: &str) -> Vec<AuditFinding> {
+    let mut findings = Vec::new();
+    audit_unterminated(text, &mut findings);
+    audit_reordered(text, &mut findings);
+    findings.sort_by_key(|finding| (finding.range.start, finding.range.end));
+    findings
+}
+
+/// Audit source code as with `audit()`, and also for each bidirectional control character in it,
+/// depending on whether it is in a string literal, in a comment, or in code.
+///
+/// ```rust
+/// # use unic_bidi::{audit_source, AuditKind, SourceSyntax};
+/// let code = "if x { /* \u{202E} } \u{2066} */ }";
+/// let kinds: Vec<AuditKind> = audit_source(code, &SourceSyntax::c_like())
+///     .into_iter()
+///     .map(|finding| finding.kind)
+///     .collect();
+/// assert!(kinds.contains(&AuditKind::ControlInComment));
+/// assert!(kinds.contains(&AuditKind::Unterminated));
+/// ```
+pub fn audit_source(text: &str, syntax: &SourceSyntax) -> Vec<AuditFinding> {
+    let mut findings = Vec::new();
+    audit_unterminated(text, &mut findings);
+    audit_reordered(text, &mut findings);
+    audit_controls(text, syntax, &mut findings);
+    findings.sort_by_key(|finding| (finding.range.start, finding.range.end));
+    findings
+}
+
+/// The byte index of the end of the line containing the byte index `i`, before its separator.
+fn line_end(text: &str, i: usize) -> usize {
+    text[i..]
+        .char_indices()
+        .find(|&(_, ch)| BidiClass::of(ch) == B)
+        .map_or(text.len(), |(j, _)| i + j)
+}
+
+fn audit_unterminated(text: &str, findings: &mut Vec<AuditFinding>) {
+    for_each_unbalanced_formatting(text, |i| {
+        let ch = text[i..].chars().next().unwrap();
+        if matches!(BidiClass::of(ch), RLE | LRE | RLO | LRO | RLI | LRI | FSI) {
+            let range = i..line_end(text, i);
+            findings.push(AuditFinding::new(text, AuditKind::Unterminated, range));
+        }
+    });
+}
+
+fn audit_reordered(text: &str, findings: &mut Vec<AuditFinding>) {
+    let bidi_info = BidiInfo::new(text, Some(Level::ltr()));
+    for para in &bidi_info.paragraphs {
+        if !bidi_info.levels[para.range.clone()]
+            .iter()
+            .any(|level| level.is_rtl())
+        {
+            continue;
+        }
+
+        let visual = bidi_info.visual_to_logical(para, para.range.clone());
+        let mut logical = visual.clone();
+        logical.sort();
+
+        // Split the line into the shortest parts that are displayed in place, as a whole.
+        let mut start = 0;
+        let mut last = 0;
+        for (k, &i) in visual.iter().enumerate() {
+            last = last.max(logical.binary_search(&i).unwrap());
+            if last == k {
+                if k > start {
+                    let end = logical[k] + text[logical[k]..].chars().next().unwrap().len_utf8();
+                    let range = logical[start]..end;
+                    findings.push(AuditFinding::new(text, AuditKind::Reordered, range));
+                }
+                start = k + 1;
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum LexState {
+    Code,
+    LineComment,
+    /// In a block comment, with the index of its syntax
+    BlockComment(usize),
+    /// In a string literal, with its quote
+    String(char),
+}
+
+fn audit_controls(text: &str, syntax: &SourceSyntax, findings: &mut Vec<AuditFinding>) {
+    let mut state = LexState::Code;
+    // Byte index up to which the text is part of a token already handled
+    let mut skip_to = 0;
+    let mut escaped = false;
+
+    for (i, ch) in text.char_indices() {
+        if is_bidi_control(ch) {
+            let kind = match state {
+                LexState::Code => AuditKind::ControlInCode,
+                LexState::LineComment | LexState::BlockComment(_) => AuditKind::ControlInComment,
+                LexState::String(_) => AuditKind::ControlInString,
+            };
+            findings.push(AuditFinding::new(text, kind, i..i + ch.len_utf8()));
+        }
+        if i < skip_to {
+            continue;
+        }
+
+        let rest = &text[i..];
+        match state {
+            LexState::Code => {
+                if syntax
+                    .line_comments
+                    .iter()
+                    .any(|start| rest.starts_with(start.as_str()))
+                {
+                    state = LexState::LineComment;
+                } else if let Some(index) = syntax
+                    .block_comments
+                    .iter()
+                    .position(|(start, _)| rest.starts_with(start.as_str()))
+                {
+                    state = LexState::BlockComment(index);
+                    skip_to = i + syntax.block_comments[index].0.len();
+                } else if syntax.quotes.contains(&ch)
+                    || (syntax.line_quotes.contains(&ch) && is_closed_in_line(rest, syntax))
+                {
+                    state = LexState::String(ch);
+                    escaped = false;
+                }
+            }
+            LexState::LineComment => {
+                if BidiClass::of(ch) == B {
+                    state = LexState::Code;
+                }
+            }
+            LexState::BlockComment(index) => {
+                let end = &syntax.block_comments[index].1;
+                if rest.starts_with(end.as_str()) {
+                    state = LexState::Code;
+                    skip_to = i + end.len();
+                }
+            }
+            LexState::String(quote) => {
+                if escaped {
+                    escaped = false;
+                } else if Some(ch) == syntax.escape {
+                    escaped = true;
+                } else if ch == quote {
+                    state = LexState::Code;
+                }
+            }
+        }
+    }
+}
+
+/// Whether the literal starting with the quote at the start of `rest` ends on the same line.
+fn is_closed_in_line(rest: &str, syntax: &SourceSyntax) -> bool {
+    let mut chars = rest.chars();
+    let quote = chars.next();
+    let mut escaped = false;
+    for ch in chars {
+        if BidiClass::of(ch) == B {
+            return false;
+        }
+        if escaped {
+            escaped = false;
+        } else if Some(ch) == syntax.escape {
+            escaped = true;
+        } else if Some(ch) == quote {
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(findings: &[AuditFinding]) -> Vec<(AuditKind, Range<usize>)> {
+        findings
+            .iter()
+            .map(|finding| (finding.kind, finding.range.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_audit_plain() {
+        assert_eq!(audit(""), &[]);
+        assert_eq!(audit("fn main() {}\n"), &[]);
+
+        // Balanced controls on one line, with nothing reordered
+        assert_eq!(audit("a\u{2067}b\u{2069}c"), &[]);
+
+        // Unterminated override, up to the end of its line only
+        let text = "a\u{202E}bc\nd";
+        assert_eq!(
+            kinds(&audit(text)),
+            &[
+                (AuditKind::Unterminated, 1..6),
+                (AuditKind::Reordered, 4..6)
+            ]
+        );
+        assert_eq!(audit(text)[0].controls, &[RLO]);
+
+        // Plain right-to-left text is reordered, without controls
+        let findings = audit("x = \"\u{5D0}\u{5D1}\";");
+        assert_eq!(kinds(&findings), &[(AuditKind::Reordered, 5..9)]);
+        assert_eq!(findings[0].controls, &[]);
+    }
+
+    #[test]
+    fn test_audit_trojan_source() {
+        // Early return, from the Trojan Source paper
+        let text = concat!(
+            "/* begin admins only \u{202E} \u{2066}*/\n",
+            "if (isAdmin) {\u{2069} \u{2066} begin admins only */\n",
+            "    stuff();\n",
+            "/* end admins only \u{202E} \u{2066}*/\n",
+            "}\u{2069}\u{2066}\n",
+        );
+        let findings = audit_source(text, &SourceSyntax::c_like());
+        let unterminated: Vec<&AuditFinding> = findings
+            .iter()
+            .filter(|finding| finding.kind == AuditKind::Unterminated)
+            .collect();
+        assert_eq!(unterminated.len(), 6);
+        assert_eq!(unterminated[0].controls, &[RLO, LRI]);
+        assert!(findings
+            .iter()
+            .any(|finding| finding.kind == AuditKind::ControlInComment));
+        assert!(findings
+            .iter()
+            .any(|finding| finding.kind == AuditKind::ControlInCode));
+        assert!(findings
+            .iter()
+            .any(|finding| finding.kind == AuditKind::Reordered && !finding.controls.is_empty()));
+    }
+
+    #[test]
+    fn test_audit_source_states() {
+        let syntax = SourceSyntax::c_like();
+        let controls = |text: &str| -> Vec<(AuditKind, Range<usize>)> {
+            kinds(&audit_source(text, &syntax))
+                .into_iter()
+                .filter(|&(kind, _)| {
+                    kind != AuditKind::Unterminated && kind != AuditKind::Reordered
+                })
+                .collect()
+        };
+
+        assert_eq!(
+            controls("\"a\u{200F}\" x\u{200F}"),
+            &[
+                (AuditKind::ControlInString, 2..5),
+                (AuditKind::ControlInCode, 8..11)
+            ]
+        );
+        assert_eq!(
+            controls("\"\\\"\u{200F}\" // \u{200F}\n\u{200F}"),
+            &[
+                (AuditKind::ControlInString, 3..6),
+                (AuditKind::ControlInComment, 11..14),
+                (AuditKind::ControlInCode, 15..18)
+            ]
+        );
+        assert_eq!(
+            controls("/*/\u{200F}*/\u{200F}"),
+            &[
+                (AuditKind::ControlInComment, 3..6),
+                (AuditKind::ControlInCode, 8..11)
+            ]
+        );
+        // Lifetimes and labels are not literals, but characters are
+        assert_eq!(
+            controls("fn f(s: &'static str) {} // \u{200F}\n'\u{200F}'"),
+            &[
+                (AuditKind::ControlInComment, 28..31),
+                (AuditKind::ControlInString, 33..36)
+            ]
+        );
+        assert_eq!(
+            controls("'a: loop {}\n// \u{200F}\n'\\''\u{200F}"),
+            &[
+                (AuditKind::ControlInComment, 15..18),
+                (AuditKind::ControlInCode, 23..26)
+            ]
+        );
+        assert_eq!(
+            kinds(&audit_source(
+                "# \u{200F}\n\"\u{200F}\"",
+                &SourceSyntax::hash_comments()
+            )),
+            &[
+                (AuditKind::ControlInComment, 2..5),
+                (AuditKind::ControlInString, 7..10)
+            ]
+        );
+    }
+}
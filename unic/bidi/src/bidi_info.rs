@@ -10,21 +10,24 @@
 // except according to those terms.
 
 use std::borrow::Cow;
+use std::char;
 use std::cmp::{max, min};
 use std::fmt;
 use std::iter::repeat;
 use std::ops::Range;
 
+use unic_common::source::CodeUnitSource;
 use unic_ucd_bidi::bidi_class::abbr_names::*;
-use unic_ucd_bidi::BidiClass;
+use unic_ucd_bidi::{bidi_mirroring_glyph, BidiClass};
 
+use crate::direction::Direction;
 use crate::explicit;
-use crate::format_chars;
 use crate::implicit;
 use crate::level;
 use crate::prepare;
 
-use crate::level::{Level, LTR_LEVEL, RTL_LEVEL};
+use crate::level::Level;
+use crate::options::BidiOptions;
 use crate::prepare::LevelRun;
 
 /// Bidi information about a single paragraph
@@ -43,14 +46,14 @@ pub struct ParagraphInfo {
 
 /// Initial bidi information of the text
 ///
-/// Contains the paragraphs and `BidiClass`es in a string of text.
+/// Contains the paragraphs and `BidiClass`es in a string of text, in UTF-8 or UTF-16.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
-pub struct InitialInfo<'text> {
+pub struct InitialInfo<'text, T: ?Sized = str> {
     /// The text
-    pub text: &'text str,
+    pub text: &'text T,
 
-    /// The BidiClass of the character at each byte in the text.
-    /// If a character is multiple bytes, its class will appear multiple times in the vector.
+    /// The BidiClass of the character at each code unit in the text.
+    /// If a character is multiple code units, its class will appear multiple times in the vector.
     pub original_classes: Vec<BidiClass>,
 
     /// The boundaries and level of each paragraph within the text.
@@ -66,34 +69,52 @@ impl<'text> InitialInfo<'text> {
     /// character is found before the matching PDI.  If no strong character is found, the class will
     /// remain FSI, and it's up to later stages to treat these as LRI when needed.
     pub fn new(text: &str, default_para_level: Option<Level>) -> InitialInfo<'_> {
+        let options = BidiOptions::from_default_para_level(default_para_level);
+        InitialInfo::new_with_options(text, &options)
+    }
+}
+
+impl<'text, T: CodeUnitSource + ?Sized> InitialInfo<'text, T> {
+    /// Find the paragraphs and `BidiClass`es in a string of text, in UTF-8 or UTF-16, with the
+    /// higher-level protocols set in `options`.
+    pub fn new_with_options(text: &'text T, options: &BidiOptions) -> InitialInfo<'text, T> {
         let mut original_classes = Vec::with_capacity(text.len());
+        for (_, c, len) in text.char_indices() {
+            original_classes.extend(repeat(BidiClass::of(c)).take(len));
+        }
+
+        // <https://www.unicode.org/reports/tr9/#HL3>
+        for &(ref range, class) in &options.class_overrides {
+            assert!(text.is_char_boundary(range.start) && text.is_char_boundary(range.end));
+            for original_class in &mut original_classes[range.clone()] {
+                *original_class = class;
+            }
+        }
 
-        // The stack contains the starting byte index for each nested isolate we're inside.
+        // The stack contains the starting code unit index for each nested isolate we're inside.
         let mut isolate_stack = Vec::new();
         let mut paragraphs = Vec::new();
 
         let mut para_start = 0;
-        let mut para_level = default_para_level;
+        let mut para_level = options.para_level;
 
-        for (i, c) in text.char_indices() {
-            let class = BidiClass::of(c);
-            original_classes.extend(repeat(class).take(c.len_utf8()));
+        for (i, _, len) in text.char_indices() {
+            let class = original_classes[i];
             match class {
                 B => {
                     // P1. Split the text into separate paragraphs. The paragraph separator is kept
                     // with the previous paragraph.
-                    let para_end = i + c.len_utf8();
+                    let para_end = i + len;
+                    // P3. If no character is found in p2, set the paragraph level to zero, or
+                    // to the level set by the higher-level protocol.
+                    let level = para_level.unwrap_or_else(|| fallback_level(options, &paragraphs));
                     paragraphs.push(ParagraphInfo {
                         range: para_start..para_end,
-                        // P3. If no character is found in p2, set the paragraph level to zero.
-                        level: para_level.unwrap_or(LTR_LEVEL),
+                        level,
                     });
                     // Reset state for the start of the next paragraph.
                     para_start = para_end;
-                    // TODO: Support defaulting to direction of previous paragraph
-                    //
-                    // <https://www.unicode.org/reports/tr9/#HL1>
-                    para_level = default_para_level;
+                    para_level = options.para_level;
                     isolate_stack.clear();
                 }
                 L | R | AL => {
@@ -102,7 +123,7 @@ impl<'text> InitialInfo<'text> {
                             if original_classes[start] == FSI {
                                 // X5c. If the first strong character between FSI and its matching
                                 // PDI is R or AL, treat it as RLI. Otherwise, treat it as LRI.
-                                for j in 0..format_chars::FSI.len_utf8() {
+                                for j in 0..text.char_at(start).unwrap().1 {
                                     original_classes[start + j] =
                                         if class == L { LRI } else { RLI };
                                 }
@@ -113,7 +134,7 @@ impl<'text> InitialInfo<'text> {
                                 // P2. Find the first character of type L, AL, or R, while skipping
                                 // any characters between an isolate initiator and its matching
                                 // PDI.
-                                para_level = Some(if class != L { RTL_LEVEL } else { LTR_LEVEL });
+                                para_level = Direction::of_class(class).map(Direction::level);
                             }
                         }
                     }
@@ -128,9 +149,10 @@ impl<'text> InitialInfo<'text> {
             }
         }
         if para_start < text.len() {
+            let level = para_level.unwrap_or_else(|| fallback_level(options, &paragraphs));
             paragraphs.push(ParagraphInfo {
                 range: para_start..text.len(),
-                level: para_level.unwrap_or(LTR_LEVEL),
+                level,
             });
         }
         assert_eq!(original_classes.len(), text.len());
@@ -143,21 +165,34 @@ impl<'text> InitialInfo<'text> {
     }
 }
 
+/// The level of a paragraph without a strong character, given the paragraphs before it.
+///
+/// <https://www.unicode.org/reports/tr9/#HL1>
+fn fallback_level(options: &BidiOptions, previous: &[ParagraphInfo]) -> Level {
+    match previous.last() {
+        Some(para) if options.inherit_direction => para.level,
+        _ => options.fallback_level,
+    }
+}
+
 /// Bidi information of the text
 ///
 /// The `original_classes` and `levels` vectors are indexed by byte offsets into the text.  If a
 /// character is multiple bytes wide, then its class and level will appear multiple times in these
 /// vectors.
+///
+/// The text can also be UTF-16, a `[u16]` slice, in which case these vectors, the paragraphs and
+/// the level runs are all indexed by `u16` code unit instead of by byte.
 // TODO: Impl `struct StringProperty<T> { values: Vec<T> }` and use instead of Vec<T>
 #[derive(Debug, Eq, PartialEq, Hash)]
-pub struct BidiInfo<'text> {
+pub struct BidiInfo<'text, T: ?Sized = str> {
     /// The text
-    pub text: &'text str,
+    pub text: &'text T,
 
-    /// The BidiClass of the character at each byte in the text.
+    /// The BidiClass of the character at each byte, or code unit, in the text.
     pub original_classes: Vec<BidiClass>,
 
-    /// The directional embedding level of each byte in the text.
+    /// The directional embedding level of each byte, or code unit, in the text.
     pub levels: Vec<Level>,
 
     /// The boundaries and paragraph embedding level of each paragraph within the text.
@@ -170,22 +205,221 @@ pub struct BidiInfo<'text> {
 impl<'text> BidiInfo<'text> {
     /// Split the text into paragraphs and determine the bidi embedding levels for each paragraph.
     ///
-    /// TODO: In early steps, check for special cases that allow later steps to be skipped. like
-    /// text that is entirely LTR.  See the `nsBidi` class from Gecko for comparison.
+    /// Use `LtrOrBidiInfo` instead to skip all of this for text that is entirely LTR.
     ///
-    /// TODO: Support auto-RTL base direction
+    /// TODO: In early steps, check for other special cases that allow later steps to be skipped.
+    /// See the `nsBidi` class from Gecko for comparison.
     pub fn new(text: &str, default_para_level: Option<Level>) -> BidiInfo<'_> {
+        BidiInfo::resolve(InitialInfo::new(text, default_para_level))
+    }
+
+    /// Re-order a line based on resolved levels and return the line in display order.
+    pub fn reorder_line(&self, para: &ParagraphInfo, line: Range<usize>) -> Cow<'text, str> {
+        self.reorder_line_with_mirroring(para, line, false)
+    }
+
+    /// Re-order a line based on resolved levels and return the line in display order, with the
+    /// characters at right-to-left levels replaced by their mirrored characters, if any.
+    ///
+    /// This applies rule L4 for displays that cannot mirror glyphs themselves, such as terminals.
+    /// Characters that are mirrored but have no `Bidi_Mirroring_Glyph` are left as they are.
+    ///
+    /// <https://www.unicode.org/reports/tr9/#L4>
+    ///
+    /// ```rust
+    /// # use unic_bidi::BidiInfo;
+    /// let text = "\u{5D0}(\u{5D1})";
+    /// let bidi_info = BidiInfo::new(text, None);
+    /// let para = &bidi_info.paragraphs[0];
+    /// assert_eq!(
+    ///     bidi_info.reorder_line_mirrored(para, para.range.clone()),
+    ///     "(\u{5D1})\u{5D0}"
+    /// );
+    /// ```
+    pub fn reorder_line_mirrored(
+        &self,
+        para: &ParagraphInfo,
+        line: Range<usize>,
+    ) -> Cow<'text, str> {
+        self.reorder_line_with_mirroring(para, line, true)
+    }
+
+    fn reorder_line_with_mirroring(
+        &self,
+        para: &ParagraphInfo,
+        line: Range<usize>,
+        mirror: bool,
+    ) -> Cow<'text, str> {
+        let (levels, runs) = self.line_levels_and_runs(para, line.clone());
+        reorder_runs(self.text, line, &levels, &runs, mirror)
+    }
+
+    /// Re-order each line of a paragraph based on resolved levels.
+    ///
+    /// `line_breaks` are the byte indices within the text at which a line ends and the next one
+    /// starts, in increasing order, such as the line break opportunities of the paragraph. The
+    /// last line ends at the end of the paragraph, and empty lines are skipped.
+    ///
+    /// Each line only copies its own levels, so this is cheaper than calling `visual_runs()` for
+    /// each line of a long paragraph.
+    ///
+    /// ```rust
+    /// # use unic_bidi::BidiInfo;
+    /// let text = "\u{5D0}\u{5D1} abc \u{5D2}\u{5D3}";
+    /// let bidi_info = BidiInfo::new(text, None);
+    /// let para = &bidi_info.paragraphs[0];
+    /// let lines = bidi_info.reorder_lines(para, vec![5, 9]);
+    /// assert_eq!(lines.len(), 3);
+    /// assert_eq!(lines[0].range, 0..5);
+    /// assert_eq!(lines[0].reordered(), " \u{5D1}\u{5D0}");
+    /// assert_eq!(lines[1].reordered(), " abc");
+    /// assert_eq!(lines[2].reordered(), "\u{5D3}\u{5D2}");
+    /// ```
+    pub fn reorder_lines<I>(
+        &self,
+        para: &ParagraphInfo,
+        line_breaks: I,
+    ) -> Vec<ReorderedLine<'text>>
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let mut lines = Vec::new();
+        let mut start = para.range.start;
+        let ends = line_breaks.into_iter().chain(Some(para.range.end));
+        for end in ends {
+            assert!(start <= end && end <= para.range.end);
+            if start == end {
+                continue;
+            }
+            let (levels, runs) = self.line_levels_and_runs(para, start..end);
+            lines.push(ReorderedLine {
+                text: self.text,
+                range: start..end,
+                levels,
+                runs,
+            });
+            start = end;
+        }
+        lines
+    }
+
+    /// Re-order a sequence of levels, such as the levels of the characters of a line, and return
+    /// the logical index of each, in visual order from left to right.
+    ///
+    /// The levels are expected to have been adjusted by rule L1.
+    ///
+    /// <https://www.unicode.org/reports/tr9/#L2>
+    ///
+    /// ```rust
+    /// # use unic_bidi::{BidiInfo, Level};
+    /// let levels = Level::vec(&[0, 0, 1, 1, 2, 1]);
+    /// assert_eq!(BidiInfo::reorder_visual(&levels), &[0, 1, 5, 4, 3, 2]);
+    /// ```
+    pub fn reorder_visual(levels: &[Level]) -> Vec<usize> {
+        reorder_visual(levels)
+    }
+}
+
+impl<'text> BidiInfo<'text, [u16]> {
+    /// Split UTF-16 text into paragraphs and determine the bidi embedding levels for each
+    /// paragraph.
+    ///
+    /// Levels, paragraphs and level runs are indexed by `u16` code unit. Unpaired surrogates are
+    /// treated as U+FFFD REPLACEMENT CHARACTER.
+    ///
+    /// ```rust
+    /// # use unic_bidi::BidiInfo;
+    /// let text: Vec<u16> = "abc \u{5D0}\u{5D1}".encode_utf16().collect();
+    /// let bidi_info = BidiInfo::new_utf16(&text, None);
+    /// let para = &bidi_info.paragraphs[0];
+    /// assert_eq!(para.range, 0..6);
+    /// let display: Vec<u16> = "abc \u{5D1}\u{5D0}".encode_utf16().collect();
+    /// assert_eq!(bidi_info.reorder_line(para, para.range.clone()), &display[..]);
+    /// ```
+    pub fn new_utf16(text: &[u16], default_para_level: Option<Level>) -> BidiInfo<'_, [u16]> {
+        let options = BidiOptions::from_default_para_level(default_para_level);
+        BidiInfo::new_with_options(text, &options)
+    }
+
+    /// Re-order a line based on resolved levels and return the line in display order.
+    ///
+    /// Unpaired surrogates are kept as they are.
+    pub fn reorder_line(&self, para: &ParagraphInfo, line: Range<usize>) -> Cow<'text, [u16]> {
+        self.reorder_line_with_mirroring(para, line, false)
+    }
+
+    /// Re-order a line based on resolved levels and return the line in display order, with the
+    /// characters at right-to-left levels replaced by their mirrored characters, if any.
+    ///
+    /// See `BidiInfo::reorder_line_mirrored()` for UTF-8 text.
+    pub fn reorder_line_mirrored(
+        &self,
+        para: &ParagraphInfo,
+        line: Range<usize>,
+    ) -> Cow<'text, [u16]> {
+        self.reorder_line_with_mirroring(para, line, true)
+    }
+
+    fn reorder_line_with_mirroring(
+        &self,
+        para: &ParagraphInfo,
+        line: Range<usize>,
+        mirror: bool,
+    ) -> Cow<'text, [u16]> {
+        let (levels, runs) = self.line_levels_and_runs(para, line.clone());
+        let run_level = |run: &LevelRun| levels[run.start - line.start];
+
+        // If all isolating run sequences are LTR, no reordering is needed
+        if runs.iter().all(|run| run_level(run).is_ltr()) {
+            return self.text[line].into();
+        }
+
+        let mut result = Vec::with_capacity(line.len());
+        for run in &runs {
+            if run_level(run).is_rtl() {
+                let chars: Vec<_> =
+                    char::decode_utf16(self.text[run.clone()].iter().cloned()).collect();
+                for ch in chars.into_iter().rev() {
+                    match ch {
+                        Ok(ch) => {
+                            let ch = if mirror {
+                                bidi_mirroring_glyph(ch).unwrap_or(ch)
+                            } else {
+                                ch
+                            };
+                            result.extend_from_slice(ch.encode_utf16(&mut [0; 2]));
+                        }
+                        Err(err) => result.push(err.unpaired_surrogate()),
+                    }
+                }
+            } else {
+                result.extend_from_slice(&self.text[run.clone()]);
+            }
+        }
+        result.into()
+    }
+}
+
+impl<'text, T: CodeUnitSource + ?Sized> BidiInfo<'text, T> {
+    /// Split the text into paragraphs and determine the bidi embedding levels for each paragraph,
+    /// with the higher-level protocols set in `options`.
+    pub fn new_with_options(text: &'text T, options: &BidiOptions) -> BidiInfo<'text, T> {
+        Self::resolve(InitialInfo::new_with_options(text, options))
+    }
+
+    /// Determine the bidi embedding levels for each paragraph found in the text.
+    fn resolve(initial_info: InitialInfo<'text, T>) -> BidiInfo<'text, T> {
         let InitialInfo {
+            text,
             original_classes,
             paragraphs,
-            ..
-        } = InitialInfo::new(text, default_para_level);
+        } = initial_info;
 
         let mut levels = Vec::<Level>::with_capacity(text.len());
         let mut processing_classes = original_classes.clone();
 
         for para in &paragraphs {
-            let text = &text[para.range.clone()];
+            let text = text.slice(para.range.clone());
             let original_classes = &original_classes[para.range.clone()];
             let processing_classes = &mut processing_classes[para.range.clone()];
 
@@ -203,8 +437,14 @@ impl<'text> BidiInfo<'text> {
 
             let sequences = prepare::isolating_run_sequences(para.level, original_classes, levels);
             for sequence in &sequences {
-                implicit::resolve_weak(sequence, processing_classes);
-                implicit::resolve_neutral(sequence, levels, processing_classes);
+                implicit::resolve_weak(text, sequence, processing_classes);
+                implicit::resolve_neutral(
+                    text,
+                    sequence,
+                    levels,
+                    original_classes,
+                    processing_classes,
+                );
             }
             implicit::resolve_levels(processing_classes, levels);
 
@@ -249,28 +489,98 @@ impl<'text> BidiInfo<'text> {
         para: &ParagraphInfo,
         line: Range<usize>,
     ) -> Vec<Level> {
-        let levels = self.reordered_levels(para, line);
-        self.text.char_indices().map(|(i, _)| levels[i]).collect()
+        let levels = self.reordered_levels(para, line.clone());
+        self.text
+            .slice(line.clone())
+            .char_indices()
+            .map(|(i, _, _)| levels[line.start + i])
+            .collect()
     }
 
-    /// Re-order a line based on resolved levels and return the line in display order.
-    pub fn reorder_line(&self, para: &ParagraphInfo, line: Range<usize>) -> Cow<'text, str> {
-        let (levels, runs) = self.visual_runs(para, line.clone());
+    /// Re-order a line based on resolved levels and return the byte index of each character, in
+    /// visual order from left to right.
+    ///
+    /// Characters removed by rule X9 are not displayed, and are left out.
+    pub fn visual_to_logical(&self, para: &ParagraphInfo, line: Range<usize>) -> Vec<usize> {
+        self.visual_order(para, line)
+            .into_iter()
+            .map(|(i, _)| i)
+            .collect()
+    }
 
-        // If all isolating run sequences are LTR, no reordering is needed
-        if runs.iter().all(|run| levels[run.start].is_ltr()) {
-            return self.text[line].into();
-        }
+    /// Re-order a line based on resolved levels and return the index of each character, counted
+    /// in characters from the start of the line, in visual order from left to right.
+    ///
+    /// Characters removed by rule X9 are not displayed, and are left out.
+    pub fn visual_to_logical_per_char(
+        &self,
+        para: &ParagraphInfo,
+        line: Range<usize>,
+    ) -> Vec<usize> {
+        self.visual_order(para, line)
+            .into_iter()
+            .map(|(_, char_index)| char_index)
+            .collect()
+    }
 
-        let mut result = String::with_capacity(line.len());
-        for run in runs {
-            if levels[run.start].is_rtl() {
-                result.extend(self.text[run].chars().rev());
-            } else {
-                result.push_str(&self.text[run]);
+    /// Re-order a line based on resolved levels and return the visual position of the character
+    /// at each *byte* of the line, or `None` for characters removed by rule X9.
+    ///
+    /// Visual positions count the displayed characters from the left. This is the inverse of
+    /// `visual_to_logical()`.
+    pub fn logical_to_visual(
+        &self,
+        para: &ParagraphInfo,
+        line: Range<usize>,
+    ) -> Vec<Option<usize>> {
+        let mut map = vec![None; line.len()];
+        for (visual, (i, _)) in self
+            .visual_order(para, line.clone())
+            .into_iter()
+            .enumerate()
+        {
+            let end = i + self.text.char_at(i).unwrap().1;
+            for j in i..end {
+                map[j - line.start] = Some(visual);
             }
         }
-        result.into()
+        map
+    }
+
+    /// Re-order a line based on resolved levels and return the visual position of each
+    /// *character* of the line, or `None` for characters removed by rule X9.
+    ///
+    /// Visual positions count the displayed characters from the left. This is the inverse of
+    /// `visual_to_logical_per_char()`.
+    pub fn logical_to_visual_per_char(
+        &self,
+        para: &ParagraphInfo,
+        line: Range<usize>,
+    ) -> Vec<Option<usize>> {
+        let mut map = vec![None; self.text.slice(line.clone()).char_indices().count()];
+        for (visual, (_, char_index)) in self.visual_order(para, line).into_iter().enumerate() {
+            map[char_index] = Some(visual);
+        }
+        map
+    }
+
+    /// The displayed characters of a line in visual order, as their byte index and their index
+    /// in characters from the start of the line.
+    fn visual_order(&self, para: &ParagraphInfo, line: Range<usize>) -> Vec<(usize, usize)> {
+        let (levels, _) = self.line_levels_and_runs(para, line.clone());
+        let chars: Vec<(usize, usize)> = self
+            .text
+            .slice(line.clone())
+            .char_indices()
+            .enumerate()
+            .map(|(char_index, (i, _, _))| (line.start + i, char_index))
+            .filter(|&(i, _)| !prepare::removed_by_x9(self.original_classes[i]))
+            .collect();
+        let char_levels: Vec<Level> = chars.iter().map(|&(i, _)| levels[i - line.start]).collect();
+        reorder_visual(&char_levels)
+            .into_iter()
+            .map(|index| chars[index])
+            .collect()
     }
 
     /// Find the level runs within a line and return them in visual order.
@@ -278,30 +588,47 @@ impl<'text> BidiInfo<'text> {
     /// `line` is a range of bytes indices within `levels`.
     ///
     /// <https://www.unicode.org/reports/tr9/#Reordering_Resolved_Levels>
-    #[allow(clippy::needless_range_loop)]
     pub fn visual_runs(
         &self,
         para: &ParagraphInfo,
         line: Range<usize>,
     ) -> (Vec<Level>, Vec<LevelRun>) {
-        assert!(line.start <= self.levels.len());
+        let (line_levels, runs) = self.line_levels_and_runs(para, line.clone());
+        let mut levels = self.levels.clone();
+        levels[line].copy_from_slice(&line_levels);
+        (levels, runs)
+    }
+
+    /// Find the levels of a line, one per byte of the line, and its level runs in visual order.
+    ///
+    /// Unlike `visual_runs()`, only the levels of the line are copied.
+    #[allow(clippy::needless_range_loop)]
+    fn line_levels_and_runs(
+        &self,
+        para: &ParagraphInfo,
+        line: Range<usize>,
+    ) -> (Vec<Level>, Vec<LevelRun>) {
+        assert!(line.start <= line.end);
         assert!(line.end <= self.levels.len());
 
-        let mut levels = self.levels.clone();
+        let mut levels = self.levels[line.clone()].to_vec();
+        if levels.is_empty() {
+            return (levels, Vec::new());
+        }
 
         // Reset some whitespace chars to paragraph level.
         // <https://www.unicode.org/reports/tr9/#L1>
-        let line_str: &str = &self.text[line.clone()];
+        let line_str = self.text.slice(line.clone());
         let mut reset_from: Option<usize> = Some(0);
         let mut reset_to: Option<usize> = None;
-        for (i, c) in line_str.char_indices() {
-            match self.original_classes[i] {
+        for (i, _, len) in line_str.char_indices() {
+            match self.original_classes[line.start + i] {
                 // Ignored by X9
                 RLE | LRE | RLO | LRO | PDF | BN => {}
                 // Segment separator, Paragraph separator
                 B | S => {
                     assert_eq!(reset_to, None);
-                    reset_to = Some(i + c.len_utf8());
+                    reset_to = Some(i + len);
                     if reset_from == None {
                         reset_from = Some(i);
                     }
@@ -332,16 +659,16 @@ impl<'text> BidiInfo<'text> {
 
         // Find consecutive level runs.
         let mut runs = Vec::new();
-        let mut start = line.start;
+        let mut start = 0;
         let mut level = levels[start];
         let mut min_level = level;
         let mut max_level = level;
 
-        for i in (start + 1)..line.end {
+        for i in 1..levels.len() {
             let new_level = levels[i];
             if new_level != level {
                 // End of the previous run, start of a new one.
-                runs.push(start..i);
+                runs.push(line.start + start..line.start + i);
                 start = i;
                 level = new_level;
 
@@ -349,7 +676,7 @@ impl<'text> BidiInfo<'text> {
                 max_level = max(level, max_level);
             }
         }
-        runs.push(start..line.end);
+        runs.push(line.start + start..line.end);
 
         let run_count = runs.len();
 
@@ -363,7 +690,7 @@ impl<'text> BidiInfo<'text> {
             // Look for the start of a sequence of consecutive runs of max_level or higher.
             let mut seq_start = 0;
             while seq_start < run_count {
-                if self.levels[runs[seq_start].start] < max_level {
+                if levels[runs[seq_start].start - line.start] < max_level {
                     seq_start += 1;
                     continue;
                 }
@@ -371,7 +698,7 @@ impl<'text> BidiInfo<'text> {
                 // Found the start of a sequence. Now find the end.
                 let mut seq_end = seq_start + 1;
                 while seq_end < run_count {
-                    if self.levels[runs[seq_end].start] < max_level {
+                    if levels[runs[seq_end].start - line.start] < max_level {
                         break;
                     }
                     seq_end += 1;
@@ -399,6 +726,120 @@ impl<'text> BidiInfo<'text> {
     }
 }
 
+/// L2. Re-order a sequence of levels and return the logical index of each, in visual order.
+fn reorder_visual(levels: &[Level]) -> Vec<usize> {
+    let mut result: Vec<usize> = (0..levels.len()).collect();
+    let (min_level, mut max_level) = match (levels.iter().min(), levels.iter().max()) {
+        (Some(&min_level), Some(&max_level)) => (min_level, max_level),
+        _ => return result,
+    };
+
+    // Stop at the lowest *odd* level.
+    let min_level = min_level.new_lowest_ge_rtl().expect("Level error");
+
+    while max_level >= min_level {
+        // Reverse each sequence of consecutive characters at max_level or higher.
+        let mut start = 0;
+        while start < levels.len() {
+            if levels[start] < max_level {
+                start += 1;
+                continue;
+            }
+            let mut end = start + 1;
+            while end < levels.len() && levels[end] >= max_level {
+                end += 1;
+            }
+            result[start..end].reverse();
+            start = end;
+        }
+        max_level
+            .lower(1)
+            .expect("Lowering embedding level below zero");
+    }
+
+    result
+}
+
+/// A line of a paragraph, with its levels adjusted by rule L1 and its level runs in visual order
+///
+/// Returned by `BidiInfo::reorder_lines()`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ReorderedLine<'text> {
+    /// The whole text, of which the line is a part.
+    text: &'text str,
+
+    /// The line boundaries within the text, as byte indices.
+    pub range: Range<usize>,
+
+    /// The directional embedding level of each byte in the line, after rule L1.
+    ///
+    /// <https://www.unicode.org/reports/tr9/#L1>
+    pub levels: Vec<Level>,
+
+    /// The level runs of the line, as byte ranges within the text, in visual order.
+    pub runs: Vec<LevelRun>,
+}
+
+impl<'text> ReorderedLine<'text> {
+    /// The line in display order.
+    pub fn reordered(&self) -> Cow<'text, str> {
+        reorder_runs(
+            self.text,
+            self.range.clone(),
+            &self.levels,
+            &self.runs,
+            false,
+        )
+    }
+
+    /// The line in display order, with the characters at right-to-left levels replaced by their
+    /// mirrored characters, if any.
+    ///
+    /// See `BidiInfo::reorder_line_mirrored()`.
+    pub fn reordered_mirrored(&self) -> Cow<'text, str> {
+        reorder_runs(
+            self.text,
+            self.range.clone(),
+            &self.levels,
+            &self.runs,
+            true,
+        )
+    }
+}
+
+/// Concatenate the level runs of a line in visual order, reversing the right-to-left ones.
+///
+/// `levels` has one `Level` per byte of the line.
+fn reorder_runs<'text>(
+    text: &'text str,
+    line: Range<usize>,
+    levels: &[Level],
+    runs: &[LevelRun],
+    mirror: bool,
+) -> Cow<'text, str> {
+    let run_level = |run: &LevelRun| levels[run.start - line.start];
+
+    // If all isolating run sequences are LTR, no reordering is needed
+    if runs.iter().all(|run| run_level(run).is_ltr()) {
+        return text[line].into();
+    }
+
+    let mut result = String::with_capacity(line.len());
+    for run in runs {
+        if run_level(run).is_rtl() {
+            let chars = text[run.clone()].chars().rev();
+            if mirror {
+                result.extend(chars.map(|ch| bidi_mirroring_glyph(ch).unwrap_or(ch)));
+            } else {
+                result.extend(chars);
+            }
+        } else {
+            result.push_str(&text[run.clone()]);
+        }
+    }
+    result.into()
+}
+
 impl<'text> fmt::Display for BidiInfo<'text> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -410,9 +851,96 @@ impl<'text> fmt::Display for BidiInfo<'text> {
     }
 }
 
+/// Bidi information of a text, resolved only if the text is not entirely left-to-right
+///
+/// Most texts in left-to-right languages have no right-to-left characters, Arabic numbers or
+/// directional formatting characters. All of their characters are at level 0, and reordering them
+/// leaves them as they are, so nothing needs to be resolved or allocated for them.
+///
+/// ```rust
+/// # use unic_bidi::LtrOrBidiInfo;
+/// let bidi_info = LtrOrBidiInfo::new("Hello, world!", None);
+/// assert!(!bidi_info.has_rtl());
+/// assert_eq!(bidi_info.reorder_line(0..5), "Hello");
+///
+/// let bidi_info = LtrOrBidiInfo::new("Hello, \u{5E2}\u{5D5}\u{5DC}\u{5DD}!", None);
+/// assert!(bidi_info.has_rtl());
+/// assert_eq!(bidi_info.reorder_line(7..16), "\u{5DD}\u{5DC}\u{5D5}\u{5E2}!");
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub enum LtrOrBidiInfo<'text> {
+    /// A text whose characters are all at level 0, in a single direction.
+    Ltr(&'text str),
+
+    /// The bidi information of any other text.
+    Bidi(BidiInfo<'text>),
+}
+
+impl<'text> LtrOrBidiInfo<'text> {
+    /// Check whether the text is entirely left-to-right, and if not, split it into paragraphs and
+    /// determine the bidi embedding levels for each paragraph.
+    ///
+    /// Only a `default_para_level` of `None` or left-to-right allows the check.
+    pub fn new(text: &str, default_para_level: Option<Level>) -> LtrOrBidiInfo<'_> {
+        if default_para_level.unwrap_or_else(Level::ltr) == Level::ltr() && is_ltr_only(text) {
+            LtrOrBidiInfo::Ltr(text)
+        } else {
+            LtrOrBidiInfo::Bidi(BidiInfo::new(text, default_para_level))
+        }
+    }
+
+    /// The text
+    pub fn text(&self) -> &'text str {
+        match *self {
+            LtrOrBidiInfo::Ltr(text) => text,
+            LtrOrBidiInfo::Bidi(ref bidi_info) => bidi_info.text,
+        }
+    }
+
+    /// If the text has any right-to-left levels
+    #[inline]
+    pub fn has_rtl(&self) -> bool {
+        match *self {
+            LtrOrBidiInfo::Ltr(_) => false,
+            LtrOrBidiInfo::Bidi(ref bidi_info) => bidi_info.has_rtl(),
+        }
+    }
+
+    /// Re-order a line of the text and return the line in display order.
+    ///
+    /// The line must be within a single paragraph.
+    pub fn reorder_line(&self, line: Range<usize>) -> Cow<'text, str> {
+        match *self {
+            LtrOrBidiInfo::Ltr(text) => text[line].into(),
+            LtrOrBidiInfo::Bidi(ref bidi_info) => {
+                let para = bidi_info
+                    .paragraphs
+                    .iter()
+                    .find(|para| para.range.start <= line.start && line.end <= para.range.end)
+                    .expect("Line is not within a paragraph");
+                bidi_info.reorder_line(para, line)
+            }
+        }
+    }
+}
+
+/// Whether all the characters of a text resolve to level 0 in a left-to-right paragraph: it has no
+/// R, AL or AN characters, and no explicit directional formatting characters.
+fn is_ltr_only(text: &str) -> bool {
+    text.chars().all(|ch| {
+        ch.is_ascii()
+            || !matches!(
+                BidiClass::of(ch),
+                R | AL | AN | RLE | LRE | RLO | LRO | PDF | RLI | LRI | FSI | PDI
+            )
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::format_chars;
+    use crate::level::{LTR_LEVEL, RTL_LEVEL};
 
     #[test]
     fn test_initial_text_info() {
@@ -420,7 +948,7 @@ mod tests {
         assert_eq!(
             InitialInfo::new(text, None),
             InitialInfo {
-                text: &text,
+                text,
                 original_classes: vec![L, EN],
                 paragraphs: vec![ParagraphInfo {
                     range: 0..2,
@@ -433,7 +961,7 @@ mod tests {
         assert_eq!(
             InitialInfo::new(text, None),
             InitialInfo {
-                text: &text,
+                text,
                 original_classes: vec![AL, AL, WS, R, R],
                 paragraphs: vec![ParagraphInfo {
                     range: 0..5,
@@ -446,7 +974,7 @@ mod tests {
         assert_eq!(
             InitialInfo::new(text, None),
             InitialInfo {
-                text: &text,
+                text,
                 original_classes: vec![L, B, B, B, L],
                 paragraphs: vec![
                     ParagraphInfo {
@@ -465,7 +993,7 @@ mod tests {
         assert_eq!(
             InitialInfo::new(&text, None),
             InitialInfo {
-                text: &text,
+                text: &text[..],
                 original_classes: vec![RLI, RLI, RLI, R, R, PDI, PDI, PDI, L],
                 paragraphs: vec![ParagraphInfo {
                     range: 0..9,
@@ -481,7 +1009,7 @@ mod tests {
         assert_eq!(
             BidiInfo::new(text, Some(LTR_LEVEL)),
             BidiInfo {
-                text: &text,
+                text,
                 levels: Level::vec(&[0, 0, 0, 0, 0, 0]),
                 original_classes: vec![L, L, L, EN, EN, EN],
                 paragraphs: vec![ParagraphInfo {
@@ -495,7 +1023,7 @@ mod tests {
         assert_eq!(
             BidiInfo::new(text, Some(LTR_LEVEL)),
             BidiInfo {
-                text: &text,
+                text,
                 levels: Level::vec(&[0, 0, 0, 0, 1, 1, 1, 1, 1, 1]),
                 original_classes: vec![L, L, L, WS, R, R, R, R, R, R],
                 paragraphs: vec![ParagraphInfo {
@@ -507,7 +1035,7 @@ mod tests {
         assert_eq!(
             BidiInfo::new(text, Some(RTL_LEVEL)),
             BidiInfo {
-                text: &text,
+                text,
                 levels: Level::vec(&[2, 2, 2, 1, 1, 1, 1, 1, 1, 1]),
                 original_classes: vec![L, L, L, WS, R, R, R, R, R, R],
                 paragraphs: vec![ParagraphInfo {
@@ -521,7 +1049,7 @@ mod tests {
         assert_eq!(
             BidiInfo::new(text, Some(LTR_LEVEL)),
             BidiInfo {
-                text: &text,
+                text,
                 levels: Level::vec(&[1, 1, 1, 1, 1, 1, 0, 0, 0, 0]),
                 original_classes: vec![R, R, R, R, R, R, WS, L, L, L],
                 paragraphs: vec![ParagraphInfo {
@@ -533,7 +1061,7 @@ mod tests {
         assert_eq!(
             BidiInfo::new(text, None),
             BidiInfo {
-                text: &text,
+                text,
                 levels: Level::vec(&[1, 1, 1, 1, 1, 1, 1, 2, 2, 2]),
                 original_classes: vec![R, R, R, R, R, R, WS, L, L, L],
                 paragraphs: vec![ParagraphInfo {
@@ -547,7 +1075,7 @@ mod tests {
         assert_eq!(
             BidiInfo::new(text, Some(LTR_LEVEL)),
             BidiInfo {
-                text: &text,
+                text,
                 levels: Level::vec(&[1, 1, 2, 1, 1, 1, 1, 1, 2, 1, 1]),
                 original_classes: vec![AL, AL, EN, AL, AL, WS, R, R, EN, R, R],
                 paragraphs: vec![ParagraphInfo {
@@ -561,7 +1089,7 @@ mod tests {
         assert_eq!(
             BidiInfo::new(text, None),
             BidiInfo {
-                text: &text,
+                text,
                 original_classes: vec![L, WS, R, R, CS, B, R, R],
                 levels: Level::vec(&[0, 0, 1, 1, 0, 0, 1, 1]),
                 paragraphs: vec![
@@ -582,6 +1110,201 @@ mod tests {
         assert_eq!(bidi_info.original_classes, vec![AL, AL, ET, ET, ET, EN, EN]);
     }
 
+    fn para_levels(text: &str, options: &BidiOptions) -> Vec<Level> {
+        InitialInfo::new_with_options(text, options)
+            .paragraphs
+            .iter()
+            .map(|para| para.level)
+            .collect()
+    }
+
+    #[test]
+    fn test_options_para_level() {
+        // Paragraphs: R, neutral, L, neutral
+        let text = "\u{5D0}\n123\nabc\n-";
+        assert_eq!(
+            para_levels(text, &BidiOptions::new()),
+            &[RTL_LEVEL, LTR_LEVEL, LTR_LEVEL, LTR_LEVEL]
+        );
+        assert_eq!(
+            para_levels(text, &BidiOptions::new().para_level(RTL_LEVEL)),
+            &[RTL_LEVEL, RTL_LEVEL, RTL_LEVEL, RTL_LEVEL]
+        );
+
+        // Auto-RTL
+        assert_eq!(
+            para_levels(text, &BidiOptions::new().fallback_level(RTL_LEVEL)),
+            &[RTL_LEVEL, RTL_LEVEL, LTR_LEVEL, RTL_LEVEL]
+        );
+
+        // HL1: Direction of the previous paragraph
+        assert_eq!(
+            para_levels(text, &BidiOptions::new().inherit_direction(true)),
+            &[RTL_LEVEL, RTL_LEVEL, LTR_LEVEL, LTR_LEVEL]
+        );
+        assert_eq!(
+            para_levels(
+                "-\n\u{5D0}",
+                &BidiOptions::new()
+                    .inherit_direction(true)
+                    .fallback_level(RTL_LEVEL)
+            ),
+            &[RTL_LEVEL, RTL_LEVEL]
+        );
+
+        assert_eq!(
+            BidiInfo::new_with_options(text, &BidiOptions::default()),
+            BidiInfo::new(text, None)
+        );
+    }
+
+    #[test]
+    fn test_options_override_class() {
+        // HL3: Markup treated as neutral
+        let text = "<a>\u{5D0}</a>";
+        let options = BidiOptions::new()
+            .override_class(0..3, ON)
+            .override_class(5..9, ON);
+        let initial_info = InitialInfo::new_with_options(text, &options);
+        assert_eq!(initial_info.paragraphs[0].level, RTL_LEVEL);
+        assert_eq!(initial_info.original_classes[1], ON);
+        assert_eq!(initial_info.original_classes[3], R);
+
+        let bidi_info = BidiInfo::new_with_options(text, &options);
+        let para = &bidi_info.paragraphs[0];
+        assert_eq!(
+            bidi_info.reorder_line(para, para.range.clone()),
+            ">a/<\u{5D0}>a<"
+        );
+
+        // Emulating explicit isolates, with the last override winning
+        let text = "\u{5D0} [abc] \u{5D1}";
+        let options = BidiOptions::new()
+            .override_class(3..4, LRI)
+            .override_class(7..8, L)
+            .override_class(7..8, PDI);
+        let bidi_info = BidiInfo::new_with_options(text, &options);
+        let para = &bidi_info.paragraphs[0];
+        assert_eq!(bidi_info.original_classes[3], LRI);
+        assert_eq!(bidi_info.original_classes[7], PDI);
+        assert_eq!(bidi_info.levels[3], RTL_LEVEL);
+        assert_eq!(bidi_info.levels[4], Level::new(2).unwrap());
+        assert_eq!(
+            bidi_info.reorder_line_mirrored(para, para.range.clone()),
+            "\u{5D1} [abc] \u{5D0}"
+        );
+    }
+
+    #[test]
+    fn test_bidi_info_utf16() {
+        // Bidi_Class: L WS R R ON L ON, with a supplementary character
+        let text = "a \u{5D0}\u{5D1}(\u{1D400})";
+        let utf16: Vec<u16> = text.encode_utf16().collect();
+        let bidi_info = BidiInfo::new_utf16(&utf16, None);
+        assert_eq!(bidi_info.original_classes.len(), 8);
+        assert_eq!(bidi_info.original_classes, vec![L, WS, R, R, ON, L, L, ON]);
+        assert_eq!(
+            bidi_info.paragraphs,
+            vec![ParagraphInfo {
+                range: 0..8,
+                level: LTR_LEVEL,
+            }]
+        );
+        assert_eq!(bidi_info.levels, Level::vec(&[0, 0, 1, 1, 0, 0, 0, 0]));
+
+        // Same levels per character as for the UTF-8 text
+        let utf8_info = BidiInfo::new(text, None);
+        let para = &bidi_info.paragraphs[0];
+        assert_eq!(
+            bidi_info.reordered_levels_per_char(para, 0..8),
+            utf8_info.reordered_levels_per_char(&utf8_info.paragraphs[0], 0..text.len())
+        );
+        assert_eq!(
+            bidi_info.visual_to_logical_per_char(para, 0..8),
+            &[0, 1, 3, 2, 4, 5, 6]
+        );
+        assert_eq!(
+            bidi_info.visual_to_logical(para, 0..8),
+            &[0, 1, 3, 2, 4, 5, 7]
+        );
+        assert_eq!(
+            bidi_info.reorder_line(para, 0..8).into_owned(),
+            "a \u{5D1}\u{5D0}(\u{1D400})"
+                .encode_utf16()
+                .collect::<Vec<u16>>()
+        );
+
+        // Unpaired surrogates are kept, and treated as neutral
+        let utf16 = [0x5D0, 0xD800, 0x5D1, 0x20, 0x61];
+        let bidi_info = BidiInfo::new_utf16(&utf16, None);
+        let para = &bidi_info.paragraphs[0];
+        assert_eq!(bidi_info.original_classes[1], ON);
+        assert_eq!(para.level, RTL_LEVEL);
+        assert_eq!(
+            bidi_info.reorder_line(para, para.range.clone()),
+            &[0x61, 0x20, 0x5D1, 0xD800, 0x5D0][..]
+        );
+
+        // Paragraphs and options
+        let utf16: Vec<u16> = "\u{5D0}\n-\n".encode_utf16().collect();
+        let options = BidiOptions::new().inherit_direction(true);
+        let bidi_info = BidiInfo::new_with_options(&utf16[..], &options);
+        assert_eq!(bidi_info.paragraphs[1].range, 2..4);
+        assert_eq!(bidi_info.paragraphs[1].level, RTL_LEVEL);
+        assert_eq!(
+            bidi_info.reorder_line_mirrored(&bidi_info.paragraphs[0], 0..2),
+            &[0x0A, 0x5D0][..]
+        );
+    }
+
+    #[test]
+    fn test_ltr_or_bidi_info() {
+        for &text in &[
+            "",
+            "abc 123\n(def)",
+            "a\u{301}\u{200D}\u{E9} \u{1F600}",
+            "\u{200E}x",
+        ] {
+            assert_eq!(LtrOrBidiInfo::new(text, None), LtrOrBidiInfo::Ltr(text));
+            assert_eq!(
+                LtrOrBidiInfo::new(text, Some(LTR_LEVEL)),
+                LtrOrBidiInfo::Ltr(text)
+            );
+
+            // Same results as the full algorithm
+            let bidi_info = BidiInfo::new(text, None);
+            assert!(bidi_info.levels.iter().all(|&level| level == LTR_LEVEL));
+            for para in &bidi_info.paragraphs {
+                assert_eq!(
+                    bidi_info.reorder_line(para, para.range.clone()),
+                    &text[para.range.clone()]
+                );
+            }
+        }
+
+        for &text in &[
+            "\u{5D0}",
+            "a\u{660}",
+            "a\u{202A}b\u{202C}",
+            "a\u{2068}b\u{2069}",
+        ] {
+            assert_eq!(
+                LtrOrBidiInfo::new(text, None),
+                LtrOrBidiInfo::Bidi(BidiInfo::new(text, None))
+            );
+        }
+        assert_eq!(
+            LtrOrBidiInfo::new("abc", Some(RTL_LEVEL)),
+            LtrOrBidiInfo::Bidi(BidiInfo::new("abc", Some(RTL_LEVEL)))
+        );
+
+        let bidi_info = LtrOrBidiInfo::new("abc\n\u{5D0}\u{5D1}", None);
+        assert_eq!(bidi_info.text(), "abc\n\u{5D0}\u{5D1}");
+        assert!(bidi_info.has_rtl());
+        assert_eq!(bidi_info.reorder_line(0..4), "abc\n");
+        assert_eq!(bidi_info.reorder_line(4..8), "\u{5D1}\u{5D0}");
+    }
+
     #[test]
     fn test_bidi_info_has_rtl() {
         // ASCII only
@@ -615,6 +1338,15 @@ mod tests {
             .collect()
     }
 
+    fn reorder_paras_mirrored(text: &str) -> Vec<Cow<'_, str>> {
+        let bidi_info = BidiInfo::new(text, None);
+        bidi_info
+            .paragraphs
+            .iter()
+            .map(|para| bidi_info.reorder_line_mirrored(para, para.range.clone()))
+            .collect()
+    }
+
     #[test]
     fn test_reorder_line() {
         // Bidi_Class: L L L B L L L B L L L
@@ -682,8 +1414,22 @@ mod tests {
         // With mirrorable characters in RTL run
         assert_eq!(reorder_paras("א(ב)ג."), vec![".ג)ב(א"]);
 
+        // With mirrored characters in RTL run (L4)
+        assert_eq!(reorder_paras_mirrored("א(ב)ג."), vec![".ג(ב)א"]);
+        assert_eq!(reorder_paras_mirrored("א<ב ∑"), vec!["∑ ב>א"]);
+        assert_eq!(reorder_paras_mirrored("a<b"), vec!["a<b"]);
+
         // With mirrorable characters on level boundry
-        assert_eq!(reorder_paras("אב(גד[&ef].)gh"), vec!["ef].)gh&[דג(בא"]);
+        assert_eq!(reorder_paras("אב(גד[&ef].)gh"), vec!["gh).]ef&[דג(בא"]);
+
+        // With bracket pairs taking the direction of the context (N0)
+        assert_eq!(reorder_paras("א a(b)"), vec!["a(b) א"]);
+        assert_eq!(reorder_paras("א a(b)\u{301}"), vec!["a(b)\u{301} א"]);
+        assert_eq!(reorder_paras("א a(b]"), vec!["]a(b א"]);
+        assert_eq!(
+            reorder_paras("א a\u{2329}b\u{3009}"),
+            vec!["a\u{2329}b\u{3009} א"]
+        );
     }
 
     fn reordered_levels_for_paras(text: &str) -> Vec<Vec<Level>> {
@@ -739,6 +1485,111 @@ mod tests {
          */
     }
 
+    #[test]
+    fn test_visual_order() {
+        // Bidi_Class: L L L WS R R R
+        let text = "abc אבג";
+        let bidi_info = BidiInfo::new(text, None);
+        let para = &bidi_info.paragraphs[0];
+        let line = para.range.clone();
+        assert_eq!(
+            bidi_info.visual_to_logical(para, line.clone()),
+            &[0, 1, 2, 3, 8, 6, 4]
+        );
+        assert_eq!(
+            bidi_info.visual_to_logical_per_char(para, line.clone()),
+            &[0, 1, 2, 3, 6, 5, 4]
+        );
+        let visual: Vec<Option<usize>> = vec![0, 1, 2, 3, 6, 6, 5, 5, 4, 4]
+            .into_iter()
+            .map(Some)
+            .collect();
+        assert_eq!(bidi_info.logical_to_visual(para, line.clone()), visual);
+        let visual: Vec<Option<usize>> = vec![0, 1, 2, 3, 6, 5, 4].into_iter().map(Some).collect();
+        assert_eq!(bidi_info.logical_to_visual_per_char(para, line), visual);
+
+        // Bidi_Class: L L BN L
+        let text = "ab\u{200D}c";
+        let bidi_info = BidiInfo::new(text, None);
+        let para = &bidi_info.paragraphs[0];
+        let line = para.range.clone();
+        assert_eq!(bidi_info.visual_to_logical(para, line.clone()), &[0, 1, 5]);
+        assert_eq!(
+            bidi_info.visual_to_logical_per_char(para, line.clone()),
+            &[0, 1, 3]
+        );
+        assert_eq!(
+            bidi_info.logical_to_visual_per_char(para, line),
+            &[Some(0), Some(1), None, Some(2)]
+        );
+
+        // Bidi_Class: R R R WS L L L WS R R R, in two lines
+        let text = "אבג abc דהו";
+        let bidi_info = BidiInfo::new(text, None);
+        let para = &bidi_info.paragraphs[0];
+        assert_eq!(
+            bidi_info.visual_to_logical(para, 0..11),
+            &[10, 7, 8, 9, 6, 4, 2, 0]
+        );
+        assert_eq!(bidi_info.visual_to_logical(para, 11..17), &[15, 13, 11]);
+        assert_eq!(
+            bidi_info.visual_to_logical_per_char(para, 11..17),
+            &[2, 1, 0]
+        );
+    }
+
+    #[test]
+    fn test_reorder_lines() {
+        // Bidi_Class: R R R WS L L L WS R R R, in two lines
+        let text = "אבג abc דהו";
+        let bidi_info = BidiInfo::new(text, None);
+        let para = &bidi_info.paragraphs[0];
+        let lines = bidi_info.reorder_lines(para, vec![0, 11, 11]);
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].range, 0..11);
+        assert_eq!(lines[0].reordered(), " abc גבא");
+        assert_eq!(lines[1].range, 11..17);
+        assert_eq!(lines[1].reordered(), "והד");
+        for line in &lines {
+            let (levels, runs) = bidi_info.visual_runs(para, line.range.clone());
+            assert_eq!(line.levels, &levels[line.range.clone()]);
+            assert_eq!(line.runs, runs);
+            assert_eq!(
+                line.reordered(),
+                bidi_info.reorder_line(para, line.range.clone())
+            );
+        }
+
+        // Levels are per byte of the line, and trailing whitespace is at the paragraph level (L1)
+        assert_eq!(lines[0].levels.len(), 11);
+        assert_eq!(lines[0].levels[7], Level::new(2).unwrap());
+        assert_eq!(lines[0].levels[10], Level::rtl());
+
+        let text = "א(ב) ג(ד)";
+        let bidi_info = BidiInfo::new(text, None);
+        let para = &bidi_info.paragraphs[0];
+        let lines = bidi_info.reorder_lines(para, vec![7]);
+        assert_eq!(lines[0].reordered_mirrored(), " (ב)א");
+        assert_eq!(lines[1].reordered_mirrored(), "(ד)ג");
+    }
+
+    #[test]
+    fn test_reorder_visual() {
+        assert_eq!(BidiInfo::reorder_visual(&[]), Vec::<usize>::new());
+        assert_eq!(
+            BidiInfo::reorder_visual(&Level::vec(&[0, 0, 0])),
+            &[0, 1, 2]
+        );
+        assert_eq!(
+            BidiInfo::reorder_visual(&Level::vec(&[1, 1, 1])),
+            &[2, 1, 0]
+        );
+        assert_eq!(
+            BidiInfo::reorder_visual(&Level::vec(&[1, 2, 2, 1, 3, 4, 0])),
+            &[5, 4, 3, 1, 2, 0, 6]
+        );
+    }
+
     #[test]
     fn test_display() {
         assert_eq!(
@@ -0,0 +1,228 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Direction of a text, without resolving its embedding levels.
+//!
+//! <https://www.unicode.org/reports/tr9/#The_Paragraph_Level>
+
+use unic_ucd_bidi::bidi_class::abbr_names::*;
+use unic_ucd_bidi::BidiClass;
+
+use crate::level::{Level, LTR_LEVEL, RTL_LEVEL};
+
+/// Direction of a text, such as a paragraph
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Direction {
+    /// Left-to-right
+    Ltr,
+
+    /// Right-to-left
+    Rtl,
+}
+
+impl Direction {
+    /// The direction of a strong `BidiClass`: L, R or AL.
+    #[inline]
+    pub fn of_class(class: BidiClass) -> Option<Direction> {
+        match class {
+            L => Some(Direction::Ltr),
+            R | AL => Some(Direction::Rtl),
+            _ => None,
+        }
+    }
+
+    /// The lowest embedding level of this direction, as a paragraph level.
+    #[inline]
+    pub fn level(self) -> Level {
+        match self {
+            Direction::Ltr => LTR_LEVEL,
+            Direction::Rtl => RTL_LEVEL,
+        }
+    }
+}
+
+/// Iterator over the directions of the strong characters of the first paragraph of a text,
+/// skipping the characters between an isolate initiator and its matching PDI.
+struct StrongDirections<I> {
+    chars: I,
+    isolate_depth: usize,
+}
+
+impl<I: Iterator<Item = char>> StrongDirections<I> {
+    fn new(chars: I) -> StrongDirections<I> {
+        StrongDirections {
+            chars,
+            isolate_depth: 0,
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for StrongDirections<I> {
+    type Item = Direction;
+
+    fn next(&mut self) -> Option<Direction> {
+        for ch in &mut self.chars {
+            match BidiClass::of(ch) {
+                B => return None,
+                RLI | LRI | FSI => self.isolate_depth += 1,
+                PDI => self.isolate_depth = self.isolate_depth.saturating_sub(1),
+                class if self.isolate_depth == 0 => {
+                    if let Some(direction) = Direction::of_class(class) {
+                        return Some(direction);
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+/// Find the direction of the first paragraph of a text from its first strong character, or
+/// `None` if it has none.
+///
+/// Characters between an isolate initiator and its matching PDI, or the end of the paragraph,
+/// are skipped. This is rules P2 and P3, with P3's default left to the caller.
+///
+/// <https://www.unicode.org/reports/tr9/#P2>
+///
+/// ```rust
+/// # use unic_bidi::{first_strong_direction, Direction};
+/// assert_eq!(first_strong_direction("123 \u{5D0}bc"), Some(Direction::Rtl));
+/// assert_eq!(first_strong_direction("\u{2067}\u{5D0}\u{2069}bc"), Some(Direction::Ltr));
+/// assert_eq!(first_strong_direction("123"), None);
+/// ```
+pub fn first_strong_direction(text: &str) -> Option<Direction> {
+    first_strong_direction_of_chars(text.chars())
+}
+
+/// Find the direction of the first paragraph of a sequence of characters from its first strong
+/// character, or `None` if it has none.
+///
+/// See [`first_strong_direction`](fn.first_strong_direction.html).
+pub fn first_strong_direction_of_chars<I>(chars: I) -> Option<Direction>
+where
+    I: IntoIterator<Item = char>,
+{
+    StrongDirections::new(chars.into_iter()).next()
+}
+
+/// Guess the direction of the first paragraph of a text from the direction of most of its strong
+/// characters, or `None` if it has none.
+///
+/// Characters inside isolates are skipped, as for
+/// [`first_strong_direction`](fn.first_strong_direction.html), which also breaks ties. This is
+/// a heuristic for texts of unknown direction, such as user-generated content, rather than a rule
+/// of the algorithm.
+///
+/// ```rust
+/// # use unic_bidi::{dominant_direction, Direction};
+/// assert_eq!(dominant_direction("ok \u{5D0}\u{5D1}\u{5D2}"), Some(Direction::Rtl));
+/// assert_eq!(dominant_direction("\u{5D0} ok"), Some(Direction::Ltr));
+/// assert_eq!(dominant_direction("\u{5D0}\u{5D1} ok"), Some(Direction::Rtl));
+/// ```
+pub fn dominant_direction(text: &str) -> Option<Direction> {
+    dominant_direction_of_chars(text.chars())
+}
+
+/// Guess the direction of the first paragraph of a sequence of characters from the direction of
+/// most of its strong characters, or `None` if it has none.
+///
+/// See [`dominant_direction`](fn.dominant_direction.html).
+pub fn dominant_direction_of_chars<I>(chars: I) -> Option<Direction>
+where
+    I: IntoIterator<Item = char>,
+{
+    let mut first = None;
+    let (mut ltr_count, mut rtl_count) = (0usize, 0usize);
+    for direction in StrongDirections::new(chars.into_iter()) {
+        first = first.or(Some(direction));
+        match direction {
+            Direction::Ltr => ltr_count += 1,
+            Direction::Rtl => rtl_count += 1,
+        }
+    }
+    if ltr_count > rtl_count {
+        Some(Direction::Ltr)
+    } else if rtl_count > ltr_count {
+        Some(Direction::Rtl)
+    } else {
+        first
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_strong_direction() {
+        assert_eq!(first_strong_direction(""), None);
+        assert_eq!(first_strong_direction("abc"), Some(Direction::Ltr));
+        assert_eq!(first_strong_direction("\u{627}bc"), Some(Direction::Rtl));
+        assert_eq!(
+            first_strong_direction("1. \u{200F}abc"),
+            Some(Direction::Rtl)
+        );
+
+        // Isolates, nested or without a matching PDI, are skipped
+        assert_eq!(
+            first_strong_direction("\u{2066}\u{2067}a\u{2069}\u{5D0}\u{2069}b"),
+            Some(Direction::Ltr)
+        );
+        assert_eq!(first_strong_direction("\u{2068}\u{5D0}"), None);
+        assert_eq!(
+            first_strong_direction("\u{2069}\u{5D0}"),
+            Some(Direction::Rtl)
+        );
+
+        // Embeddings are not skipped
+        assert_eq!(
+            first_strong_direction("\u{202B}abc\u{202C}"),
+            Some(Direction::Ltr)
+        );
+
+        // Only the first paragraph counts
+        assert_eq!(first_strong_direction("123\n\u{5D0}"), None);
+
+        let utf16: Vec<u16> = "1 \u{5D0}".encode_utf16().collect();
+        let chars = std::char::decode_utf16(utf16).map(Result::unwrap);
+        assert_eq!(first_strong_direction_of_chars(chars), Some(Direction::Rtl));
+    }
+
+    #[test]
+    fn test_dominant_direction() {
+        assert_eq!(dominant_direction("123"), None);
+        assert_eq!(dominant_direction("a \u{5D0}\u{5D1}"), Some(Direction::Rtl));
+        assert_eq!(dominant_direction("\u{5D0} ab"), Some(Direction::Ltr));
+        assert_eq!(dominant_direction("a \u{5D0}"), Some(Direction::Ltr));
+        assert_eq!(dominant_direction("\u{5D0} a"), Some(Direction::Rtl));
+        assert_eq!(
+            dominant_direction("\u{5D0} \u{2066}abc\u{2069}"),
+            Some(Direction::Rtl)
+        );
+        assert_eq!(
+            dominant_direction("\u{5D0}\u{5D1}\nabc"),
+            Some(Direction::Rtl)
+        );
+        assert_eq!(
+            dominant_direction_of_chars(vec!['a', '\u{627}', '\u{628}']),
+            Some(Direction::Rtl)
+        );
+    }
+
+    #[test]
+    fn test_direction_level() {
+        assert_eq!(Direction::Ltr.level(), Level::ltr());
+        assert_eq!(Direction::Rtl.level(), Level::rtl());
+        assert_eq!(Direction::of_class(AL), Some(Direction::Rtl));
+        assert_eq!(Direction::of_class(EN), None);
+    }
+}
@@ -13,6 +13,7 @@
 //!
 //! <https://www.unicode.org/reports/tr9/#Explicit_Levels_and_Directions>
 
+use unic_common::source::CodeUnitSource;
 use unic_ucd_bidi::bidi_class::abbr_names::*;
 use unic_ucd_bidi::BidiClass;
 
@@ -20,10 +21,10 @@ use super::level::Level;
 
 /// Compute explicit embedding levels for one paragraph of text (X1-X8).
 ///
-/// `processing_classes[i]` must contain the `BidiClass` of the char at byte index `i`,
+/// `processing_classes[i]` must contain the `BidiClass` of the char at code unit index `i`,
 /// for each char in `text`.
-pub fn compute(
-    text: &str,
+pub fn compute<T: CodeUnitSource + ?Sized>(
+    text: &T,
     para_level: Level,
     original_classes: &[BidiClass],
     levels: &mut [Level],
@@ -39,7 +40,7 @@ pub fn compute(
     let mut overflow_embedding_count = 0u32;
     let mut valid_isolate_count = 0u32;
 
-    for (i, c) in text.char_indices() {
+    for (i, _, len) in text.char_indices() {
         match original_classes[i] {
             // Rules X2-X5c
             RLE | LRE | RLO | LRO | RLI | LRI | FSI => {
@@ -149,8 +150,8 @@ pub fn compute(
             }
         }
 
-        // Handle multi-byte characters.
-        for j in 1..c.len_utf8() {
+        // Handle multi-code-unit characters.
+        for j in 1..len {
             levels[i + j] = levels[i];
             processing_classes[i + j] = processing_classes[i];
         }
@@ -13,8 +13,9 @@
 
 use std::cmp::max;
 
+use unic_common::source::CodeUnitSource;
 use unic_ucd_bidi::bidi_class::abbr_names::*;
-use unic_ucd_bidi::BidiClass;
+use unic_ucd_bidi::{bidi_paired_bracket, BidiClass, BidiPairedBracketType};
 
 use super::level::Level;
 use super::prepare::{not_removed_by_x9, removed_by_x9, IsolatingRunSequence, LevelRun};
@@ -22,15 +23,15 @@ use super::prepare::{not_removed_by_x9, removed_by_x9, IsolatingRunSequence, Lev
 /// 3.3.4 Resolving Weak Types
 ///
 /// <https://www.unicode.org/reports/tr9/#Resolving_Weak_Types>
-pub fn resolve_weak(sequence: &IsolatingRunSequence, processing_classes: &mut [BidiClass]) {
-    // FIXME (#8): This function applies steps W1-W6 in a single pass.  This can produce
-    // incorrect results in cases where a "later" rule changes the value of `prev_class` seen
-    // by an "earlier" rule.  We should either split this into separate passes, or preserve
-    // extra state so each rule can see the correct previous class.
-
-    // FIXME: Also, this could be the cause of increased failure for using longer-UTF-8 chars in
-    // conformance tests, like BidiTest:69635 (AL ET EN)
-
+pub fn resolve_weak<T: CodeUnitSource + ?Sized>(
+    text: &T,
+    sequence: &IsolatingRunSequence,
+    processing_classes: &mut [BidiClass],
+) {
+    // W1-W6 are applied in a single pass, so each rule keeps its own view of the previous
+    // character: W1 looks at the classes before W3 has run, W4 before W5, and W5 and W6 after.
+    let mut prev_class_before_w3 = sequence.sos;
+    let mut prev_class_before_w5 = sequence.sos;
     let mut prev_class = sequence.sos;
     let mut last_strong_is_al = false;
     let mut et_run_indices = Vec::new(); // for W5
@@ -46,73 +47,96 @@ pub fn resolve_weak(sequence: &IsolatingRunSequence, processing_classes: &mut [B
         .flat_map(id as fn(LevelRun) -> LevelRun);
 
     while let Some(i) = indices.next() {
-        match processing_classes[i] {
-            // https://www.unicode.org/reports/tr9/#W1
-            NSM => {
-                processing_classes[i] = match prev_class {
-                    RLI | LRI | FSI | PDI => ON,
-                    _ => prev_class,
-                };
-            }
-            EN => {
-                if last_strong_is_al {
-                    // W2. If previous strong char was AL, change EN to AN.
-                    processing_classes[i] = AN;
-                } else {
-                    // W5. If a run of ETs is adjacent to an EN, change the ETs to EN.
-                    for j in &et_run_indices {
-                        processing_classes[*j] = EN;
-                    }
-                    et_run_indices.clear();
-                }
-            }
-            // <https://www.unicode.org/reports/tr9/#W3>
-            AL => processing_classes[i] = R,
-
-            // <https://www.unicode.org/reports/tr9/#W4>
-            ES | CS => {
-                let next_class = indices
-                    .clone()
-                    .map(|j| processing_classes[j])
-                    .find(not_removed_by_x9)
-                    .unwrap_or(sequence.eos);
-                processing_classes[i] = match (prev_class, processing_classes[i], next_class) {
-                    (EN, ES, EN) | (EN, CS, EN) => EN,
-                    (AN, CS, AN) => AN,
-                    (_, _, _) => ON,
-                }
-            }
-            // <https://www.unicode.org/reports/tr9/#W5>
-            ET => {
-                match prev_class {
-                    EN => processing_classes[i] = EN,
-                    _ => et_run_indices.push(i), // In case this is followed by an EN.
-                }
-            }
-            class => {
-                if removed_by_x9(class) {
-                    continue;
-                }
-            }
+        // Only the first code unit of each character is resolved here; the rest copy it below.
+        if !text.is_char_boundary(i) || removed_by_x9(processing_classes[i]) {
+            continue;
         }
 
-        prev_class = processing_classes[i];
-        match prev_class {
-            L | R => {
-                last_strong_is_al = false;
-            }
-            AL => {
-                last_strong_is_al = true;
+        let mut class = processing_classes[i];
+
+        // <https://www.unicode.org/reports/tr9/#W1>
+        if class == NSM {
+            class = match prev_class_before_w3 {
+                RLI | LRI | FSI | PDI => ON,
+                _ => prev_class_before_w3,
+            };
+        }
+
+        // <https://www.unicode.org/reports/tr9/#W2>
+        match class {
+            EN if last_strong_is_al => class = AN,
+            AL => last_strong_is_al = true,
+            L | R => last_strong_is_al = false,
+            _ => {}
+        }
+        prev_class_before_w3 = class;
+
+        // <https://www.unicode.org/reports/tr9/#W3>
+        if class == AL {
+            class = R;
+        }
+
+        // <https://www.unicode.org/reports/tr9/#W4>
+        if class == ES || class == CS {
+            let next_class = match indices
+                .clone()
+                .filter(|&j| text.is_char_boundary(j))
+                .map(|j| processing_classes[j])
+                .find(not_removed_by_x9)
+                .unwrap_or(sequence.eos)
+            {
+                // W2 has not been applied to the next character yet.
+                EN if last_strong_is_al => AN,
+                next_class => next_class,
+            };
+            class = match (prev_class_before_w5, class, next_class) {
+                (EN, ES, EN) | (EN, CS, EN) => EN,
+                (AN, CS, AN) => AN,
+                (_, _, _) => ON,
+            };
+        }
+        prev_class_before_w5 = class;
+
+        // <https://www.unicode.org/reports/tr9/#W5>
+        match class {
+            ET if prev_class == EN => class = EN,
+            ET => et_run_indices.push(i), // In case this is followed by an EN.
+            EN => {
+                for j in &et_run_indices {
+                    processing_classes[*j] = EN;
+                }
+                et_run_indices.clear();
             }
             _ => {}
         }
-        if prev_class != ET {
-            // W6. If we didn't find an adjacent EN, turn any ETs into ON instead.
+
+        // <https://www.unicode.org/reports/tr9/#W6>
+        if class != ET {
+            // If we didn't find an adjacent EN, turn any ETs into ON instead.
             for j in &et_run_indices {
                 processing_classes[*j] = ON;
             }
             et_run_indices.clear();
         }
+        if matches!(class, ES | ET | CS) {
+            class = ON;
+        }
+
+        processing_classes[i] = class;
+        prev_class = class;
+    }
+    // W6 also applies to ETs at the end of the sequence.
+    for j in &et_run_indices {
+        processing_classes[*j] = ON;
+    }
+
+    // The remaining code units of each character take the class of its first one.
+    for run in &sequence.runs {
+        for i in run.clone() {
+            if !text.is_char_boundary(i) {
+                processing_classes[i] = processing_classes[i - 1];
+            }
+        }
     }
 
     // W7. If the previous strong char was L, change EN to L.
@@ -138,18 +162,25 @@ pub fn resolve_weak(sequence: &IsolatingRunSequence, processing_classes: &mut [B
 /// 3.3.5 Resolving Neutral Types
 ///
 /// <https://www.unicode.org/reports/tr9/#Resolving_Neutral_Types>
-pub fn resolve_neutral(
+pub fn resolve_neutral<T: CodeUnitSource + ?Sized>(
+    text: &T,
     sequence: &IsolatingRunSequence,
     levels: &[Level],
+    original_classes: &[BidiClass],
     processing_classes: &mut [BidiClass],
 ) {
     let e: BidiClass = levels[sequence.runs[0].start].bidi_class();
+
+    // N0. Process bracket pairs.
+    resolve_bracket_pairs(text, sequence, e, original_classes, processing_classes);
+
     let mut indices = sequence.runs.iter().flat_map(Clone::clone);
     let mut prev_class = sequence.sos;
 
     while let Some(mut i) = indices.next() {
-        // N0. Process bracket pairs.
-        // TODO
+        if removed_by_x9(processing_classes[i]) {
+            continue;
+        }
 
         // Process sequences of NI characters.
         let mut ni_run = Vec::new();
@@ -204,6 +235,141 @@ pub fn resolve_neutral(
     }
 }
 
+/// A bracket pair, as positions of the opening and closing brackets in the isolating run sequence.
+#[derive(Debug, PartialEq)]
+struct BracketPair {
+    start: usize,
+    end: usize,
+}
+
+/// Maximum nesting of opening brackets waiting for a match.
+///
+/// <https://www.unicode.org/reports/tr9/#BD16>
+const MAX_BRACKET_DEPTH: usize = 63;
+
+/// The bracket that brackets are matched by, which is the same for canonical equivalents.
+fn canonical_bracket(ch: char) -> char {
+    match ch {
+        '\u{2329}' => '\u{3008}',
+        '\u{232A}' => '\u{3009}',
+        _ => ch,
+    }
+}
+
+/// BD16. Identify the bracket pairs in an isolating run sequence, given as the indices of its
+/// characters, sorted by the positions of their opening brackets.
+///
+/// <https://www.unicode.org/reports/tr9/#BD16>
+fn identify_bracket_pairs<T: CodeUnitSource + ?Sized>(
+    text: &T,
+    indices: &[usize],
+    processing_classes: &[BidiClass],
+) -> Vec<BracketPair> {
+    let mut pairs = Vec::new();
+    // The closing bracket expected for each open bracket, and the position of the latter
+    let mut stack: Vec<(char, usize)> = Vec::with_capacity(MAX_BRACKET_DEPTH);
+
+    for (position, &i) in indices.iter().enumerate() {
+        if processing_classes[i] != ON || !text.is_char_boundary(i) {
+            continue;
+        }
+        let ch = match text.char_at(i) {
+            Some((ch, _)) => ch,
+            None => continue,
+        };
+        match BidiPairedBracketType::of(ch) {
+            BidiPairedBracketType::Open => {
+                if stack.len() == MAX_BRACKET_DEPTH {
+                    break;
+                }
+                let closing = bidi_paired_bracket(ch).expect("Bracket without a pair");
+                stack.push((canonical_bracket(closing), position));
+            }
+            BidiPairedBracketType::Close => {
+                let closing = canonical_bracket(ch);
+                if let Some(depth) = stack.iter().rposition(|&(c, _)| c == closing) {
+                    pairs.push(BracketPair {
+                        start: stack[depth].1,
+                        end: position,
+                    });
+                    stack.truncate(depth);
+                }
+            }
+            BidiPairedBracketType::None => {}
+        }
+    }
+
+    pairs.sort_by_key(|pair| pair.start);
+    pairs
+}
+
+/// The direction of a strong type, with EN and AN counted as R.
+fn strong_direction(class: BidiClass) -> Option<BidiClass> {
+    match class {
+        L => Some(L),
+        R | AL | EN | AN => Some(R),
+        _ => None,
+    }
+}
+
+/// N0. Resolve each bracket pair to the embedding direction, or to the direction of the context
+/// before it, depending on the strong types it encloses.
+///
+/// <https://www.unicode.org/reports/tr9/#N0>
+fn resolve_bracket_pairs<T: CodeUnitSource + ?Sized>(
+    text: &T,
+    sequence: &IsolatingRunSequence,
+    e: BidiClass,
+    original_classes: &[BidiClass],
+    processing_classes: &mut [BidiClass],
+) {
+    let indices: Vec<usize> = sequence.runs.iter().flat_map(Clone::clone).collect();
+
+    for pair in identify_bracket_pairs(text, &indices, processing_classes) {
+        // N0 b. A strong type matching the embedding direction inside the brackets wins.
+        let mut found_opposite = false;
+        let mut new_class = None;
+        for &i in &indices[pair.start + 1..pair.end] {
+            match strong_direction(processing_classes[i]) {
+                Some(class) if class == e => {
+                    new_class = Some(e);
+                    break;
+                }
+                Some(_) => found_opposite = true,
+                None => {}
+            }
+        }
+
+        // N0 c. Otherwise, with only the opposite direction inside, the brackets take the
+        // direction of the first strong type before them, or `sos`.
+        if new_class.is_none() && found_opposite {
+            let context = indices[..pair.start]
+                .iter()
+                .rev()
+                .filter_map(|&i| strong_direction(processing_classes[i]))
+                .next()
+                .unwrap_or(sequence.sos);
+            new_class = Some(context);
+        }
+
+        // N0 d. Brackets with no strong types inside are left for the next rules.
+        if let Some(class) = new_class {
+            for &position in &[pair.start, pair.end] {
+                let i = indices[position];
+                let end = i + text.char_at(i).unwrap().1;
+                // Nonspacing marks following a bracket change along with it.
+                for &j in &indices[position..] {
+                    if j < end || original_classes[j] == NSM {
+                        processing_classes[j] = class;
+                    } else if !removed_by_x9(original_classes[j]) {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
 /// 3.3.6 Resolving Implicit Levels
 ///
 /// Returns the maximum embedding level in the paragraph.
@@ -0,0 +1,321 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Inserting text of any direction into other text, such as a user name into a message, without
+//! either of them affecting the order of the other.
+//!
+//! <https://www.unicode.org/reports/tr9/#Directional_Formatting_Characters>
+
+use std::borrow::Cow;
+
+use unic_ucd_bidi::bidi_class::abbr_names::*;
+use unic_ucd_bidi::BidiClass;
+
+use crate::direction::{first_strong_direction, Direction};
+use crate::format_chars;
+
+/// Wrap a text in a directional isolate, for inserting it into other text.
+///
+/// The isolate is an RLI or an LRI if the direction of the text is given, or an FSI otherwise,
+/// for its direction to be found from its first strong character. Unbalanced directional
+/// formatting characters are removed from the text first, so that it cannot end the isolate
+/// early or affect the text after it. A paragraph separator would end the isolate too, so each
+/// paragraph of the text is isolated on its own.
+///
+/// ```rust
+/// # use unic_bidi::{isolate, Direction};
+/// assert_eq!(isolate("abc", None), "\u{2068}abc\u{2069}");
+/// assert_eq!(isolate("abc", Some(Direction::Rtl)), "\u{2067}abc\u{2069}");
+/// assert_eq!(isolate("ab\u{2069}c", Some(Direction::Ltr)), "\u{2066}abc\u{2069}");
+/// assert_eq!(isolate("a\nb", None), "\u{2068}a\u{2069}\n\u{2068}b\u{2069}");
+/// ```
+pub fn isolate(text: &str, direction: Option<Direction>) -> String {
+    let text = strip_unbalanced_formatting(text);
+    let initiator = match direction {
+        Some(Direction::Ltr) => format_chars::LRI,
+        Some(Direction::Rtl) => format_chars::RLI,
+        None => format_chars::FSI,
+    };
+
+    map_paragraphs(&text, 6, |paragraph, result| {
+        result.push(initiator);
+        result.push_str(paragraph);
+        result.push(format_chars::PDI);
+    })
+}
+
+/// Wrap a text in directional marks and, if needed, an embedding, for inserting it into other
+/// text of the `context` direction, where isolates are not supported.
+///
+/// If its direction is not given, the text gets that of its first strong character. A text of the
+/// opposite direction to the context is wrapped in an RLE or an LRE and a PDF, and the result is
+/// surrounded by the mark of the context direction, an LRM or an RLM, so that neutral characters
+/// around it take the context direction. Unbalanced directional formatting characters are removed
+/// from the text first, and each paragraph of the text is wrapped on its own.
+///
+/// This is not as robust as [`isolate`](fn.isolate.html), since the text can still affect the
+/// numbers that follow it.
+///
+/// ```rust
+/// # use unic_bidi::{isolate_with_marks, Direction};
+/// assert_eq!(
+///     isolate_with_marks("abc", None, Direction::Rtl),
+///     "\u{200F}\u{202A}abc\u{202C}\u{200F}"
+/// );
+/// assert_eq!(
+///     isolate_with_marks("abc", None, Direction::Ltr),
+///     "\u{200E}abc\u{200E}"
+/// );
+/// ```
+pub fn isolate_with_marks(text: &str, direction: Option<Direction>, context: Direction) -> String {
+    let text = strip_unbalanced_formatting(text);
+    let mark = match context {
+        Direction::Ltr => format_chars::LRM,
+        Direction::Rtl => format_chars::RLM,
+    };
+
+    map_paragraphs(&text, 12, |paragraph, result| {
+        let embedding = match direction.or_else(|| first_strong_direction(paragraph)) {
+            Some(direction) if direction == context => None,
+            Some(Direction::Ltr) => Some(format_chars::LRE),
+            Some(Direction::Rtl) => Some(format_chars::RLE),
+            None => None,
+        };
+
+        result.push(mark);
+        match embedding {
+            Some(embedding) => {
+                result.push(embedding);
+                result.push_str(paragraph);
+                result.push(format_chars::PDF);
+            }
+            None => result.push_str(paragraph),
+        }
+        result.push(mark);
+    })
+}
+
+/// Append each paragraph of a text with `f`, and the paragraph separators between them as-is, to
+/// a new string, with room for `extra` more bytes. Empty paragraphs are skipped, unless the text
+/// is empty.
+fn map_paragraphs<F>(text: &str, extra: usize, mut f: F) -> String
+where
+    F: FnMut(&str, &mut String),
+{
+    let mut result = String::with_capacity(text.len() + extra);
+    let mut start = 0;
+    for (i, ch) in text.char_indices() {
+        if BidiClass::of(ch) == B {
+            if i > start {
+                f(&text[start..i], &mut result);
+            }
+            result.push(ch);
+            start = i + ch.len_utf8();
+        }
+    }
+    if start < text.len() || text.is_empty() {
+        f(&text[start..], &mut result);
+    }
+    result
+}
+
+/// Whether a text has directional formatting characters without a match: embeddings, overrides
+/// or isolates that are not terminated, or terminators that do not end any.
+///
+/// An embedding or override must end with a PDF before the end of the isolate it is in, and before
+/// the end of the paragraph.
+///
+/// ```rust
+/// # use unic_bidi::has_unbalanced_formatting;
+/// assert!(!has_unbalanced_formatting("a\u{202B}b\u{202C}c"));
+/// assert!(has_unbalanced_formatting("a\u{202B}bc"));
+/// assert!(has_unbalanced_formatting("abc\u{2069}"));
+/// ```
+pub fn has_unbalanced_formatting(text: &str) -> bool {
+    let mut unbalanced = false;
+    for_each_unbalanced_formatting(text, |_| unbalanced = true);
+    unbalanced
+}
+
+/// Remove the directional formatting characters without a match from a text.
+///
+/// See [`has_unbalanced_formatting`](fn.has_unbalanced_formatting.html) for which are removed.
+///
+/// ```rust
+/// # use unic_bidi::strip_unbalanced_formatting;
+/// assert_eq!(strip_unbalanced_formatting("a\u{202B}b\u{202C}c"), "a\u{202B}b\u{202C}c");
+/// assert_eq!(strip_unbalanced_formatting("\u{2067}a\u{202E}bc"), "abc");
+/// assert_eq!(strip_unbalanced_formatting("ab\u{202C}\u{2069}c"), "abc");
+/// ```
+pub fn strip_unbalanced_formatting(text: &str) -> Cow<'_, str> {
+    let mut unbalanced = Vec::new();
+    for_each_unbalanced_formatting(text, |i| unbalanced.push(i));
+    if unbalanced.is_empty() {
+        return text.into();
+    }
+
+    unbalanced.sort();
+    let mut result = String::with_capacity(text.len());
+    let mut start = 0;
+    for i in unbalanced {
+        result.push_str(&text[start..i]);
+        // All directional formatting characters are three bytes long.
+        start = i + 3;
+    }
+    result.push_str(&text[start..]);
+    result.into()
+}
+
+/// Call `f` with the byte index of each directional formatting character without a match.
+pub(crate) fn for_each_unbalanced_formatting<F: FnMut(usize)>(text: &str, mut f: F) {
+    // The starting byte index of each embedding, override and isolate we're inside, and whether
+    // it is an isolate.
+    let mut stack: Vec<(usize, bool)> = Vec::new();
+
+    for (i, c) in text.char_indices() {
+        match BidiClass::of(c) {
+            RLE | LRE | RLO | LRO => stack.push((i, false)),
+            RLI | LRI | FSI => stack.push((i, true)),
+            PDF => match stack.last() {
+                Some(&(_, false)) => {
+                    stack.pop();
+                }
+                _ => f(i),
+            },
+            PDI => {
+                if stack.iter().any(|&(_, is_isolate)| is_isolate) {
+                    // Embeddings inside the isolate are ended with it, without their PDF.
+                    while let Some((start, is_isolate)) = stack.pop() {
+                        if is_isolate {
+                            break;
+                        }
+                        f(start);
+                    }
+                } else {
+                    f(i);
+                }
+            }
+            B => {
+                for (start, _) in stack.drain(..) {
+                    f(start);
+                }
+            }
+            _ => {}
+        }
+    }
+    for (start, _) in stack {
+        f(start);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use unic_ucd_bidi::BidiClassCategory;
+
+    use crate::BidiInfo;
+
+    fn reorder(text: &str) -> String {
+        let bidi_info = BidiInfo::new(text, None);
+        let para = &bidi_info.paragraphs[0];
+        bidi_info
+            .reorder_line(para, para.range.clone())
+            .chars()
+            .filter(|&c| BidiClass::of(c).category() != BidiClassCategory::ExplicitFormatting)
+            .filter(|&c| c != format_chars::LRM && c != format_chars::RLM)
+            .collect()
+    }
+
+    #[test]
+    fn test_isolate() {
+        assert_eq!(isolate("", None), "\u{2068}\u{2069}");
+        assert_eq!(
+            isolate("\u{5D0}", Some(Direction::Ltr)),
+            "\u{2066}\u{5D0}\u{2069}"
+        );
+
+        // An RTL message with an LTR name and punctuation after it
+        let message = format!("\u{5D0}\u{5D1} {}!", isolate("Bob 2", None));
+        assert_eq!(reorder(&message), "!Bob 2 \u{5D1}\u{5D0}");
+
+        // An LTR message with an RTL name, followed by a number
+        let message = format!("User {} 5 points", isolate("\u{5D2}\u{5D3}", None));
+        assert_eq!(reorder(&message), "User \u{5D3}\u{5D2} 5 points");
+        let message = format!("User {} 5 points", "\u{5D2}\u{5D3}");
+        assert_eq!(reorder(&message), "User 5 \u{5D3}\u{5D2} points");
+
+        // Paragraph separators would end the isolate
+        assert_eq!(
+            isolate("a\nb", None),
+            "\u{2068}a\u{2069}\n\u{2068}b\u{2069}"
+        );
+        assert_eq!(
+            isolate("\u{5D0}\r\n\u{2029}b\n", Some(Direction::Rtl)),
+            "\u{2067}\u{5D0}\u{2069}\r\n\u{2029}\u{2067}b\u{2069}\n"
+        );
+        assert!(!has_unbalanced_formatting(&isolate("a\nb", None)));
+    }
+
+    #[test]
+    fn test_isolate_with_marks() {
+        assert_eq!(
+            isolate_with_marks("\u{5D0}", None, Direction::Ltr),
+            "\u{200E}\u{202B}\u{5D0}\u{202C}\u{200E}"
+        );
+        assert_eq!(
+            isolate_with_marks("123", None, Direction::Rtl),
+            "\u{200F}123\u{200F}"
+        );
+        assert_eq!(
+            isolate_with_marks("123", Some(Direction::Ltr), Direction::Rtl),
+            "\u{200F}\u{202A}123\u{202C}\u{200F}"
+        );
+        assert_eq!(
+            isolate_with_marks("a\u{202C}", None, Direction::Ltr),
+            "\u{200E}a\u{200E}"
+        );
+        assert_eq!(
+            isolate_with_marks("a\nb", None, Direction::Rtl),
+            "\u{200F}\u{202A}a\u{202C}\u{200F}\n\u{200F}\u{202A}b\u{202C}\u{200F}"
+        );
+
+        let message = format!(
+            "\u{5D0}\u{5D1} {}!",
+            isolate_with_marks("Bob", None, Direction::Rtl)
+        );
+        assert_eq!(reorder(&message), "!Bob \u{5D1}\u{5D0}");
+    }
+
+    #[test]
+    fn test_unbalanced_formatting() {
+        assert!(!has_unbalanced_formatting(""));
+        assert!(!has_unbalanced_formatting(
+            "\u{2066}a\u{202B}b\u{202C}\u{2069}"
+        ));
+        assert!(has_unbalanced_formatting("\u{202C}"));
+        assert!(has_unbalanced_formatting("\u{2066}\u{202C}\u{2069}"));
+        assert!(has_unbalanced_formatting("\u{202D}\n\u{202C}"));
+
+        assert_eq!(
+            strip_unbalanced_formatting("\u{2066}a\u{202B}b\u{2069}c"),
+            "\u{2066}ab\u{2069}c"
+        );
+        assert_eq!(
+            strip_unbalanced_formatting("\u{202A}a\u{2067}b\u{202C}"),
+            "ab"
+        );
+        assert_eq!(strip_unbalanced_formatting("a\u{2068}\nb"), "a\nb");
+        match strip_unbalanced_formatting("\u{202B}a\u{202C}") {
+            Cow::Borrowed(_) => {}
+            Cow::Owned(_) => panic!("Balanced text copied"),
+        }
+    }
+}
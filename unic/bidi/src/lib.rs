@@ -78,6 +78,7 @@ extern crate matches;
 #[macro_use]
 extern crate serde;
 
+pub use unic_common::source::{CharIndices, CodeUnitSource};
 pub use unic_ucd_bidi::UNICODE_VERSION;
 pub use unic_ucd_bidi::{bidi_class, BidiClass, BidiClassCategory};
 
@@ -89,12 +90,35 @@ pub mod format_chars;
 pub mod level;
 pub use crate::level::Level;
 
+mod audit;
+pub use crate::audit::{audit, audit_source, AuditFinding, AuditKind, SourceSyntax};
+
 mod bidi_info;
-pub use crate::bidi_info::{BidiInfo, ParagraphInfo};
+pub use crate::bidi_info::{BidiInfo, LtrOrBidiInfo, ParagraphInfo, ReorderedLine};
+
+mod direction;
+pub use crate::direction::{
+    dominant_direction,
+    dominant_direction_of_chars,
+    first_strong_direction,
+    first_strong_direction_of_chars,
+    Direction,
+};
 
 mod explicit;
 
 mod implicit;
 
+mod isolate;
+pub use crate::isolate::{
+    has_unbalanced_formatting,
+    isolate,
+    isolate_with_marks,
+    strip_unbalanced_formatting,
+};
+
+mod options;
+pub use crate::options::BidiOptions;
+
 mod prepare;
 pub use crate::prepare::LevelRun;
@@ -0,0 +1,114 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Higher-Level Protocols
+//!
+//! <https://www.unicode.org/reports/tr9/#Higher-Level_Protocols>
+
+use std::ops::Range;
+
+use unic_ucd_bidi::BidiClass;
+
+use crate::level::{Level, LTR_LEVEL};
+
+/// Options for resolving the embedding levels of a text, for the higher-level protocols that
+/// override parts of the algorithm
+///
+/// By default, the level of each paragraph is that of its first strong character, or left-to-right
+/// if it has none (P2, P3), and each character has its own `BidiClass`.
+///
+/// ```rust
+/// # use unic_bidi::{BidiClass, BidiInfo, BidiOptions, Level};
+/// // Treat the markup as a neutral, and fall back to right-to-left (auto-RTL)
+/// let text = "<b>123</b>";
+/// let options = BidiOptions::new()
+///     .fallback_level(Level::rtl())
+///     .override_class(0..3, BidiClass::OtherNeutral)
+///     .override_class(6..10, BidiClass::OtherNeutral);
+/// let bidi_info = BidiInfo::new_with_options(text, &options);
+/// assert_eq!(bidi_info.paragraphs[0].level, Level::rtl());
+/// ```
+///
+/// <https://www.unicode.org/reports/tr9/#Higher-Level_Protocols>
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BidiOptions {
+    pub(crate) para_level: Option<Level>,
+    pub(crate) fallback_level: Level,
+    pub(crate) inherit_direction: bool,
+    pub(crate) class_overrides: Vec<(Range<usize>, BidiClass)>,
+}
+
+impl BidiOptions {
+    /// Options for the algorithm as specified, without any higher-level protocol.
+    pub fn new() -> BidiOptions {
+        BidiOptions {
+            para_level: None,
+            fallback_level: LTR_LEVEL,
+            inherit_direction: false,
+            class_overrides: Vec::new(),
+        }
+    }
+
+    /// Options with the given paragraph level, if any, as taken by `BidiInfo::new()`.
+    pub(crate) fn from_default_para_level(default_para_level: Option<Level>) -> BidiOptions {
+        match default_para_level {
+            Some(level) => BidiOptions::new().para_level(level),
+            None => BidiOptions::new(),
+        }
+    }
+
+    /// Set the level of every paragraph, instead of finding it from the first strong character.
+    ///
+    /// <https://www.unicode.org/reports/tr9/#HL1>
+    pub fn para_level(mut self, level: Level) -> BidiOptions {
+        self.para_level = Some(level);
+        self
+    }
+
+    /// Set the level of the paragraphs without a strong character, instead of left-to-right.
+    ///
+    /// Setting it to `Level::rtl()` gives the *auto-RTL* direction: that of the first strong
+    /// character, or right-to-left if there is none.
+    ///
+    /// <https://www.unicode.org/reports/tr9/#HL1>
+    pub fn fallback_level(mut self, level: Level) -> BidiOptions {
+        self.fallback_level = level;
+        self
+    }
+
+    /// Give the paragraphs without a strong character the level of the previous paragraph.
+    ///
+    /// The first paragraph of the text still falls back to the `fallback_level()`.
+    ///
+    /// <https://www.unicode.org/reports/tr9/#HL1>
+    pub fn inherit_direction(mut self, inherit: bool) -> BidiOptions {
+        self.inherit_direction = inherit;
+        self
+    }
+
+    /// Treat the characters in a range of code units of the text as if they were of the given
+    /// class. The code units are bytes for `str` text, and 16-bit units for `[u16]` text.
+    ///
+    /// This can be used to treat markup as neutral, or to emulate directional formatting
+    /// characters. When ranges overlap, the last one set wins. The range must fall on character
+    /// boundaries of the text.
+    ///
+    /// <https://www.unicode.org/reports/tr9/#HL3>
+    pub fn override_class(mut self, range: Range<usize>, class: BidiClass) -> BidiOptions {
+        self.class_overrides.push((range, class));
+        self
+    }
+}
+
+impl Default for BidiOptions {
+    fn default() -> BidiOptions {
+        BidiOptions::new()
+    }
+}
@@ -114,8 +114,13 @@ pub fn isolating_run_sequences(
                 None => para_level,
             };
 
-            // Get the level of the next non-removed char after the runs.
-            let succ_level = if matches!(original_classes[end_of_seq - 1], RLI | LRI | FSI) {
+            // Get the level of the next non-removed char after the runs, unless the last
+            // non-removed char of the runs is an isolate initiator.
+            let last_class = original_classes[sequence[sequence.len() - 1].clone()]
+                .iter()
+                .rev()
+                .find(|class| not_removed_by_x9(class));
+            let succ_level = if matches!(last_class, Some(RLI) | Some(LRI) | Some(FSI)) {
                 para_level
             } else {
                 match original_classes[end_of_seq..]
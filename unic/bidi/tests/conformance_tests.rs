@@ -28,11 +28,10 @@ struct Fail {
     pub exp_ordering: Vec<String>,
     pub actual_base_level: Option<Level>,
     pub actual_levels: Vec<Level>,
-    // TODO pub actual_ordering: Vec<String>,
+    pub actual_ordering: Vec<String>,
 }
 
 #[test]
-#[should_panic(expected = "314 test cases failed! (256433 passed)")]
 fn test_basic_conformance() {
     // Test set state
     let mut passed_num: i32 = 0;
@@ -88,7 +87,11 @@ fn test_basic_conformance() {
                 let exp_levels: Vec<String> = exp_levels.iter().map(|x| x.to_owned()).collect();
                 let para = &bidi_info.paragraphs[0];
                 let levels = bidi_info.reordered_levels_per_char(para, para.range.clone());
-                if levels != exp_levels {
+
+                // Check reorder map
+                let ordering = get_ordering(&bidi_info);
+
+                if levels != exp_levels || ordering != exp_ordering {
                     fails.push(Fail {
                         line_num: line_idx + 1,
                         input_base_level,
@@ -99,13 +102,11 @@ fn test_basic_conformance() {
                         exp_ordering: exp_ordering.to_owned(),
                         actual_base_level: None,
                         actual_levels: levels.to_owned(),
+                        actual_ordering: ordering,
                     });
                 } else {
                     passed_num += 1;
                 }
-
-                // Check reorder map
-                // TODO: Add reorder map to API output and test the map here
             }
         }
     }
@@ -131,19 +132,17 @@ fn test_basic_conformance() {
     }
 }
 
-// TODO: Support auto-RTL
 fn gen_base_levels_for_base_tests(bitset: u8) -> Vec<Option<Level>> {
     /// Values: auto-LTR, LTR, RTL
     const VALUES: &[Option<Level>] = &[None, Some(level::LTR_LEVEL), Some(level::RTL_LEVEL)];
     assert!(bitset < (1 << VALUES.len()));
     (0..VALUES.len())
-        .filter(|bit| bitset & (1u8 << bit) == 1)
+        .filter(|bit| bitset & (1u8 << bit) != 0)
         .map(|idx| VALUES[idx])
         .collect()
 }
 
 #[test]
-#[should_panic(expected = "14558 test cases failed! (77141 passed)")]
 fn test_character_conformance() {
     // Test set state
     let mut passed_num: i32 = 0;
@@ -180,7 +179,11 @@ fn test_character_conformance() {
             // Check levels
             let para = &bidi_info.paragraphs[0];
             let levels = bidi_info.reordered_levels_per_char(para, para.range.clone());
-            if levels != exp_levels {
+
+            // Check reorder map
+            let ordering = get_ordering(&bidi_info);
+
+            if levels != exp_levels || ordering != exp_ordering {
                 fails.push(Fail {
                     line_num: line_idx + 1,
                     input_base_level,
@@ -191,13 +194,11 @@ fn test_character_conformance() {
                     exp_ordering: exp_ordering.to_owned(),
                     actual_base_level: None,
                     actual_levels: levels.to_owned(),
+                    actual_ordering: ordering,
                 });
             } else {
                 passed_num += 1;
             }
-
-            // Check reorder map
-            // TODO: Add reorder map to API output and test the map here
         }
     }
 
@@ -222,7 +223,6 @@ fn test_character_conformance() {
     }
 }
 
-// TODO: Support auto-RTL
 fn gen_base_level_for_characters_tests(idx: usize) -> Option<Level> {
     /// Values: LTR, RTL, auto-LTR
     const VALUES: &[Option<Level>] = &[Some(level::LTR_LEVEL), Some(level::RTL_LEVEL), None];
@@ -230,6 +230,16 @@ fn gen_base_level_for_characters_tests(idx: usize) -> Option<Level> {
     VALUES[idx]
 }
 
+/// The visual ordering of the first paragraph as one line, in the format of the test data.
+fn get_ordering(bidi_info: &BidiInfo<'_>) -> Vec<String> {
+    let para = &bidi_info.paragraphs[0];
+    bidi_info
+        .visual_to_logical_per_char(para, para.range.clone())
+        .iter()
+        .map(|idx| idx.to_string())
+        .collect()
+}
+
 fn get_sample_string_from_bidi_classes(class_names: &[&str]) -> String {
     class_names
         .iter()
@@ -27,4 +27,6 @@
 mod pkg_info;
 pub use crate::pkg_info::{PKG_DESCRIPTION, PKG_NAME, PKG_VERSION};
 
+pub mod source;
+
 pub mod version;
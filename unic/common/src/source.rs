@@ -0,0 +1,364 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Code-unit sources for the text algorithms, such as segmentation and bidi.
+//!
+//! The algorithms work on the characters decoded from a source, and report positions as offsets
+//! in the source's code units: bytes for `str` and `[u8]`, 16-bit code units for `[u16]`.
+
+use core::char;
+use core::ops::Range;
+
+const REPLACEMENT: char = '\u{FFFD}';
+
+/// A sequence of code units that characters can be decoded from, in both directions.
+///
+/// Implemented for `str` (UTF-8), `[u16]` (UTF-16, with each unpaired surrogate decoded as
+/// U+FFFD) and `[u8]` (UTF-8, with each maximal invalid subsequence decoded as U+FFFD).
+///
+/// When text is provided in chunks, the chunks must be split between characters.
+pub trait CodeUnitSource {
+    /// Length in code units.
+    fn len(&self) -> usize;
+
+    /// Whether the source has no code units.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The character starting at code unit `offset`, and its length in code units, or `None` at
+    /// the end. `offset` must be a character boundary; for `str`, this panics otherwise.
+    fn char_at(&self, offset: usize) -> Option<(char, usize)>;
+
+    /// The character ending at code unit `offset`, and its length in code units, or `None` at
+    /// the start. `offset` must be a character boundary; for `str`, this panics otherwise.
+    fn char_before(&self, offset: usize) -> Option<(char, usize)>;
+
+    /// Whether code unit `offset` is the start or the end of a character, as decoded. This is
+    /// `false` past the end.
+    fn is_char_boundary(&self, offset: usize) -> bool;
+
+    /// The code units in `range`.
+    fn slice(&self, range: Range<usize>) -> &Self;
+
+    /// The characters of the source, with their offsets and lengths in code units.
+    #[inline]
+    fn char_indices(&self) -> CharIndices<'_, Self> {
+        CharIndices::new(self, 0..self.len())
+    }
+}
+
+impl CodeUnitSource for str {
+    #[inline]
+    fn len(&self) -> usize {
+        str::len(self)
+    }
+
+    #[inline]
+    fn char_at(&self, offset: usize) -> Option<(char, usize)> {
+        self[offset..].chars().next().map(|ch| (ch, ch.len_utf8()))
+    }
+
+    #[inline]
+    fn char_before(&self, offset: usize) -> Option<(char, usize)> {
+        self[..offset]
+            .chars()
+            .next_back()
+            .map(|ch| (ch, ch.len_utf8()))
+    }
+
+    #[inline]
+    fn is_char_boundary(&self, offset: usize) -> bool {
+        str::is_char_boundary(self, offset)
+    }
+
+    #[inline]
+    fn slice(&self, range: Range<usize>) -> &str {
+        &self[range]
+    }
+}
+
+impl CodeUnitSource for [u16] {
+    #[inline]
+    fn len(&self) -> usize {
+        <[u16]>::len(self)
+    }
+
+    fn char_at(&self, offset: usize) -> Option<(char, usize)> {
+        let unit = *self.get(offset)?;
+        match unit {
+            0xD800..=0xDBFF => match self.get(offset + 1) {
+                Some(&low @ 0xDC00..=0xDFFF) => Some((decode_surrogates(unit, low), 2)),
+                _ => Some((REPLACEMENT, 1)),
+            },
+            0xDC00..=0xDFFF => Some((REPLACEMENT, 1)),
+            _ => Some((char::from_u32(u32::from(unit)).unwrap(), 1)),
+        }
+    }
+
+    fn char_before(&self, offset: usize) -> Option<(char, usize)> {
+        if offset == 0 {
+            return None;
+        }
+        let unit = self[offset - 1];
+        match unit {
+            0xDC00..=0xDFFF if offset >= 2 => match self[offset - 2] {
+                high @ 0xD800..=0xDBFF => Some((decode_surrogates(high, unit), 2)),
+                _ => Some((REPLACEMENT, 1)),
+            },
+            0xD800..=0xDFFF => Some((REPLACEMENT, 1)),
+            _ => Some((char::from_u32(u32::from(unit)).unwrap(), 1)),
+        }
+    }
+
+    fn is_char_boundary(&self, offset: usize) -> bool {
+        if offset == 0 || offset == self.len() {
+            return true;
+        }
+        match (self.get(offset - 1), self.get(offset)) {
+            (Some(0xD800..=0xDBFF), Some(0xDC00..=0xDFFF)) => false,
+            (_, unit) => unit.is_some(),
+        }
+    }
+
+    #[inline]
+    fn slice(&self, range: Range<usize>) -> &[u16] {
+        &self[range]
+    }
+}
+
+fn decode_surrogates(high: u16, low: u16) -> char {
+    let scalar = 0x1_0000 + ((u32::from(high) - 0xD800) << 10) + (u32::from(low) - 0xDC00);
+    char::from_u32(scalar).unwrap()
+}
+
+impl CodeUnitSource for [u8] {
+    #[inline]
+    fn len(&self) -> usize {
+        <[u8]>::len(self)
+    }
+
+    #[inline]
+    fn char_at(&self, offset: usize) -> Option<(char, usize)> {
+        decode_utf8(&self[offset..])
+    }
+
+    fn char_before(&self, offset: usize) -> Option<(char, usize)> {
+        if offset == 0 {
+            return None;
+        }
+        // A sequence is at most four bytes, and starts with a byte that is not a continuation
+        for start in (offset.saturating_sub(4)..offset).rev() {
+            if self[start] & 0xC0 != 0x80 {
+                let (ch, len) = decode_utf8(&self[start..]).unwrap();
+                if start + len == offset {
+                    return Some((ch, len));
+                }
+                break;
+            }
+        }
+        Some((REPLACEMENT, 1))
+    }
+
+    fn is_char_boundary(&self, offset: usize) -> bool {
+        if offset == 0 || offset == self.len() {
+            return true;
+        }
+        if offset > self.len() {
+            return false;
+        }
+        // A sequence containing the byte before `offset` starts at most three bytes before it
+        for start in (offset.saturating_sub(4)..offset).rev() {
+            if self[start] & 0xC0 != 0x80 {
+                let (_, len) = decode_utf8(&self[start..]).unwrap();
+                return start + len <= offset;
+            }
+        }
+        true
+    }
+
+    #[inline]
+    fn slice(&self, range: Range<usize>) -> &[u8] {
+        &self[range]
+    }
+}
+
+// Decode the first character of `bytes`, replacing a maximal invalid subsequence with U+FFFD.
+fn decode_utf8(bytes: &[u8]) -> Option<(char, usize)> {
+    let first = *bytes.first()?;
+    let (continuations, second_range) = match first {
+        0x00..=0x7F => return Some((char::from(first), 1)),
+        0xC2..=0xDF => (1, 0x80..=0xBF),
+        0xE0 => (2, 0xA0..=0xBF),
+        0xE1..=0xEC | 0xEE..=0xEF => (2, 0x80..=0xBF),
+        0xED => (2, 0x80..=0x9F),
+        0xF0 => (3, 0x90..=0xBF),
+        0xF1..=0xF3 => (3, 0x80..=0xBF),
+        0xF4 => (3, 0x80..=0x8F),
+        _ => return Some((REPLACEMENT, 1)),
+    };
+    let mut scalar = u32::from(first) & (0x7F >> (continuations + 1));
+    for i in 1..=continuations {
+        let byte = match bytes.get(i) {
+            Some(&byte) => byte,
+            None => return Some((REPLACEMENT, i)),
+        };
+        let valid = if i == 1 {
+            second_range.contains(&byte)
+        } else {
+            byte & 0xC0 == 0x80
+        };
+        if !valid {
+            return Some((REPLACEMENT, i));
+        }
+        scalar = (scalar << 6) | (u32::from(byte) & 0x3F);
+    }
+    Some((char::from_u32(scalar).unwrap(), continuations + 1))
+}
+
+/// Iterator over the characters of a `CodeUnitSource`, with their offsets and lengths in code
+/// units.
+///
+/// Created by `CodeUnitSource::char_indices()` or `CharIndices::new()`.
+#[derive(Clone, Debug)]
+pub struct CharIndices<'a, T: ?Sized> {
+    source: &'a T,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T: CodeUnitSource + ?Sized> CharIndices<'a, T> {
+    /// Iterate over the characters in `range` of `source`.
+    pub fn new(source: &'a T, range: Range<usize>) -> CharIndices<'a, T> {
+        CharIndices {
+            source,
+            front: range.start,
+            back: range.end,
+        }
+    }
+}
+
+impl<'a, T: CodeUnitSource + ?Sized> Iterator for CharIndices<'a, T> {
+    type Item = (usize, char, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, char, usize)> {
+        if self.front >= self.back {
+            return None;
+        }
+        let (ch, len) = self.source.char_at(self.front)?;
+        let offset = self.front;
+        self.front += len;
+        Some((offset, ch, len))
+    }
+}
+
+impl<'a, T: CodeUnitSource + ?Sized> DoubleEndedIterator for CharIndices<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(usize, char, usize)> {
+        if self.front >= self.back {
+            return None;
+        }
+        let (ch, len) = self.source.char_before(self.back)?;
+        self.back -= len;
+        Some((self.back, ch, len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use self::std::string::String;
+    use self::std::vec::Vec;
+    use super::{CharIndices, CodeUnitSource};
+
+    type Decoded = Vec<(usize, char)>;
+
+    // The characters decoded forwards and backwards, after checking that they start on the
+    // offsets that `is_char_boundary()` accepts.
+    fn decode<T: CodeUnitSource + ?Sized>(source: &T) -> (Decoded, Decoded) {
+        let starts = CharIndices::new(source, 0..source.len())
+            .map(|(offset, _, _)| offset)
+            .chain(Some(source.len()))
+            .collect::<Vec<_>>();
+        let boundaries = (0..=source.len() + 1)
+            .filter(|&offset| source.is_char_boundary(offset))
+            .collect::<Vec<_>>();
+        assert_eq!(boundaries, starts);
+
+        let forward = CharIndices::new(source, 0..source.len())
+            .map(|(offset, ch, _)| (offset, ch))
+            .collect();
+        let mut backward: Vec<_> = CharIndices::new(source, 0..source.len())
+            .rev()
+            .map(|(offset, ch, _)| (offset, ch))
+            .collect();
+        backward.reverse();
+        (forward, backward)
+    }
+
+    #[test]
+    fn test_char_indices() {
+        let text = "a\u{5D0}\u{1F600}";
+        assert_eq!(
+            CodeUnitSource::char_indices(text).collect::<Vec<_>>(),
+            &[(0, 'a', 1), (1, '\u{5D0}', 2), (3, '\u{1F600}', 4)]
+        );
+
+        let text: Vec<u16> = text.encode_utf16().collect();
+        assert_eq!(
+            text[..].char_indices().collect::<Vec<_>>(),
+            &[(0, 'a', 1), (1, '\u{5D0}', 1), (2, '\u{1F600}', 2)]
+        );
+    }
+
+    #[test]
+    fn test_utf16() {
+        let units: &[u16] = &[0x61, 0xD83D, 0xDE00, 0xDC00, 0xD800, 0x62];
+        let (forward, backward) = decode(units);
+        assert_eq!(
+            forward,
+            &[
+                (0, 'a'),
+                (1, '😀'),
+                (3, '\u{FFFD}'),
+                (4, '\u{FFFD}'),
+                (5, 'b')
+            ]
+        );
+        assert_eq!(forward, backward);
+    }
+
+    #[test]
+    fn test_utf8_lossy() {
+        let bytes: &[u8] = b"a\xC3\xA9\x80\xE2\x82\xF0\x9F\x98\x80\xF4\x90\xFFb";
+        let (forward, backward) = decode(bytes);
+        assert_eq!(
+            forward,
+            &[
+                (0, 'a'),
+                (1, 'é'),
+                (3, '\u{FFFD}'),
+                (4, '\u{FFFD}'),
+                (6, '😀'),
+                (10, '\u{FFFD}'),
+                (11, '\u{FFFD}'),
+                (12, '\u{FFFD}'),
+                (13, 'b'),
+            ]
+        );
+        assert_eq!(forward, backward);
+        assert_eq!(
+            String::from_utf8_lossy(bytes).chars().collect::<Vec<_>>(),
+            forward.iter().map(|&(_, ch)| ch).collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,227 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::error;
+use std::fmt;
+use std::slice;
+use std::vec;
+
+/// The step of UTS #46 processing that failed.
+///
+/// <https://www.unicode.org/reports/tr46/#Processing>
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ErrorKind {
+    /// P1: The character is *disallowed* in the IDNA Mapping Table.
+    Disallowed,
+
+    /// P1: The character is *disallowed_STD3_valid* and *UseSTD3ASCIIRules* is set.
+    DisallowedByStd3AsciiRules,
+
+    /// P1: The character is *disallowed_STD3_mapped* and *UseSTD3ASCIIRules* is set.
+    DisallowedMappedInStd3,
+
+    /// P4: The label starts with the Punycode prefix, but the rest of it is not valid Punycode.
+    PunycodeDecode,
+
+    /// V1: The label is not in Normalization Form C.
+    NotNfc,
+
+    /// V2: The label has a U+002D HYPHEN-MINUS in both the third and fourth positions.
+    HyphensInThirdAndFourth,
+
+    /// V2: The label begins with the Punycode prefix `xn--`, checked in place of V2 and V3 when
+    /// *CheckHyphens* is not set.
+    PunycodePrefix,
+
+    /// V3: The label begins or ends with a U+002D HYPHEN-MINUS.
+    LeadingOrTrailingHyphen,
+
+    /// V4: The label contains a U+002E FULL STOP.
+    FullStop,
+
+    /// V5: The label begins with a combining mark.
+    LeadingCombiningMark,
+
+    /// V6: The character is not *valid* in the IDNA Mapping Table, for the processing used.
+    InvalidCharacter,
+
+    /// V7: The label has a U+200C ZERO WIDTH NON-JOINER or U+200D ZERO WIDTH JOINER outside of
+    /// the contexts allowed by the ContextJ rules of RFC 5892.
+    ContextJ,
+
+    /// V8: The label breaks the Bidi Rule of RFC 5893 in a Bidi Domain Name.
+    Bidi,
+
+    /// A3: The label could not be converted to Punycode.
+    PunycodeEncode,
+
+    /// A4: The domain name, or one of its labels, is empty.
+    TooShortForDns,
+
+    /// A4: The domain name is longer than 253 bytes, or one of its labels is longer than 63.
+    TooLongForDns,
+}
+
+impl ErrorKind {
+    /// The name of the step that failed, such as `"V1"`, as in the specification.
+    pub fn step(self) -> &'static str {
+        match self {
+            ErrorKind::Disallowed
+            | ErrorKind::DisallowedByStd3AsciiRules
+            | ErrorKind::DisallowedMappedInStd3 => "P1",
+            ErrorKind::PunycodeDecode => "P4",
+            ErrorKind::NotNfc => "V1",
+            ErrorKind::HyphensInThirdAndFourth | ErrorKind::PunycodePrefix => "V2",
+            ErrorKind::LeadingOrTrailingHyphen => "V3",
+            ErrorKind::FullStop => "V4",
+            ErrorKind::LeadingCombiningMark => "V5",
+            ErrorKind::InvalidCharacter => "V6",
+            ErrorKind::ContextJ => "V7",
+            ErrorKind::Bidi => "V8",
+            ErrorKind::PunycodeEncode => "A3",
+            ErrorKind::TooShortForDns | ErrorKind::TooLongForDns => "A4",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            ErrorKind::Disallowed => "disallowed character",
+            ErrorKind::DisallowedByStd3AsciiRules => "character disallowed by STD3 ASCII rules",
+            ErrorKind::DisallowedMappedInStd3 => "character mapped to one disallowed by STD3",
+            ErrorKind::PunycodeDecode => "invalid Punycode",
+            ErrorKind::NotNfc => "label not in Normalization Form C",
+            ErrorKind::HyphensInThirdAndFourth => "label has hyphens in third and fourth positions",
+            ErrorKind::PunycodePrefix => "label begins with the Punycode prefix",
+            ErrorKind::LeadingOrTrailingHyphen => "label begins or ends with a hyphen",
+            ErrorKind::FullStop => "label contains a full stop",
+            ErrorKind::LeadingCombiningMark => "label begins with a combining mark",
+            ErrorKind::InvalidCharacter => "invalid character",
+            ErrorKind::ContextJ => "joiner not allowed in this context",
+            ErrorKind::Bidi => "label breaks the Bidi Rule",
+            ErrorKind::PunycodeEncode => "label cannot be encoded as Punycode",
+            ErrorKind::TooShortForDns => "empty label or domain name",
+            ErrorKind::TooLongForDns => "label or domain name too long for DNS",
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.step(), self.description())
+    }
+}
+
+/// An error recorded during UTS #46 processing, and where in the domain name it was found.
+///
+/// Labels are counted from zero, in the domain name as mapped (P1), where characters such as
+/// U+3002 IDEOGRAPHIC FULL STOP have become U+002E FULL STOP.
+///
+/// The `offset` is in bytes, into the domain name as given. It is that of the offending character
+/// for P1 and V1–V8 errors, and otherwise that of the start of the label. Characters of labels
+/// decoded from Punycode are not in the domain name, so their errors have the offset of the label.
+/// Where normalization has combined characters, the offset is that of the first of them.
+///
+/// The `label_offset` is in bytes, into the label as it was when the step failed:
+///
+/// * For P1 errors, the label as mapped so far, before normalization.
+/// * For V1–V8 errors, the label as normalized, or as decoded from Punycode.
+/// * For A4 errors, the label as converted to ASCII.
+///
+/// Errors about a whole label, or a whole domain name, have no `code_point`, and a `label_offset`
+/// of zero, except for A4 length errors, where the `label_offset` is that of the first byte over
+/// the limit.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Error {
+    /// The step that failed.
+    pub kind: ErrorKind,
+
+    /// The index of the label in the domain name.
+    pub label_index: usize,
+
+    /// The byte offset of the error in the domain name.
+    pub offset: usize,
+
+    /// The byte offset of the error in the label, as processed.
+    pub label_offset: usize,
+
+    /// The offending code point, if any.
+    pub code_point: Option<char>,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "byte {} (label {})", self.offset, self.label_index)?;
+        if let Some(code_point) = self.code_point {
+            write!(f, ", U+{:04X}", code_point as u32)?;
+        }
+        write!(f, ": {}", self.kind)
+    }
+}
+
+impl error::Error for Error {}
+
+/// Errors recorded during UTS #46 processing, in the order they were found.
+///
+/// This is never empty.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Errors(Vec<Error>);
+
+impl Errors {
+    /// `None` if there are no errors.
+    pub(crate) fn from_vec(errors: Vec<Error>) -> Option<Errors> {
+        if errors.is_empty() {
+            None
+        } else {
+            Some(Errors(errors))
+        }
+    }
+
+    /// The errors, as a slice.
+    pub fn as_slice(&self) -> &[Error] {
+        &self.0
+    }
+
+    /// Iterate over the errors.
+    pub fn iter(&self) -> slice::Iter<'_, Error> {
+        self.0.iter()
+    }
+}
+
+impl IntoIterator for Errors {
+    type Item = Error;
+    type IntoIter = vec::IntoIter<Error>;
+
+    fn into_iter(self) -> vec::IntoIter<Error> {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Errors {
+    type Item = &'a Error;
+    type IntoIter = slice::Iter<'a, Error>;
+
+    fn into_iter(self) -> slice::Iter<'a, Error> {
+        self.0.iter()
+    }
+}
+
+impl fmt::Display for Errors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, error) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for Errors {}
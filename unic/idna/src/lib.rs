@@ -55,7 +55,10 @@ pub use crate::pkg_info::{PKG_DESCRIPTION, PKG_NAME, PKG_VERSION};
 
 pub use crate::mapping::UNICODE_VERSION;
 
+mod error;
+pub use crate::error::{Error, ErrorKind, Errors};
+
 mod process;
+pub use crate::process::Flags;
 pub use crate::process::PUNYCODE_PREFIX;
 pub use crate::process::{to_ascii, to_unicode};
-pub use crate::process::{Errors, Flags};
@@ -9,17 +9,22 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use unic_normal::StrNormalForm;
+use unic_normal::{NormalizationCursor, StrNormalForm};
 use unic_ucd_bidi::{bidi_class, BidiClass};
-use unic_ucd_normal::is_combining_mark;
+use unic_ucd_joining::JoiningType;
+use unic_ucd_normal::{is_combining_mark, CanonicalCombiningClass};
 
+use crate::error::{Error, ErrorKind, Errors};
 use crate::mapping::Mapping;
 use crate::punycode;
 
 /// Prefix used in Punycode encoding.
 pub static PUNYCODE_PREFIX: &str = "xn--";
 
-fn map_char(codepoint: char, flags: Flags, output: &mut String, errors: &mut Vec<Error>) {
+const ZWNJ: char = '\u{200C}';
+const ZWJ: char = '\u{200D}';
+
+fn map_char(codepoint: char, flags: Flags, output: &mut String) -> Option<ErrorKind> {
     match Mapping::of(codepoint) {
         Mapping::Valid => output.push(codepoint),
         Mapping::Ignored => {}
@@ -32,71 +37,63 @@ fn map_char(codepoint: char, flags: Flags, output: &mut String, errors: &mut Vec
             }
         }
         Mapping::Disallowed => {
-            errors.push(Error::DissallowedCharacter);
             output.push(codepoint);
+            return Some(ErrorKind::Disallowed);
         }
         Mapping::DisallowedStd3Valid => {
+            output.push(codepoint);
             if flags.use_std3_ascii_rules {
-                errors.push(Error::DissallowedByStd3AsciiRules);
+                return Some(ErrorKind::DisallowedByStd3AsciiRules);
             }
-            output.push(codepoint)
         }
         Mapping::DisallowedStd3Mapped(slice) => {
+            output.push_str(slice);
             if flags.use_std3_ascii_rules {
-                errors.push(Error::DissallowedMappedInStd3);
+                return Some(ErrorKind::DisallowedMappedInStd3);
             }
-            output.push_str(slice)
         }
     }
+    None
 }
 
 // http://tools.ietf.org/html/rfc5893#section-2
-fn passes_bidi(label: &str, is_bidi_domain: bool) -> bool {
+//
+// Returns the byte offset of the character breaking the rules, and the character, if any.
+fn find_bidi_violation(label: &str, is_bidi_domain: bool) -> Option<(usize, char)> {
     use self::bidi_class::abbr_names::*;
 
     // Rule 0: Bidi Rules apply to Bidi Domain Names: a name with at least one RTL label.  A label
     // is RTL if it contains at least one character of bidi class R, AL or AN.
     if !is_bidi_domain {
-        return true;
+        return None;
     }
 
-    let mut chars = label.chars();
-    let first_char_class = match chars.next() {
-        Some(c) => BidiClass::of(c),
-        None => return true, // empty string
+    let mut chars = label.char_indices();
+    let first_char = match chars.next() {
+        Some((_, c)) => c,
+        None => return None, // empty string
     };
+    // The first character is not an NSM, so there is always a last one.
+    let last_non_nsm = label
+        .char_indices()
+        .rev()
+        .find(|&(_, c)| BidiClass::of(c) != NSM);
 
-    match first_char_class {
+    match BidiClass::of(first_char) {
         // LTR label
         L => {
             // Rule 5
-            while let Some(c) = chars.next() {
-                if !matches!(BidiClass::of(c), L | EN | ES | CS | ET | ON | BN | NSM) {
-                    return false;
-                }
+            let invalid = chars
+                .find(|&(_, c)| !matches!(BidiClass::of(c), L | EN | ES | CS | ET | ON | BN | NSM));
+            if invalid.is_some() {
+                return invalid;
             }
 
             // Rule 6
             // must end in L or EN followed by 0 or more NSM
-            let mut rev_chars = label.chars().rev();
-            let mut last_non_nsm = rev_chars.next();
-            loop {
-                match last_non_nsm {
-                    Some(c) if BidiClass::of(c) == NSM => {
-                        last_non_nsm = rev_chars.next();
-                        continue;
-                    }
-                    _ => {
-                        break;
-                    }
-                }
-            }
             match last_non_nsm {
-                Some(c) if BidiClass::of(c) == L || BidiClass::of(c) == EN => {}
-                Some(_) => {
-                    return false;
-                }
-                _ => {}
+                Some((_, c)) if matches!(BidiClass::of(c), L | EN) => {}
+                last => return last,
             }
         }
 
@@ -105,121 +102,279 @@ fn passes_bidi(label: &str, is_bidi_domain: bool) -> bool {
             let mut found_en = false;
             let mut found_an = false;
 
-            // Rule 2
-            for c in chars {
+            for (i, c) in chars {
                 let char_class = BidiClass::of(c);
 
+                // Rule 2
+                if !matches!(char_class, R | AL | AN | EN | ES | CS | ET | ON | BN | NSM) {
+                    return Some((i, c));
+                }
+
+                // Rule 4
                 if char_class == EN {
                     found_en = true;
                 }
                 if char_class == AN {
                     found_an = true;
                 }
-
-                if !matches!(char_class, R | AL | AN | EN | ES | CS | ET | ON | BN | NSM) {
-                    return false;
+                if found_an && found_en {
+                    return Some((i, c));
                 }
             }
 
             // Rule 3
-            let mut rev_chars = label.chars().rev();
-            let mut last = rev_chars.next();
-            loop {
-                // must end in L or EN followed by 0 or more NSM
-                match last {
-                    Some(c) if BidiClass::of(c) == NSM => {
-                        last = rev_chars.next();
-                        continue;
-                    }
-                    _ => {
-                        break;
-                    }
-                }
-            }
-            match last {
-                Some(c) if matches!(BidiClass::of(c), R | AL | EN | AN) => {}
-                _ => {
-                    return false;
-                }
-            }
-
-            // Rule 4
-            if found_an && found_en {
-                return false;
+            // must end in R, AL, EN or AN followed by 0 or more NSM
+            match last_non_nsm {
+                Some((_, c)) if matches!(BidiClass::of(c), R | AL | EN | AN) => {}
+                last => return last,
             }
         }
 
         // Rule 1: Should start with L or R/AL
         _ => {
-            return false;
+            return Some((0, first_char));
         }
     }
 
-    true
+    None
 }
 
-// https://www.unicode.org/reports/tr46/#Validity_Criteria
-#[allow(clippy::if_same_then_else)]
-fn validate(label: &str, is_bidi_domain: bool, flags: Flags, errors: &mut Vec<Error>) {
-    let first_char = label.chars().next();
+// Returns the byte offset of the first character that differs from the NFC form of the label, and
+// the character, if any.
+fn find_non_nfc(label: &str) -> Option<(usize, char)> {
+    let mut nfc = label.nfc();
+    let mismatch = label.char_indices().find(|&(_, c)| nfc.next() != Some(c));
+    match mismatch {
+        Some(_) => mismatch,
+        // The NFC form is longer, and starts with the label.
+        None if nfc.next().is_some() => label.char_indices().last(),
+        None => None,
+    }
+}
+
+// https://tools.ietf.org/html/rfc5892#appendix-A.1
+// https://tools.ietf.org/html/rfc5892#appendix-A.2
+//
+// Returns the byte offset of the joiner out of context, and the joiner, if any.
+fn find_contextj_violation(label: &str) -> Option<(usize, char)> {
+    use unic_ucd_joining::joining_type::abbr_names::*;
+
+    for (i, c) in label.char_indices() {
+        if c != ZWNJ && c != ZWJ {
+            continue;
+        }
+
+        // Rule 1: Allowed after a virama, for both
+        let before = &label[..i];
+        let virama_before = matches!(
+            before.chars().next_back().map(CanonicalCombiningClass::of),
+            Some(CanonicalCombiningClass::Virama)
+        );
+        if virama_before {
+            continue;
+        }
+
+        // Rule 2: Allowed between joining characters, for ZWNJ only
+        //
+        // (Joining_Type:{L,D})(Joining_Type:T)*\u200C(Joining_Type:T)*(Joining_Type:{R,D})
+        if c == ZWNJ {
+            let after = &label[i + c.len_utf8()..];
+            let joins_before = matches!(
+                before
+                    .chars()
+                    .rev()
+                    .map(JoiningType::of)
+                    .find(|&jt| jt != T),
+                Some(L) | Some(D)
+            );
+            let joins_after = matches!(
+                after.chars().map(JoiningType::of).find(|&jt| jt != T),
+                Some(R) | Some(D)
+            );
+            if joins_before && joins_after {
+                continue;
+            }
+        }
 
-    if first_char == None {
-        // Empty string, pass
+        return Some((i, c));
     }
+    None
+}
+
+// https://www.unicode.org/reports/tr46/#Validity_Criteria
+//
+// Returns the first criterion the label fails, where, and the character at fault.
+fn find_invalid(
+    label: &str,
+    is_bidi_domain: bool,
+    flags: Flags,
+) -> Option<(ErrorKind, (usize, char))> {
+    // Empty string, pass
+    let first_char = label.chars().next()?;
+
     // V1: Must be in NFC form.
-    else if label.nfc().ne(label.chars()) {
-        errors.push(Error::ValidityCriteria);
+    if let Some(found) = find_non_nfc(label) {
+        return Some((ErrorKind::NotNfc, found));
     }
-    // V2: No U+002D HYPHEN-MINUS in both third and fourth positions.
-    //
-    // NOTE: Spec says that the label must not contain a HYPHEN-MINUS character in both the
-    // third and fourth positions. But nobody follows this criteria. See the spec issue below:
-    // https://github.com/whatwg/url/issues/53
-    //
-    // TODO: Add *CheckHyphens* flag.
 
-    // V3: neither begin nor end with a U+002D HYPHEN-MINUS
-    else if label.starts_with('-') || label.ends_with('-') {
-        errors.push(Error::ValidityCriteria);
+    if flags.check_hyphens {
+        // V2: No U+002D HYPHEN-MINUS in both third and fourth positions.
+        if label.get(2..4) == Some("--") {
+            return Some((ErrorKind::HyphensInThirdAndFourth, (2, '-')));
+        }
+
+        // V3: neither begin nor end with a U+002D HYPHEN-MINUS
+        if label.starts_with('-') {
+            return Some((ErrorKind::LeadingOrTrailingHyphen, (0, '-')));
+        }
+        if label.ends_with('-') {
+            return Some((ErrorKind::LeadingOrTrailingHyphen, (label.len() - 1, '-')));
+        }
+    } else if label.starts_with(PUNYCODE_PREFIX) {
+        // Labels that look like Punycode must still not decode to another Punycode label.
+        return Some((ErrorKind::PunycodePrefix, (0, first_char)));
     }
+
     // V4: not contain a U+002E FULL STOP
     //
-    // Here, label can't contain '.' since the input is from .split('.')
+    // Labels split on '.' can't contain one, but labels decoded from Punycode can.
+    if let Some(offset) = label.find('.') {
+        return Some((ErrorKind::FullStop, (offset, '.')));
+    }
 
     // V5: not begin with a GC=Mark
-    else if is_combining_mark(first_char.unwrap()) {
-        errors.push(Error::ValidityCriteria);
+    if is_combining_mark(first_char) {
+        return Some((ErrorKind::LeadingCombiningMark, (0, first_char)));
     }
+
     // V6: Check against Mapping Table
-    else if label.chars().any(|c| match Mapping::of(c) {
+    if let Some(found) = label.char_indices().find(|&(_, c)| match Mapping::of(c) {
         Mapping::Valid => false,
         Mapping::Deviation(_) => flags.transitional_processing,
         Mapping::DisallowedStd3Valid => flags.use_std3_ascii_rules,
         _ => true,
     }) {
-        errors.push(Error::ValidityCriteria);
+        return Some((ErrorKind::InvalidCharacter, found));
     }
+
     // V7: ContextJ rules
-    //
-    // TODO: Implement rules and add *CheckJoiners* flag.
+    if flags.check_joiners {
+        if let Some(found) = find_contextj_violation(label) {
+            return Some((ErrorKind::ContextJ, found));
+        }
+    }
 
     // V8: Bidi rules
-    //
-    // TODO: Add *CheckBidi* flag
-    else if !passes_bidi(label, is_bidi_domain) {
-        errors.push(Error::ValidityCriteria);
+    if flags.check_bidi {
+        if let Some(found) = find_bidi_violation(label, is_bidi_domain) {
+            return Some((ErrorKind::Bidi, found));
+        }
+    }
+
+    None
+}
+
+fn validate<F: Fn(usize) -> usize>(
+    label: &str,
+    label_index: usize,
+    is_bidi_domain: bool,
+    flags: Flags,
+    origin: F,
+    errors: &mut Vec<Error>,
+) {
+    if let Some((kind, (label_offset, code_point))) = find_invalid(label, is_bidi_domain, flags) {
+        errors.push(Error {
+            kind,
+            label_index,
+            offset: origin(label_offset),
+            label_offset,
+            code_point: Some(code_point),
+        });
     }
 }
 
+// The byte offsets in the domain name of the characters of a string derived from it, as pairs of
+// (offset in the string, offset in the domain name), in ascending order.
+#[derive(Debug, Default)]
+struct Origins(Vec<(usize, usize)>);
+
+impl Origins {
+    fn push(&mut self, offset: usize, origin: usize) {
+        self.0.push((offset, origin));
+    }
+
+    // The offset in the domain name of the character at `offset`, or of the last character
+    // recorded before it.
+    fn of(&self, offset: usize) -> usize {
+        use std::cmp::Ordering::{Greater, Less};
+
+        match self
+            .0
+            .binary_search_by(|&(start, _)| if start <= offset { Less } else { Greater })
+        {
+            Ok(_) => unreachable!(),
+            Err(0) => 0,
+            Err(i) => self.0[i - 1].1,
+        }
+    }
+}
+
+// Normalize `mapped` to NFC, along with the origins of its characters.
+//
+// Each piece between normalization boundaries is normalized on its own. Pieces left unchanged
+// keep the origins of their characters; the others take the origin of their first character.
+fn normalize(mapped: &str, mapped_origins: &Origins) -> (String, Origins) {
+    let mut normalized = String::with_capacity(mapped.len());
+    let mut origins = Origins::default();
+    let mut cursor = NormalizationCursor::new(0, mapped.len());
+    let mut start = 0;
+    while let Ok(Some(end)) = cursor.next_boundary(mapped, 0) {
+        let piece = &mapped[start..end];
+        if piece.nfc().eq(piece.chars()) {
+            for (i, _) in piece.char_indices() {
+                origins.push(normalized.len() + i, mapped_origins.of(start + i));
+            }
+            normalized.push_str(piece);
+        } else {
+            origins.push(normalized.len(), mapped_origins.of(start));
+            normalized.extend(piece.nfc());
+        }
+        start = end;
+    }
+    (normalized, origins)
+}
+
 // https://www.unicode.org/reports/tr46/#Processing
-fn processing(domain: &str, flags: Flags, errors: &mut Vec<Error>) -> String {
+//
+// Returns the processed domain name, and the offsets in `domain` of the starts of its labels.
+fn processing(domain: &str, flags: Flags, errors: &mut Vec<Error>) -> (String, Vec<usize>) {
     use self::bidi_class::abbr_names::*;
 
     let mut mapped = String::new();
-    for c in domain.chars() {
-        map_char(c, flags, &mut mapped, errors)
+    let mut mapped_origins = Origins::default();
+    let mut label_index = 0;
+    let mut label_start = 0;
+    for (offset, c) in domain.char_indices() {
+        let label_offset = mapped.len() - label_start;
+        mapped_origins.push(mapped.len(), offset);
+        if let Some(kind) = map_char(c, flags, &mut mapped) {
+            errors.push(Error {
+                kind,
+                label_index,
+                offset,
+                label_offset,
+                code_point: Some(c),
+            });
+        }
+        // Characters such as U+3002 IDEOGRAPHIC FULL STOP are mapped to label separators.
+        while let Some(i) = mapped[label_start..].find('.') {
+            label_index += 1;
+            label_start += i + 1;
+        }
     }
-    let normalized: String = mapped.nfc().collect();
+    mapped_origins.push(mapped.len(), domain.len());
+    let (normalized, mut origins) = normalize(&mapped, &mapped_origins);
+    origins.push(normalized.len(), domain.len());
 
     // Find out if it's a Bidi Domain Name
     //
@@ -249,12 +404,14 @@ fn processing(domain: &str, flags: Flags, errors: &mut Vec<Error>) -> String {
     }
 
     let mut validated = String::new();
-    let mut first = true;
-    for label in normalized.split('.') {
-        if !first {
+    let mut label_origins = Vec::new();
+    let mut label_start = 0;
+    for (label_index, label) in normalized.split('.').enumerate() {
+        if label_index > 0 {
             validated.push('.');
         }
-        first = false;
+        let label_origin = origins.of(label_start);
+        label_origins.push(label_origin);
         if label.starts_with(PUNYCODE_PREFIX) {
             match punycode::decode_to_string(&label[PUNYCODE_PREFIX.len()..]) {
                 Some(decoded_label) => {
@@ -262,17 +419,39 @@ fn processing(domain: &str, flags: Flags, errors: &mut Vec<Error>) -> String {
                         transitional_processing: false,
                         ..flags
                     };
-                    validate(&decoded_label, is_bidi_domain, flags, errors);
+                    // Characters of a decoded label are not in the domain name, only the label
+                    validate(
+                        &decoded_label,
+                        label_index,
+                        is_bidi_domain,
+                        flags,
+                        |_| label_origin,
+                        errors,
+                    );
                     validated.push_str(&decoded_label)
                 }
-                None => errors.push(Error::PunycodeError),
+                None => errors.push(Error {
+                    kind: ErrorKind::PunycodeDecode,
+                    label_index,
+                    offset: label_origin,
+                    label_offset: 0,
+                    code_point: None,
+                }),
             }
         } else {
-            validate(label, is_bidi_domain, flags, errors);
+            validate(
+                label,
+                label_index,
+                is_bidi_domain,
+                flags,
+                |offset| origins.of(label_start + offset),
+                errors,
+            );
             validated.push_str(label)
         }
+        label_start += label.len() + 1;
     }
-    validated
+    (validated, label_origins)
 }
 
 /// Optional settings for processing and conversion algorithms.
@@ -283,6 +462,27 @@ pub struct Flags {
     /// <https://www.unicode.org/reports/tr46/#UseSTD3ASCIIRules>
     pub use_std3_ascii_rules: bool,
 
+    /// *CheckHyphens* flag, to reject labels with U+002D HYPHEN-MINUS in both the third and fourth
+    /// positions, or at the start or the end (V2, V3).
+    ///
+    /// When this is not set, labels must still not begin with `xn--`.
+    ///
+    /// Web browsers leave this off. See <https://github.com/whatwg/url/issues/53>.
+    ///
+    /// <https://www.unicode.org/reports/tr46/#Validity_Criteria>
+    pub check_hyphens: bool,
+
+    /// *CheckBidi* flag, to apply the Bidi Rule of RFC 5893 to the labels of Bidi Domain Names (V8).
+    ///
+    /// <https://www.unicode.org/reports/tr46/#Validity_Criteria>
+    pub check_bidi: bool,
+
+    /// *CheckJoiners* flag, to only allow U+200C ZERO WIDTH NON-JOINER and U+200D ZERO WIDTH
+    /// JOINER in the contexts of the ContextJ rules of RFC 5892 (V7).
+    ///
+    /// <https://www.unicode.org/reports/tr46/#Validity_Criteria>
+    pub check_joiners: bool,
+
     /// *Transitional_Processing* or *Nontransitional Processing*, for transitional handling of
     /// *Deviation* characters.
     ///
@@ -295,36 +495,15 @@ pub struct Flags {
     pub verify_dns_length: bool,
 }
 
-/// Error types recorded during UTS #46 processing.
-#[allow(clippy::enum_variant_names)]
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
-enum Error {
-    PunycodeError,
-    ValidityCriteria,
-    DissallowedByStd3AsciiRules,
-    DissallowedMappedInStd3,
-    DissallowedCharacter,
-    TooLongForDns,
-    TooShortForDns,
-}
-
-/// Errors recorded during UTS #46 processing.
-///
-/// This is opaque for now, only indicating the presence of at least one error.
-/// More details may be exposed in the future.
-#[derive(Debug, Eq, PartialEq)]
-pub struct Errors(Vec<Error>);
-
 /// <https://www.unicode.org/reports/tr46/#ToASCII>
 pub fn to_ascii(domain: &str, flags: Flags) -> Result<String, Errors> {
     let mut errors = Vec::new();
     let mut result = String::new();
-    let mut first = true;
-    for label in processing(domain, flags, &mut errors).split('.') {
-        if !first {
+    let (processed, label_origins) = processing(domain, flags, &mut errors);
+    for (label_index, label) in processed.split('.').enumerate() {
+        if label_index > 0 {
             result.push('.');
         }
-        first = false;
         if label.is_ascii() {
             result.push_str(label);
         } else {
@@ -333,28 +512,55 @@ pub fn to_ascii(domain: &str, flags: Flags) -> Result<String, Errors> {
                     result.push_str(PUNYCODE_PREFIX);
                     result.push_str(&x);
                 }
-                None => errors.push(Error::PunycodeError),
+                None => errors.push(Error {
+                    kind: ErrorKind::PunycodeEncode,
+                    label_index,
+                    offset: label_origins[label_index],
+                    label_offset: 0,
+                    code_point: None,
+                }),
             }
         }
     }
 
     if flags.verify_dns_length {
-        let domain = if result.ends_with('.') {
-            &result[..result.len() - 1]
-        } else {
-            &*result
+        verify_dns_length(&result, &label_origins, &mut errors);
+    }
+    match Errors::from_vec(errors) {
+        None => Ok(result),
+        Some(errors) => Err(errors),
+    }
+}
+
+// https://www.unicode.org/reports/tr46/#ToASCII (step 4)
+//
+// `label_origins` are the offsets of the labels in the domain name before processing.
+fn verify_dns_length(domain: &str, label_origins: &[usize], errors: &mut Vec<Error>) {
+    let domain = domain.strip_suffix('.').unwrap_or(domain);
+
+    let mut label_start = 0;
+    for (label_index, label) in domain.split('.').enumerate() {
+        let mut error = |kind, label_offset| {
+            errors.push(Error {
+                kind,
+                label_index,
+                offset: label_origins[label_index],
+                label_offset,
+                code_point: None,
+            })
         };
-        if domain.is_empty() || domain.split('.').any(|label| label.is_empty()) {
-            errors.push(Error::TooShortForDns)
+
+        if label.is_empty() {
+            error(ErrorKind::TooShortForDns, 0);
+        } else if label.len() > 63 {
+            error(ErrorKind::TooLongForDns, 63);
         }
-        if domain.len() > 253 || domain.split('.').any(|label| label.len() > 63) {
-            errors.push(Error::TooLongForDns)
+        // The domain name being too long is reported at the label that crosses the limit.
+        let label_end = label_start + label.len();
+        if label_start <= 253 && label_end > 253 {
+            error(ErrorKind::TooLongForDns, 253 - label_start);
         }
-    }
-    if errors.is_empty() {
-        Ok(result)
-    } else {
-        Err(Errors(errors))
+        label_start = label_end + 1;
     }
 }
 
@@ -364,11 +570,10 @@ pub fn to_ascii(domain: &str, flags: Flags) -> Result<String, Errors> {
 pub fn to_unicode(domain: &str, mut flags: Flags) -> (String, Result<(), Errors>) {
     flags.transitional_processing = false;
     let mut errors = Vec::new();
-    let domain = processing(domain, flags, &mut errors);
-    let errors = if errors.is_empty() {
-        Ok(())
-    } else {
-        Err(Errors(errors))
+    let (domain, _) = processing(domain, flags, &mut errors);
+    let errors = match Errors::from_vec(errors) {
+        None => Ok(()),
+        Some(errors) => Err(errors),
     };
     (domain, errors)
 }
@@ -386,6 +591,9 @@ mod tests {
                 Flags {
                     transitional_processing: false,
                     use_std3_ascii_rules: true,
+                    check_hyphens: true,
+                    check_bidi: true,
+                    check_joiners: true,
                     verify_dns_length: true,
                 },
             )
@@ -411,6 +619,9 @@ mod tests {
                 Flags {
                     transitional_processing: false,
                     use_std3_ascii_rules: true,
+                    check_hyphens: true,
+                    check_bidi: true,
+                    check_joiners: true,
                     verify_dns_length: false,
                 },
             )
@@ -435,6 +646,9 @@ mod tests {
                 Flags {
                     transitional_processing: false,
                     use_std3_ascii_rules: true,
+                    check_hyphens: true,
+                    check_bidi: true,
+                    check_joiners: true,
                     verify_dns_length: true,
                 },
             )
@@ -455,6 +669,9 @@ mod tests {
                 Flags {
                     transitional_processing: false,
                     use_std3_ascii_rules: true,
+                    check_hyphens: true,
+                    check_bidi: true,
+                    check_joiners: true,
                     verify_dns_length: true,
                 },
             )
@@ -474,4 +691,221 @@ mod tests {
         // Bidi chars may be punycode-encoded
         assert!(_to_ascii("xn--0ca24w").is_err());
     }
+
+    #[test]
+    fn test_error_positions() {
+        fn _to_ascii(domain: &str) -> Vec<Error> {
+            to_ascii(
+                domain,
+                Flags {
+                    transitional_processing: false,
+                    use_std3_ascii_rules: true,
+                    check_hyphens: true,
+                    check_bidi: true,
+                    check_joiners: true,
+                    verify_dns_length: true,
+                },
+            )
+            .unwrap_err()
+            .into_iter()
+            .collect()
+        }
+
+        fn error(
+            kind: ErrorKind,
+            label_index: usize,
+            offset: usize,
+            label_offset: usize,
+            code_point: Option<char>,
+        ) -> Error {
+            Error {
+                kind,
+                label_index,
+                offset,
+                label_offset,
+                code_point,
+            }
+        }
+
+        // P1, after a label separator mapped from U+3002 IDEOGRAPHIC FULL STOP, and V6 for the
+        // same character
+        assert_eq!(
+            _to_ascii("a\u{3002}b\u{E9}_"),
+            vec![
+                error(ErrorKind::DisallowedByStd3AsciiRules, 1, 7, 3, Some('_')),
+                error(ErrorKind::InvalidCharacter, 1, 7, 3, Some('_')),
+            ]
+        );
+        assert_eq!(
+            _to_ascii("ex\u{FFFF}.com")[0],
+            error(ErrorKind::Disallowed, 0, 2, 2, Some('\u{FFFF}'))
+        );
+
+        // P1 and V3, after full-width characters mapped to ASCII
+        assert_eq!(
+            _to_ascii("\u{FF21}\u{FF22}\u{FFFF}")[0],
+            error(ErrorKind::Disallowed, 0, 6, 2, Some('\u{FFFF}'))
+        );
+        assert_eq!(
+            _to_ascii("\u{FF41}e\u{301}-"),
+            vec![error(
+                ErrorKind::LeadingOrTrailingHyphen,
+                0,
+                6,
+                3,
+                Some('-')
+            )]
+        );
+
+        // V3, V5 and V8
+        assert_eq!(
+            _to_ascii("ab-.c"),
+            vec![error(
+                ErrorKind::LeadingOrTrailingHyphen,
+                0,
+                2,
+                2,
+                Some('-')
+            )]
+        );
+        assert_eq!(
+            _to_ascii("a.\u{301}b"),
+            vec![error(
+                ErrorKind::LeadingCombiningMark,
+                1,
+                2,
+                0,
+                Some('\u{301}')
+            )]
+        );
+        assert_eq!(
+            _to_ascii("\u{5D0}1\u{661}"),
+            vec![error(ErrorKind::Bidi, 0, 3, 3, Some('\u{661}'))]
+        );
+
+        // P4 and A4
+        assert_eq!(
+            _to_ascii("a.xn--a-9999999999"),
+            vec![error(ErrorKind::PunycodeDecode, 1, 2, 0, None)]
+        );
+        let label = "a".repeat(64);
+        assert_eq!(
+            _to_ascii(&format!("b..{}", label)),
+            vec![
+                error(ErrorKind::TooShortForDns, 1, 2, 0, None),
+                error(ErrorKind::TooLongForDns, 2, 3, 63, None),
+            ]
+        );
+        let domain = vec!["a".repeat(50); 6].join(".");
+        assert_eq!(
+            _to_ascii(&domain),
+            vec![error(ErrorKind::TooLongForDns, 4, 204, 49, None)]
+        );
+    }
+
+    #[test]
+    fn test_error_display() {
+        let errors = to_ascii(
+            "a.\u{301}b",
+            Flags {
+                transitional_processing: false,
+                use_std3_ascii_rules: true,
+                check_hyphens: true,
+                check_bidi: true,
+                check_joiners: true,
+                verify_dns_length: true,
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            errors.to_string(),
+            "byte 2 (label 1), U+0301: V5: label begins with a combining mark"
+        );
+    }
+
+    #[test]
+    fn test_check_flags() {
+        fn _to_ascii(
+            domain: &str,
+            check_hyphens: bool,
+            check_bidi: bool,
+            check_joiners: bool,
+        ) -> Result<String, Vec<(ErrorKind, usize, usize)>> {
+            to_ascii(
+                domain,
+                Flags {
+                    transitional_processing: false,
+                    use_std3_ascii_rules: true,
+                    check_hyphens,
+                    check_bidi,
+                    check_joiners,
+                    verify_dns_length: true,
+                },
+            )
+            .map_err(|errors| {
+                errors
+                    .iter()
+                    .map(|error| (error.kind, error.label_index, error.label_offset))
+                    .collect()
+            })
+        }
+
+        // CheckHyphens
+        assert_eq!(
+            _to_ascii("ab--c", true, true, true),
+            Err(vec![(ErrorKind::HyphensInThirdAndFourth, 0, 2)])
+        );
+        assert_eq!(
+            _to_ascii("a.b-", true, true, true),
+            Err(vec![(ErrorKind::LeadingOrTrailingHyphen, 1, 1)])
+        );
+        assert_eq!(
+            _to_ascii("ab--c", false, true, true),
+            Ok("ab--c".to_owned())
+        );
+        assert_eq!(
+            _to_ascii("-a.b-", false, true, true),
+            Ok("-a.b-".to_owned())
+        );
+        // "xn--xn--a-" decodes to "xn--a"
+        assert_eq!(
+            _to_ascii("xn--xn--a-", false, true, true),
+            Err(vec![(ErrorKind::PunycodePrefix, 0, 0)])
+        );
+        assert_eq!(
+            _to_ascii("xn--xn--a-", true, true, true),
+            Err(vec![(ErrorKind::HyphensInThirdAndFourth, 0, 2)])
+        );
+
+        // CheckJoiners
+        assert_eq!(
+            _to_ascii("a\u{200D}b", true, true, true),
+            Err(vec![(ErrorKind::ContextJ, 0, 1)])
+        );
+        assert!(_to_ascii("a\u{200D}b", true, true, false).is_ok());
+        // After a virama
+        assert!(_to_ascii("\u{915}\u{94D}\u{200D}\u{937}", true, true, true).is_ok());
+        assert!(_to_ascii("\u{915}\u{94D}\u{200C}\u{937}", true, true, true).is_ok());
+        // Between joining characters, skipping transparent ones
+        assert!(_to_ascii("\u{628}\u{200C}\u{628}", true, true, true).is_ok());
+        assert!(_to_ascii("\u{628}\u{64B}\u{200C}\u{64B}\u{627}", true, true, true).is_ok());
+        assert_eq!(
+            _to_ascii("\u{627}\u{200C}\u{628}", true, true, true),
+            Err(vec![(ErrorKind::ContextJ, 0, 2)])
+        );
+        assert_eq!(
+            _to_ascii("\u{628}\u{200D}\u{628}", true, true, true),
+            Err(vec![(ErrorKind::ContextJ, 0, 2)])
+        );
+
+        // CheckBidi
+        assert_eq!(
+            _to_ascii("0a.\u{5D0}", true, true, true),
+            Err(vec![(ErrorKind::Bidi, 0, 0)])
+        );
+        assert_eq!(
+            _to_ascii("0a.\u{5D0}", true, false, true),
+            Ok("0a.xn--4db".to_owned())
+        );
+    }
 }
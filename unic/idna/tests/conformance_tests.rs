@@ -52,38 +52,6 @@ fn test_idn_test_data() {
 
         let test_name = format!("IdnaTest:{}", line_idx + 1);
 
-        let result = unic_idna::to_ascii(
-            &source,
-            unic_idna::Flags {
-                use_std3_ascii_rules: true,
-                transitional_processing: test_type == "T",
-                verify_dns_length: true,
-            },
-        );
-
-        if to_ascii.starts_with('[') {
-            if to_ascii.starts_with("[C") {
-                // http://unicode.org/reports/tr46/#Deviations
-                // applications that perform IDNA2008 lookup are not required to check
-                // for these contexts
-                return;
-            }
-            if to_ascii == "[V2]" {
-                // Everybody ignores V2
-                // https://github.com/servo/rust-url/pull/240
-                // https://github.com/whatwg/url/issues/53#issuecomment-181528158
-                // https://www.unicode.org/review/pri317/
-                return;
-            }
-            let res = result.ok();
-            assert_eq!(
-                res, None,
-                "{}: Expected error for source `{}`. original: `{}`",
-                test_name, source, original
-            );
-            return;
-        }
-
         let to_ascii = if !to_ascii.is_empty() {
             to_ascii.to_string()
         } else if !to_unicode.is_empty() {
@@ -92,25 +60,105 @@ fn test_idn_test_data() {
             source.clone()
         };
 
-        if nv8 == "NV8" {
-            // This result isn't valid under IDNA2008. Skip it
-            return;
-        }
-
-        assert!(
-            result.is_ok(),
-            "{}: Couldn't parse source `{}`. original: `{}`. error: `{:?}`",
-            test_name,
-            source,
-            original,
-            result.err()
-        );
-        let output = result.unwrap();
-        assert_eq!(
-            output, to_ascii,
-            "{}: Incorrect result for source `{}`. original: `{}`",
-            test_name, source, original
+        // Without CheckHyphens, V2 still rejects labels that begin with the Punycode prefix once
+        // decoded.
+        let (unicode, _) = unic_idna::to_unicode(
+            &source,
+            unic_idna::Flags {
+                use_std3_ascii_rules: true,
+                check_hyphens: false,
+                check_bidi: false,
+                check_joiners: false,
+                transitional_processing: false,
+                verify_dns_length: false,
+            },
         );
+        let has_punycode_prefix = unicode
+            .split('.')
+            .any(|label| label.starts_with(unic_idna::PUNYCODE_PREFIX));
+
+        // The expected results are for CheckHyphens, CheckBidi and CheckJoiners all set. Try every
+        // combination of them, without expecting the errors of the checks that are not set.
+        for &check_hyphens in &[true, false] {
+            for &check_bidi in &[true, false] {
+                for &check_joiners in &[true, false] {
+                    let result = unic_idna::to_ascii(
+                        &source,
+                        unic_idna::Flags {
+                            use_std3_ascii_rules: true,
+                            check_hyphens,
+                            check_bidi,
+                            check_joiners,
+                            transitional_processing: test_type == "T",
+                            verify_dns_length: true,
+                        },
+                    );
+                    let flags_name = format!(
+                        "CheckHyphens={}, CheckBidi={}, CheckJoiners={}",
+                        check_hyphens, check_bidi, check_joiners
+                    );
+
+                    if to_ascii.starts_with('[') {
+                        let expected_error = to_ascii[1..to_ascii.len() - 1]
+                            .split(|c: char| c == ',' || c.is_whitespace())
+                            .filter(|code| !code.is_empty())
+                            .any(|code| match code {
+                                "V2" => check_hyphens || has_punycode_prefix,
+                                "V3" => check_hyphens,
+                                // http://unicode.org/reports/tr46/#Deviations
+                                // applications that perform IDNA2008 lookup are not required to
+                                // check for these contexts
+                                _ if code.starts_with('C') => check_joiners,
+                                _ if code.starts_with('B') => check_bidi,
+                                _ => true,
+                            });
+                        if expected_error {
+                            assert!(
+                                result.is_err(),
+                                "{}: Expected error for source `{}`. original: `{}`. {}",
+                                test_name,
+                                source,
+                                original,
+                                flags_name
+                            );
+                        } else {
+                            assert!(
+                                result.is_ok(),
+                                "{}: Expected no error for source `{}`. original: `{}`. {}. \
+                                 error: `{}`",
+                                test_name,
+                                source,
+                                original,
+                                flags_name,
+                                result.unwrap_err()
+                            );
+                        }
+                        continue;
+                    }
+
+                    if nv8 == "NV8" {
+                        // This result isn't valid under IDNA2008. Skip it
+                        continue;
+                    }
+
+                    assert!(
+                        result.is_ok(),
+                        "{}: Couldn't parse source `{}`. original: `{}`. {}. error: `{:?}`",
+                        test_name,
+                        source,
+                        original,
+                        flags_name,
+                        result.err()
+                    );
+                    let output = result.unwrap();
+                    assert_eq!(
+                        output, to_ascii,
+                        "{}: Incorrect result for source `{}`. original: `{}`. {}",
+                        test_name, source, original, flags_name
+                    );
+                }
+            }
+        }
     }
 }
 
@@ -0,0 +1,55 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::slice;
+
+use unic_ucd_normal::nfkc_casefold;
+
+use crate::decompose::{self, Decompositions};
+use crate::recompose::{self, Recompositions};
+
+/// External iterator for the `NFKC_Casefold` mapping of a string's characters, after canonical
+/// decomposition and before the final canonical composition.
+///
+/// The mapping is defined on the canonically decomposed and reordered string, so that marks are
+/// mapped in canonical order: `NFKC_Casefold(X) = NFC(map(NFD(X)))`.
+#[derive(Clone, Debug)]
+pub struct NFKCCasefoldMapping<I> {
+    iter: Decompositions<I>,
+    buffer: slice::Iter<'static, char>,
+}
+
+#[inline]
+pub fn new_nfkc_casefold<I: Iterator<Item = char>>(
+    iter: I,
+) -> Recompositions<NFKCCasefoldMapping<I>> {
+    recompose::new_canonical(NFKCCasefoldMapping {
+        iter: decompose::new_canonical(iter),
+        buffer: [].iter(),
+    })
+}
+
+impl<I: Iterator<Item = char>> Iterator for NFKCCasefoldMapping<I> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let Some(&c) = self.buffer.next() {
+                return Some(c);
+            }
+            let ch = self.iter.next()?;
+            match nfkc_casefold(ch) {
+                Some(mapping) => self.buffer = mapping.iter(),
+                None => return Some(ch),
+            }
+        }
+    }
+}
@@ -0,0 +1,487 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Incremental normalization of chunked text, such as ropes.
+//!
+//! ## References
+//!
+//! * <https://www.unicode.org/reports/tr15/#Stable_Code_Points>
+
+use std::ops::Range;
+
+use unic_ucd_normal::{CanonicalCombiningClass, NFCQuickCheck};
+
+use crate::StrNormalForm;
+
+/// Return `true` if normalization never crosses a boundary placed right before this character.
+///
+/// That is the case for starters which never compose with a preceding character.
+#[inline]
+fn is_stable_starter(ch: char) -> bool {
+    CanonicalCombiningClass::of(ch).is_not_reordered()
+        && NFCQuickCheck::of(ch) == NFCQuickCheck::Yes
+}
+
+/// Cursor-based finder for normalization boundaries.
+///
+/// A normalization boundary is an offset in the text at which it can be split, normalized
+/// piecewise, and joined back with the same result as normalizing the whole text, in both NFC and
+/// NFD. These are the start and the end of the text, and the offsets right before starters with
+/// `NFC_Quick_Check=Yes`.
+///
+/// Like `GraphemeCursor` in `unic-segment`, the text is provided in chunks, so that the cursor
+/// can be used over ropes and other non-contiguous storage.
+#[derive(Clone, Debug)]
+pub struct NormalizationCursor {
+    /// Current cursor position.
+    offset: usize,
+
+    /// Total length of the string.
+    len: usize,
+
+    /// Set if a call to `next_boundary` was suspended due to needing more input.
+    resuming: bool,
+}
+
+/// An error return indicating that not enough content was available in the
+/// provided chunk to satisfy the query, and that more content must be provided.
+#[derive(Debug, Eq, PartialEq)]
+pub enum NormalizationIncomplete {
+    /// When requesting `prev_boundary`, the cursor is moving past the beginning
+    /// of the current chunk, so the chunk before that is requested.
+    PrevChunk,
+
+    /// When requesting `is_boundary` or `next_boundary`, the cursor is moving
+    /// past the end of the current chunk, so the chunk after that is requested.
+    NextChunk,
+
+    /// An error returned when the chunk given does not contain the cursor position.
+    InvalidOffset,
+}
+
+impl NormalizationCursor {
+    /// Create a new cursor. The string and initial offset are given at creation
+    /// time, but the contents of the string are not.
+    ///
+    /// The `offset` parameter must be on a codepoint boundary.
+    ///
+    /// ```rust
+    /// # use unic_normal::NormalizationCursor;
+    /// let s = "a\u{301}b";
+    /// let mut cursor = NormalizationCursor::new(0, s.len());
+    /// assert_eq!(cursor.next_boundary(s, 0), Ok(Some(3)));
+    /// ```
+    pub fn new(offset: usize, len: usize) -> NormalizationCursor {
+        NormalizationCursor {
+            offset,
+            len,
+            resuming: false,
+        }
+    }
+
+    /// Set the cursor to a new location in the same string.
+    pub fn set_cursor(&mut self, offset: usize) {
+        self.offset = offset;
+        self.resuming = false;
+    }
+
+    /// The current offset of the cursor. Equal to the last value provided to
+    /// `new()` or `set_cursor()`, or returned from `next_boundary()` or
+    /// `prev_boundary()`.
+    pub fn cur_cursor(&self) -> usize {
+        self.offset
+    }
+
+    fn check_offset(&self, chunk: &str, chunk_start: usize) -> Result<(), NormalizationIncomplete> {
+        if self.offset < chunk_start || self.offset > chunk_start + chunk.len() {
+            Err(NormalizationIncomplete::InvalidOffset)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Determine whether the current cursor location is a normalization boundary.
+    ///
+    /// Only a part of the string need be supplied. The chunk should contain the codepoint
+    /// following the cursor, otherwise `NormalizationIncomplete::NextChunk` is returned.
+    ///
+    /// ```rust
+    /// # use unic_normal::NormalizationCursor;
+    /// let s = "a\u{301}b";
+    /// let mut cursor = NormalizationCursor::new(1, s.len());
+    /// assert_eq!(cursor.is_boundary(s, 0), Ok(false));
+    /// cursor.set_cursor(3);
+    /// assert_eq!(cursor.is_boundary(s, 0), Ok(true));
+    /// ```
+    pub fn is_boundary(
+        &self,
+        chunk: &str,
+        chunk_start: usize,
+    ) -> Result<bool, NormalizationIncomplete> {
+        if self.offset == 0 || self.offset == self.len {
+            return Ok(true);
+        }
+        self.check_offset(chunk, chunk_start)?;
+        match chunk[self.offset - chunk_start..].chars().next() {
+            Some(ch) => Ok(is_stable_starter(ch)),
+            None => Err(NormalizationIncomplete::NextChunk),
+        }
+    }
+
+    /// Find the next boundary after the current cursor position. Only a part of
+    /// the string need be supplied. If the chunk is incomplete, then this
+    /// method might return `NormalizationIncomplete::NextChunk`, in which case
+    /// the caller should provide the chunk following the one given, then retry.
+    ///
+    /// ```rust
+    /// # use unic_normal::{NormalizationCursor, NormalizationIncomplete};
+    /// let s = "a\u{301}b";
+    /// let mut cursor = NormalizationCursor::new(0, s.len());
+    /// assert_eq!(cursor.next_boundary(&s[..1], 0), Err(NormalizationIncomplete::NextChunk));
+    /// assert_eq!(cursor.next_boundary(&s[1..], 1), Ok(Some(3)));
+    /// assert_eq!(cursor.next_boundary(&s[1..], 1), Ok(Some(4)));
+    /// assert_eq!(cursor.next_boundary(&s[1..], 1), Ok(None));
+    /// ```
+    pub fn next_boundary(
+        &mut self,
+        chunk: &str,
+        chunk_start: usize,
+    ) -> Result<Option<usize>, NormalizationIncomplete> {
+        if self.offset == self.len {
+            return Ok(None);
+        }
+        self.check_offset(chunk, chunk_start)?;
+        let chunk_end = chunk_start + chunk.len();
+        loop {
+            if self.resuming {
+                if self.is_boundary(chunk, chunk_start)? {
+                    self.resuming = false;
+                    return Ok(Some(self.offset));
+                }
+                self.resuming = false;
+            }
+            match chunk[self.offset - chunk_start..].chars().next() {
+                Some(ch) => self.offset += ch.len_utf8(),
+                None => return Err(NormalizationIncomplete::NextChunk),
+            }
+            if self.offset == self.len {
+                return Ok(Some(self.offset));
+            }
+            self.resuming = true;
+            if self.offset == chunk_end {
+                return Err(NormalizationIncomplete::NextChunk);
+            }
+        }
+    }
+
+    /// Find the previous boundary before the current cursor position. Only a part
+    /// of the string need be supplied. If the chunk is incomplete, then this
+    /// method might return `NormalizationIncomplete::PrevChunk`, in which case
+    /// the caller should provide the chunk preceding the one given, then retry.
+    ///
+    /// ```rust
+    /// # use unic_normal::{NormalizationCursor, NormalizationIncomplete};
+    /// let s = "a\u{301}b";
+    /// let mut cursor = NormalizationCursor::new(4, s.len());
+    /// assert_eq!(cursor.prev_boundary(&s[1..], 1), Ok(Some(3)));
+    /// assert_eq!(cursor.prev_boundary(&s[1..], 1), Err(NormalizationIncomplete::PrevChunk));
+    /// assert_eq!(cursor.prev_boundary(&s[..1], 0), Ok(Some(0)));
+    /// assert_eq!(cursor.prev_boundary(&s[..1], 0), Ok(None));
+    /// ```
+    pub fn prev_boundary(
+        &mut self,
+        chunk: &str,
+        chunk_start: usize,
+    ) -> Result<Option<usize>, NormalizationIncomplete> {
+        if self.offset == 0 {
+            return Ok(None);
+        }
+        self.check_offset(chunk, chunk_start)?;
+        self.resuming = false;
+        loop {
+            if self.offset == chunk_start {
+                return Err(NormalizationIncomplete::PrevChunk);
+            }
+            let ch = chunk[..self.offset - chunk_start]
+                .chars()
+                .next_back()
+                .unwrap();
+            self.offset -= ch.len_utf8();
+            if self.offset == 0 || is_stable_starter(ch) {
+                return Ok(Some(self.offset));
+            }
+        }
+    }
+}
+
+/// Find the span of text around an edit that needs to be re-normalized, and return it along with
+/// its normalized replacement.
+///
+/// `edited` is the byte range of the edited text, in the text after the edit. `chunk_at(offset)`
+/// must return a chunk of the text containing the byte at `offset`, along with the offset at
+/// which that chunk starts; for `offset` equal to `len`, it must return the last chunk.
+fn renormalize<'a, F, N>(
+    len: usize,
+    edited: Range<usize>,
+    mut chunk_at: F,
+    normalize: N,
+) -> Result<(Range<usize>, String), NormalizationIncomplete>
+where
+    F: FnMut(usize) -> (&'a str, usize),
+    N: Fn(&str) -> String,
+{
+    if edited.start > edited.end || edited.end > len {
+        return Err(NormalizationIncomplete::InvalidOffset);
+    }
+
+    // Get the chunk at `offset`, checking that it does contain it.
+    let mut chunk_at = |offset: usize| {
+        let (chunk, chunk_start) = chunk_at(offset);
+        let chunk_end = chunk_start + chunk.len();
+        if chunk_start <= offset && (offset < chunk_end || (offset == len && chunk_end == len)) {
+            Ok((chunk, chunk_start))
+        } else {
+            Err(NormalizationIncomplete::InvalidOffset)
+        }
+    };
+
+    let mut cursor = NormalizationCursor::new(edited.start, len);
+    let (mut chunk, mut chunk_start) = chunk_at(edited.start)?;
+    if !chunk.is_char_boundary(edited.start - chunk_start) {
+        return Err(NormalizationIncomplete::InvalidOffset);
+    }
+    let start = if cursor.is_boundary(chunk, chunk_start)? {
+        edited.start
+    } else {
+        loop {
+            match cursor.prev_boundary(chunk, chunk_start) {
+                Ok(offset) => break offset.unwrap_or(0),
+                Err(NormalizationIncomplete::PrevChunk) => {
+                    let prev = chunk_at(chunk_start - 1)?;
+                    chunk = prev.0;
+                    chunk_start = prev.1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    };
+
+    cursor.set_cursor(edited.end);
+    let (mut chunk, mut chunk_start) = chunk_at(edited.end)?;
+    if !chunk.is_char_boundary(edited.end - chunk_start) {
+        return Err(NormalizationIncomplete::InvalidOffset);
+    }
+    let end = if cursor.is_boundary(chunk, chunk_start)? {
+        edited.end
+    } else {
+        loop {
+            match cursor.next_boundary(chunk, chunk_start) {
+                Ok(offset) => break offset.unwrap_or(len),
+                Err(NormalizationIncomplete::NextChunk) => {
+                    let next = chunk_at(chunk_start + chunk.len())?;
+                    chunk = next.0;
+                    chunk_start = next.1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    };
+
+    let mut text = String::with_capacity(end - start);
+    let mut offset = start;
+    while offset < end {
+        let (chunk, chunk_start) = chunk_at(offset)?;
+        let chunk_end = chunk_start + chunk.len();
+        let piece_end = if end < chunk_end { end } else { chunk_end };
+        text.push_str(&chunk[offset - chunk_start..piece_end - chunk_start]);
+        offset = piece_end;
+    }
+
+    Ok((start..end, normalize(&text)))
+}
+
+/// Re-normalize chunked text to NFC around an edit, without materializing the whole text.
+///
+/// `len` is the total length of the text, and `edited` is the byte range of the edited text, in
+/// the text after the edit. `chunk_at(offset)` must return a chunk of the text containing the byte
+/// at `offset`, along with the offset at which that chunk starts; for `offset` equal to `len`, it
+/// must return the last chunk.
+///
+/// Returns the byte range to replace, which contains `edited` and is extended to the nearest
+/// normalization boundaries, along with its replacement in NFC. Provided that the text was in NFC
+/// before the edit, replacing this range makes the whole text NFC again.
+///
+/// Returns `NormalizationIncomplete::InvalidOffset` if `edited` is not within the text or not on
+/// codepoint boundaries, or if `chunk_at()` returns a chunk that does not contain the requested
+/// offset.
+///
+/// ```rust
+/// use unic_normal::renormalize_nfc;
+///
+/// // A combining acute accent was inserted after "e"
+/// let chunks = ["cafe", "\u{301} au lait"];
+/// let chunk_at = |offset: usize| {
+///     if offset < 4 {
+///         (chunks[0], 0)
+///     } else {
+///         (chunks[1], 4)
+///     }
+/// };
+///
+/// let (range, replacement) = renormalize_nfc(14, 4..6, chunk_at).unwrap();
+/// assert_eq!(range, 3..6);
+/// assert_eq!(replacement, "\u{e9}");
+/// ```
+pub fn renormalize_nfc<'a, F>(
+    len: usize,
+    edited: Range<usize>,
+    chunk_at: F,
+) -> Result<(Range<usize>, String), NormalizationIncomplete>
+where
+    F: FnMut(usize) -> (&'a str, usize),
+{
+    renormalize(len, edited, chunk_at, |s| s.nfc().collect())
+}
+
+/// Re-normalize chunked text to NFD around an edit, without materializing the whole text.
+///
+/// See `renormalize_nfc()` for the details.
+pub fn renormalize_nfd<'a, F>(
+    len: usize,
+    edited: Range<usize>,
+    chunk_at: F,
+) -> Result<(Range<usize>, String), NormalizationIncomplete>
+where
+    F: FnMut(usize) -> (&'a str, usize),
+{
+    renormalize(len, edited, chunk_at, |s| s.nfd().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{renormalize_nfc, renormalize_nfd, NormalizationCursor, NormalizationIncomplete};
+    use crate::StrNormalForm;
+
+    /// Split `text` into chunks of at most `size` bytes, on codepoint boundaries.
+    fn chunks(text: &str, size: usize) -> Vec<(&str, usize)> {
+        let mut result = vec![];
+        let mut start = 0;
+        while start < text.len() {
+            let mut end = start + size;
+            if end >= text.len() {
+                end = text.len();
+            } else {
+                while !text.is_char_boundary(end) {
+                    end += 1;
+                }
+            }
+            result.push((&text[start..end], start));
+            start = end;
+        }
+        result
+    }
+
+    fn chunk_at<'a>(chunks: &[(&'a str, usize)], offset: usize) -> (&'a str, usize) {
+        for &(chunk, start) in chunks {
+            if offset < start + chunk.len() {
+                return (chunk, start);
+            }
+        }
+        *chunks.last().unwrap()
+    }
+
+    #[test]
+    fn test_boundaries() {
+        let s = "a\u{301}\u{1e0b}\u{323}\u{1100}\u{1161}\u{11a8}x";
+        let mut boundaries = vec![0];
+        let mut cursor = NormalizationCursor::new(0, s.len());
+        while let Some(offset) = cursor.next_boundary(s, 0).unwrap() {
+            boundaries.push(offset);
+        }
+        assert_eq!(boundaries, [0, 3, 8, 17, 18]);
+
+        let mut rev_boundaries = vec![s.len()];
+        while let Some(offset) = cursor.prev_boundary(s, 0).unwrap() {
+            rev_boundaries.push(offset);
+        }
+        rev_boundaries.reverse();
+        assert_eq!(rev_boundaries, boundaries);
+    }
+
+    #[test]
+    fn test_chunked_boundaries() {
+        let s = "a\u{301}\u{1e0b}\u{323}\u{1100}\u{1161}\u{11a8}x";
+        for size in 1..s.len() {
+            let chunks = chunks(s, size);
+            let mut boundaries = vec![0];
+            let mut cursor = NormalizationCursor::new(0, s.len());
+            let mut idx = 0;
+            loop {
+                let (chunk, start) = chunks[idx];
+                match cursor.next_boundary(chunk, start) {
+                    Ok(Some(offset)) => boundaries.push(offset),
+                    Ok(None) => break,
+                    Err(NormalizationIncomplete::NextChunk) => idx += 1,
+                    Err(err) => panic!("unexpected {:?}", err),
+                }
+            }
+            assert_eq!(boundaries, [0, 3, 8, 17, 18]);
+        }
+    }
+
+    #[test]
+    fn test_renormalize() {
+        let cases = [
+            ("cafe\u{301}", 4..6, 3..6),
+            ("\u{1e0b}\u{323}x", 3..5, 0..5),
+            ("x\u{1100}\u{1161}\u{11a8}y", 4..7, 1..10),
+            ("abc", 1..2, 1..2),
+            ("abc", 3..3, 3..3),
+            ("\u{301}\u{301}a", 0..2, 0..4),
+        ];
+        for &(text, ref edited, ref expected) in cases.iter() {
+            for size in 1..text.len() + 1 {
+                let chunks = chunks(text, size);
+                let (range, nfc) = renormalize_nfc(text.len(), edited.clone(), |offset| {
+                    chunk_at(&chunks, offset)
+                })
+                .unwrap();
+                assert_eq!(&range, expected);
+                assert_eq!(nfc, text[range.clone()].nfc().collect::<String>());
+
+                let (range, nfd) = renormalize_nfd(text.len(), edited.clone(), |offset| {
+                    chunk_at(&chunks, offset)
+                })
+                .unwrap();
+                assert_eq!(&range, expected);
+                assert_eq!(nfd, text[range.clone()].nfd().collect::<String>());
+            }
+        }
+    }
+
+    #[test]
+    fn test_renormalize_invalid() {
+        let text = "cafe\u{301}";
+        let whole = |_| (text, 0);
+        assert_eq!(
+            renormalize_nfc(text.len(), 4..7, whole),
+            Err(NormalizationIncomplete::InvalidOffset)
+        );
+        assert_eq!(
+            renormalize_nfc(text.len(), 5..6, whole),
+            Err(NormalizationIncomplete::InvalidOffset)
+        );
+        // The chunk does not contain the offset
+        assert_eq!(
+            renormalize_nfd(text.len(), 4..6, |_| (&text[..4], 0)),
+            Err(NormalizationIncomplete::InvalidOffset)
+        );
+    }
+}
@@ -0,0 +1,348 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::Chars;
+
+use unic_ucd_normal::{decompose_canonical, decompose_compatible, CanonicalCombiningClass};
+
+use crate::StrNormalForm;
+
+/// Return `true` if the two strings are canonically equivalent, that is, if they have the same
+/// Normalization Form D.
+///
+/// The decompositions are compared one starter and its non-starters at a time, in a buffer on
+/// the stack, so that no memory is allocated. Only a run of more than 32 non-starters, which does
+/// not occur in ordinary text, falls back to normalizing the strings.
+///
+/// ```rust
+/// use unic_normal::canonical_eq;
+///
+/// assert!(canonical_eq("\u{c5}", "A\u{30a}"));
+/// assert!(canonical_eq("\u{212b}", "\u{c5}"));
+/// assert!(!canonical_eq("\u{fb01}", "fi"));
+/// ```
+pub fn canonical_eq(a: &str, b: &str) -> bool {
+    a == b || decompositions_eq(a, b, false).unwrap_or_else(|| a.nfd().eq(b.nfd()))
+}
+
+/// Return `true` if the two strings are compatibility equivalent, that is, if they have the same
+/// Normalization Form KD.
+///
+/// The decompositions are compared one starter and its non-starters at a time, in a buffer on
+/// the stack, so that no memory is allocated. Only a run of more than 32 non-starters, which does
+/// not occur in ordinary text, falls back to normalizing the strings.
+///
+/// ```rust
+/// use unic_normal::compatibility_eq;
+///
+/// assert!(compatibility_eq("\u{c5}", "A\u{30a}"));
+/// assert!(compatibility_eq("\u{fb01}", "fi"));
+/// assert!(!compatibility_eq("\u{fb01}", "FI"));
+/// ```
+pub fn compatibility_eq(a: &str, b: &str) -> bool {
+    a == b || decompositions_eq(a, b, true).unwrap_or_else(|| a.nfkd().eq(b.nfkd()))
+}
+
+// Number of characters from a starter to the next that are compared in place.
+const SEGMENT_CAPACITY: usize = 32;
+
+// Longest full decomposition of a character, that of U+FDFA ARABIC LIGATURE SALLALLAHOU ALAYHE
+// WASALLAM.
+const MAX_DECOMPOSITION_LEN: usize = 18;
+
+type Segment = [(char, CanonicalCombiningClass); SEGMENT_CAPACITY];
+
+// Compare the decompositions of two strings, or return `None` if a segment does not fit in the
+// buffer.
+fn decompositions_eq(a: &str, b: &str, compatible: bool) -> Option<bool> {
+    let mut a = Decomposed::new(a, compatible);
+    let mut b = Decomposed::new(b, compatible);
+    let mut a_segment = [('\0', CanonicalCombiningClass::NotReordered); SEGMENT_CAPACITY];
+    let mut b_segment = a_segment;
+    loop {
+        let a_len = a.read_segment(&mut a_segment)?;
+        let b_len = b.read_segment(&mut b_segment)?;
+        if a_len != b_len {
+            return Some(false);
+        }
+        if a_len == 0 {
+            return Some(true);
+        }
+        if a_segment[..a_len]
+            .iter()
+            .zip(&b_segment[..b_len])
+            .any(|(a, b)| a.0 != b.0)
+        {
+            return Some(false);
+        }
+    }
+}
+
+// The characters of a string's decomposition, before canonical ordering.
+struct Decomposed<'a> {
+    chars: Chars<'a>,
+    compatible: bool,
+    // Decomposition of the last character read from `chars`, and the position in it
+    buffer: [char; MAX_DECOMPOSITION_LEN],
+    len: usize,
+    pos: usize,
+}
+
+impl<'a> Decomposed<'a> {
+    fn new(s: &'a str, compatible: bool) -> Decomposed<'a> {
+        Decomposed {
+            chars: s.chars(),
+            compatible,
+            buffer: ['\0'; MAX_DECOMPOSITION_LEN],
+            len: 0,
+            pos: 0,
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        if self.pos == self.len {
+            let ch = self.chars.next()?;
+            let buffer = &mut self.buffer;
+            let mut len = 0;
+            let callback = |d| {
+                buffer[len] = d;
+                len += 1;
+            };
+            if self.compatible {
+                decompose_compatible(ch, callback);
+            } else {
+                decompose_canonical(ch, callback);
+            }
+            self.len = len;
+            self.pos = 0;
+        }
+        Some(self.buffer[self.pos])
+    }
+
+    // Read the next starter and the non-starters after it into `segment`, in canonical order, and
+    // return their number, which is zero at the end of the string. Return `None` if they do not
+    // fit.
+    fn read_segment(&mut self, segment: &mut Segment) -> Option<usize> {
+        let mut len = 0;
+        while let Some(ch) = self.peek() {
+            let ccc = CanonicalCombiningClass::of(ch);
+            if ccc.is_not_reordered() && len > 0 {
+                break;
+            }
+            if len == SEGMENT_CAPACITY {
+                return None;
+            }
+            // Insert the character after the ones of lower or equal class, which keeps the
+            // starter first.
+            let mut i = len;
+            while i > 0 && segment[i - 1].1 > ccc {
+                segment[i] = segment[i - 1];
+                i -= 1;
+            }
+            segment[i] = (ch, ccc);
+            len += 1;
+            self.pos += 1;
+        }
+        Some(len)
+    }
+}
+
+/// A string wrapper whose `Eq`, `Ord`, and `Hash` implementations respect canonical
+/// equivalence.
+///
+/// Two `CanonicalStr` values are equal if their contents have the same Normalization Form D, and
+/// they are ordered by the code points of their NFD forms. This allows canonically equivalent
+/// strings to be used interchangeably as keys of a `HashMap` or `BTreeMap`, while keeping the
+/// original text as-is.
+///
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// use unic_normal::CanonicalStr;
+///
+/// let mut map = HashMap::new();
+/// map.insert(CanonicalStr::new("caf\u{e9}"), 1);
+/// assert_eq!(map.get(&CanonicalStr::new("cafe\u{301}")), Some(&1));
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct CanonicalStr<S: AsRef<str> = String>(S);
+
+impl<S: AsRef<str>> CanonicalStr<S> {
+    /// Wrap a string.
+    pub fn new(s: S) -> CanonicalStr<S> {
+        CanonicalStr(s)
+    }
+
+    /// Return the wrapped string, as given.
+    pub fn as_str(&self) -> &str {
+        self.0.as_ref()
+    }
+
+    /// Unwrap the string.
+    pub fn into_inner(self) -> S {
+        self.0
+    }
+}
+
+impl<S: AsRef<str>> From<S> for CanonicalStr<S> {
+    fn from(s: S) -> Self {
+        CanonicalStr(s)
+    }
+}
+
+impl<S: AsRef<str>> AsRef<str> for CanonicalStr<S> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<S: AsRef<str>, T: AsRef<str>> PartialEq<CanonicalStr<T>> for CanonicalStr<S> {
+    fn eq(&self, other: &CanonicalStr<T>) -> bool {
+        canonical_eq(self.as_str(), other.as_str())
+    }
+}
+
+impl<S: AsRef<str>> Eq for CanonicalStr<S> {}
+
+impl<S: AsRef<str>, T: AsRef<str>> PartialOrd<CanonicalStr<T>> for CanonicalStr<S> {
+    fn partial_cmp(&self, other: &CanonicalStr<T>) -> Option<Ordering> {
+        Some(self.as_str().nfd().cmp(other.as_str().nfd()))
+    }
+}
+
+impl<S: AsRef<str>> Ord for CanonicalStr<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().nfd().cmp(other.as_str().nfd())
+    }
+}
+
+impl<S: AsRef<str>> Hash for CanonicalStr<S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for c in self.as_str().nfd() {
+            state.write_u32(c as u32);
+        }
+        // Same as `str`, to avoid prefix collisions
+        state.write_u8(0xff);
+    }
+}
+
+impl<S: AsRef<str>> fmt::Debug for CanonicalStr<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CanonicalStr").field(&self.as_str()).finish()
+    }
+}
+
+impl<S: AsRef<str>> fmt::Display for CanonicalStr<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::{BTreeSet, HashSet};
+    use std::hash::{Hash, Hasher};
+
+    use unic_ucd_normal::decompose_compatible;
+
+    use super::{
+        canonical_eq, compatibility_eq, CanonicalStr, MAX_DECOMPOSITION_LEN, SEGMENT_CAPACITY,
+    };
+    use crate::StrNormalForm;
+
+    fn hash<T: Hash>(t: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        t.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_canonical_eq() {
+        assert!(canonical_eq("", ""));
+        assert!(canonical_eq("abc", "abc"));
+        assert!(!canonical_eq("abc", "abd"));
+        assert!(!canonical_eq("abc", "ab"));
+        assert!(canonical_eq("\u{1e0b}\u{323}", "\u{1e0d}\u{307}"));
+        assert!(canonical_eq("d\u{307}\u{323}", "d\u{323}\u{307}"));
+        assert!(canonical_eq("\u{2126}", "\u{3a9}"));
+        assert!(canonical_eq("\u{d4db}", "\u{1111}\u{1171}\u{11b6}"));
+        assert!(!canonical_eq("\u{2026}", "..."));
+        assert!(!canonical_eq("\u{1e9b}\u{323}", "s\u{323}\u{307}"));
+    }
+
+    #[test]
+    fn test_compatibility_eq() {
+        assert!(compatibility_eq("abc", "abc"));
+        assert!(!compatibility_eq("abc", "ABC"));
+        assert!(compatibility_eq("\u{1e0b}\u{323}", "\u{1e0d}\u{307}"));
+        assert!(compatibility_eq("\u{2026}", "..."));
+        assert!(compatibility_eq("\u{1e9b}\u{323}", "s\u{323}\u{307}"));
+        assert!(compatibility_eq("\u{1c4}", "DZ\u{30c}"));
+        assert!(compatibility_eq(
+            "\u{fdfa}\u{fb01}",
+            &"\u{fdfa}fi".nfkd().collect::<String>()
+        ));
+    }
+
+    #[test]
+    fn test_long_segments() {
+        let acutes = "\u{301}".repeat(SEGMENT_CAPACITY);
+        let a = format!("a{}\u{323}b", acutes);
+        let b = format!("a\u{323}{}b", acutes);
+        assert!(canonical_eq(&a, &b));
+        assert!(compatibility_eq(&a, &b));
+        assert!(!canonical_eq(&a, &format!("a{}b", acutes)));
+
+        let cedillas = "\u{327}".repeat(SEGMENT_CAPACITY - 1);
+        assert!(canonical_eq(
+            &format!("\u{1e0b}{}", cedillas),
+            &format!("d{}\u{307}", cedillas),
+        ));
+    }
+
+    #[test]
+    fn test_max_decomposition_len() {
+        for ch in (0..=0x10_ffff).filter_map(char::from_u32) {
+            let mut len = 0;
+            decompose_compatible(ch, |_| len += 1);
+            assert!(len <= MAX_DECOMPOSITION_LEN, "{:?}", ch);
+        }
+    }
+
+    #[test]
+    fn test_canonical_str() {
+        let a = CanonicalStr::new("\u{1e0b}\u{323}");
+        let b = CanonicalStr::new(String::from("d\u{323}\u{307}"));
+        let c = CanonicalStr::new("d\u{323}");
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(hash(&a), hash(&CanonicalStr::new("\u{1e0d}\u{307}")));
+        assert!(c < a);
+        assert_eq!(a.as_str(), "\u{1e0b}\u{323}");
+        assert_eq!(format!("{}", a), "\u{1e0b}\u{323}");
+
+        let set: HashSet<_> = vec!["\u{c5}", "A\u{30a}", "\u{212b}", "A"]
+            .into_iter()
+            .map(CanonicalStr::new)
+            .collect();
+        assert_eq!(set.len(), 2);
+
+        let set: BTreeSet<_> = vec!["\u{c5}", "A\u{30a}", "\u{212b}", "B"]
+            .into_iter()
+            .map(CanonicalStr::new)
+            .collect();
+        assert_eq!(set.len(), 2);
+    }
+}
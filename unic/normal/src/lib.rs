@@ -36,13 +36,26 @@
 //! }
 //! ```
 
+mod casefold;
+mod cursor;
 mod decompose;
+mod equivalence;
 mod recompose;
+mod utf16;
 
 use std::str::Chars;
 
+pub use crate::casefold::NFKCCasefoldMapping;
+pub use crate::cursor::{
+    renormalize_nfc,
+    renormalize_nfd,
+    NormalizationCursor,
+    NormalizationIncomplete,
+};
 pub use crate::decompose::Decompositions;
+pub use crate::equivalence::{canonical_eq, compatibility_eq, CanonicalStr};
 pub use crate::recompose::Recompositions;
+pub use crate::utf16::{UnpairedSurrogates, Utf16NormalForm, Utf16Normalization};
 pub use unic_ucd_normal::UNICODE_VERSION;
 
 mod pkg_info;
@@ -67,6 +80,24 @@ pub trait StrNormalForm<I: Iterator<Item = char>> {
     /// An Iterator over the string in Unicode Normalization Form KC
     /// (compatibility decomposition followed by canonical composition).
     fn nfkc(self) -> Recompositions<I>;
+
+    /// An Iterator over the string under the `NFKC_Casefold` transformation
+    /// (canonical decomposition, `NFKC_Casefold` mapping of each character, then canonical
+    /// composition).
+    ///
+    /// This removes case, compatibility, and default-ignorable differences, and is suitable for
+    /// caseless identifier matching as described in
+    /// [Unicode Standard Annex #31](https://www.unicode.org/reports/tr31/#Case_and_Stability).
+    ///
+    /// ```rust
+    /// use unic_normal::StrNormalForm;
+    ///
+    /// let a = "Stra\u{df}e";
+    /// let b = "\u{ff33}TRASSE\u{ad}";
+    /// assert!(a.nfkc_casefold().eq(b.nfkc_casefold()));
+    /// assert_eq!(a.nfkc_casefold().collect::<String>(), "strasse");
+    /// ```
+    fn nfkc_casefold(self) -> Recompositions<NFKCCasefoldMapping<I>>;
 }
 
 impl<'a> StrNormalForm<Chars<'a>> for &'a str {
@@ -89,6 +120,11 @@ impl<'a> StrNormalForm<Chars<'a>> for &'a str {
     fn nfkc(self) -> Recompositions<Chars<'a>> {
         recompose::new_compatible(self.chars())
     }
+
+    #[inline]
+    fn nfkc_casefold(self) -> Recompositions<NFKCCasefoldMapping<Chars<'a>>> {
+        casefold::new_nfkc_casefold(self.chars())
+    }
 }
 
 impl<I: Iterator<Item = char>> StrNormalForm<I> for I {
@@ -111,6 +147,11 @@ impl<I: Iterator<Item = char>> StrNormalForm<I> for I {
     fn nfkc(self) -> Recompositions<I> {
         recompose::new_compatible(self)
     }
+
+    #[inline]
+    fn nfkc_casefold(self) -> Recompositions<NFKCCasefoldMapping<I>> {
+        casefold::new_nfkc_casefold(self)
+    }
 }
 
 #[cfg(test)]
@@ -206,4 +247,27 @@ mod tests {
             "\u{e0}\u{5ae}\u{305}\u{315}b"
         );
     }
+
+    #[test]
+    fn test_nfkc_casefold() {
+        macro_rules! nfkc_casefold_eq {
+            ($input: expr, $expected: expr) => {
+                assert_eq!($input.nfkc_casefold().to_string(), $expected);
+            };
+        }
+        nfkc_casefold_eq!("abc", "abc");
+        nfkc_casefold_eq!("ABC", "abc");
+        nfkc_casefold_eq!("\u{1e0b}\u{1c4}", "\u{1e0b}d\u{17e}");
+        nfkc_casefold_eq!("\u{2026}", "...");
+        nfkc_casefold_eq!("\u{2126}", "\u{3c9}");
+        nfkc_casefold_eq!("A\u{301}", "\u{e1}");
+        nfkc_casefold_eq!("\u{c1}", "\u{e1}");
+        nfkc_casefold_eq!("\u{df}", "ss");
+        nfkc_casefold_eq!("\u{ff21}\u{ff22}\u{ff23}", "abc");
+        nfkc_casefold_eq!("a\u{ad}b\u{200b}c", "abc");
+        nfkc_casefold_eq!("\u{d4db}", "\u{d4db}");
+        // Marks are mapped after canonical reordering
+        nfkc_casefold_eq!("\u{3b1}\u{345}\u{301}", "\u{3ac}\u{3b9}");
+        nfkc_casefold_eq!("\u{3b1}\u{301}\u{345}", "\u{3ac}\u{3b9}");
+    }
 }
@@ -0,0 +1,329 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::char::{self, DecodeUtf16};
+use std::iter::Cloned;
+use std::slice;
+
+use crate::decompose::{self, Decompositions};
+use crate::recompose::{self, Recompositions};
+
+/// How unpaired surrogate code units in UTF-16 input are handled during normalization.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnpairedSurrogates {
+    /// Pass unpaired surrogates through to the output unchanged.
+    ///
+    /// An unpaired surrogate never combines with the surrounding text, so it acts like a
+    /// starter that blocks reordering and composition across it.
+    Preserve,
+
+    /// Replace each unpaired surrogate with U+FFFD REPLACEMENT CHARACTER.
+    Replace,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Form {
+    D,
+    KD,
+    C,
+    KC,
+}
+
+/// Iterator over the characters of a UTF-16 buffer, with unpaired surrogates replaced by
+/// U+FFFD REPLACEMENT CHARACTER.
+#[derive(Clone, Debug)]
+struct Utf16Chars<'a> {
+    iter: DecodeUtf16<Cloned<slice::Iter<'a, u16>>>,
+}
+
+impl<'a> Utf16Chars<'a> {
+    fn new(units: &'a [u16]) -> Utf16Chars<'a> {
+        Utf16Chars {
+            iter: char::decode_utf16(units.iter().cloned()),
+        }
+    }
+}
+
+impl<'a> Iterator for Utf16Chars<'a> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        self.iter
+            .next()
+            .map(|r| r.unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Segment<'a> {
+    Decompositions(Decompositions<Utf16Chars<'a>>),
+    Recompositions(Recompositions<Utf16Chars<'a>>),
+}
+
+impl<'a> Segment<'a> {
+    fn new(form: Form, units: &'a [u16]) -> Segment<'a> {
+        let chars = Utf16Chars::new(units);
+        match form {
+            Form::D => Segment::Decompositions(decompose::new_canonical(chars)),
+            Form::KD => Segment::Decompositions(decompose::new_compatible(chars)),
+            Form::C => Segment::Recompositions(recompose::new_canonical(chars)),
+            Form::KC => Segment::Recompositions(recompose::new_compatible(chars)),
+        }
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        match *self {
+            Segment::Decompositions(ref mut iter) => iter.next(),
+            Segment::Recompositions(ref mut iter) => iter.next(),
+        }
+    }
+}
+
+/// External iterator for the UTF-16 code units of a normalized UTF-16 buffer.
+///
+/// The input is split at unpaired surrogates (when preserved), and each well-formed segment is
+/// normalized with the same `Decompositions`/`Recompositions` logic used for `&str` input.
+#[derive(Clone, Debug)]
+pub struct Utf16Normalization<'a> {
+    form: Form,
+    unpaired: UnpairedSurrogates,
+    rest: &'a [u16],
+    segment: Option<Segment<'a>>,
+    surrogate: Option<u16>,
+    buffer: [u16; 2],
+    buffer_pos: usize,
+    buffer_len: usize,
+}
+
+impl<'a> Utf16Normalization<'a> {
+    fn new(form: Form, units: &'a [u16], unpaired: UnpairedSurrogates) -> Utf16Normalization<'a> {
+        Utf16Normalization {
+            form,
+            unpaired,
+            rest: units,
+            segment: None,
+            surrogate: None,
+            buffer: [0; 2],
+            buffer_pos: 0,
+            buffer_len: 0,
+        }
+    }
+
+    /// Start normalizing the next segment of the input, up to the next unpaired surrogate.
+    fn next_segment(&mut self) {
+        let end = match self.unpaired {
+            UnpairedSurrogates::Preserve => find_unpaired_surrogate(self.rest),
+            UnpairedSurrogates::Replace => None,
+        };
+        let (segment, rest) = match end {
+            Some(idx) => {
+                self.surrogate = Some(self.rest[idx]);
+                (&self.rest[..idx], &self.rest[idx + 1..])
+            }
+            None => (self.rest, &[][..]),
+        };
+        self.segment = Some(Segment::new(self.form, segment));
+        self.rest = rest;
+    }
+}
+
+impl<'a> Iterator for Utf16Normalization<'a> {
+    type Item = u16;
+
+    fn next(&mut self) -> Option<u16> {
+        loop {
+            if self.buffer_pos < self.buffer_len {
+                self.buffer_pos += 1;
+                return Some(self.buffer[self.buffer_pos - 1]);
+            }
+
+            if let Some(ref mut segment) = self.segment {
+                if let Some(c) = segment.next() {
+                    self.buffer_len = c.encode_utf16(&mut self.buffer).len();
+                    self.buffer_pos = 0;
+                    continue;
+                }
+            }
+            self.segment = None;
+
+            if let Some(surrogate) = self.surrogate.take() {
+                return Some(surrogate);
+            }
+            if self.rest.is_empty() {
+                return None;
+            }
+            self.next_segment();
+        }
+    }
+}
+
+/// Return the index of the first unpaired surrogate code unit, if any.
+fn find_unpaired_surrogate(units: &[u16]) -> Option<usize> {
+    let mut idx = 0;
+    while idx < units.len() {
+        match units[idx] {
+            0xD800..=0xDBFF => {
+                if idx + 1 < units.len() && matches!(units[idx + 1], 0xDC00..=0xDFFF) {
+                    idx += 2;
+                } else {
+                    return Some(idx);
+                }
+            }
+            0xDC00..=0xDFFF => return Some(idx),
+            _ => idx += 1,
+        }
+    }
+    None
+}
+
+/// Methods for iterating over UTF-16 buffers while applying Unicode normalizations
+/// as described in
+/// [Unicode Standard Annex #15](https://www.unicode.org/reports/tr15/).
+///
+/// The results are the same as normalizing the equivalent `&str`, encoded back to UTF-16.
+///
+/// ```rust
+/// use unic_normal::{UnpairedSurrogates, Utf16NormalForm};
+///
+/// let input: Vec<u16> = "A\u{30a}".encode_utf16().collect();
+/// let nfc: Vec<u16> = input.nfc(UnpairedSurrogates::Preserve).collect();
+/// assert_eq!(nfc, [0xC5]);
+///
+/// let input = [0x41, 0xD800, 0x30A];
+/// let nfc: Vec<u16> = input.nfc(UnpairedSurrogates::Preserve).collect();
+/// assert_eq!(nfc, [0x41, 0xD800, 0x30A]);
+/// let nfc: Vec<u16> = input.nfc(UnpairedSurrogates::Replace).collect();
+/// assert_eq!(nfc, [0x41, 0xFFFD, 0x30A]);
+/// ```
+pub trait Utf16NormalForm {
+    /// Returns an iterator over the buffer in Unicode Normalization Form D
+    /// (canonical decomposition).
+    fn nfd(&self, unpaired: UnpairedSurrogates) -> Utf16Normalization<'_>;
+
+    /// Returns an iterator over the buffer in Unicode Normalization Form KD
+    /// (compatibility decomposition).
+    fn nfkd(&self, unpaired: UnpairedSurrogates) -> Utf16Normalization<'_>;
+
+    /// An Iterator over the buffer in Unicode Normalization Form C
+    /// (canonical decomposition followed by canonical composition).
+    fn nfc(&self, unpaired: UnpairedSurrogates) -> Utf16Normalization<'_>;
+
+    /// An Iterator over the buffer in Unicode Normalization Form KC
+    /// (compatibility decomposition followed by canonical composition).
+    fn nfkc(&self, unpaired: UnpairedSurrogates) -> Utf16Normalization<'_>;
+}
+
+impl Utf16NormalForm for [u16] {
+    #[inline]
+    fn nfd(&self, unpaired: UnpairedSurrogates) -> Utf16Normalization<'_> {
+        Utf16Normalization::new(Form::D, self, unpaired)
+    }
+
+    #[inline]
+    fn nfkd(&self, unpaired: UnpairedSurrogates) -> Utf16Normalization<'_> {
+        Utf16Normalization::new(Form::KD, self, unpaired)
+    }
+
+    #[inline]
+    fn nfc(&self, unpaired: UnpairedSurrogates) -> Utf16Normalization<'_> {
+        Utf16Normalization::new(Form::C, self, unpaired)
+    }
+
+    #[inline]
+    fn nfkc(&self, unpaired: UnpairedSurrogates) -> Utf16Normalization<'_> {
+        Utf16Normalization::new(Form::KC, self, unpaired)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{UnpairedSurrogates, Utf16NormalForm};
+    use crate::StrNormalForm;
+
+    fn utf16(s: &str) -> Vec<u16> {
+        s.encode_utf16().collect()
+    }
+
+    #[test]
+    fn test_matches_str() {
+        let inputs = [
+            "",
+            "abc",
+            "\u{1e0b}\u{1c4}",
+            "\u{2026}",
+            "\u{2126}",
+            "\u{1e0b}\u{323}",
+            "a\u{301}",
+            "\u{301}a",
+            "\u{d4db}",
+            "\u{ac1c}",
+            "a\u{300}\u{305}\u{315}\u{5ae}b",
+            "\u{1d15e}\u{1d165}",
+            "\u{1f468}\u{200d}\u{1f469}",
+        ];
+        for input in inputs.iter() {
+            let units = utf16(input);
+            for &unpaired in &[UnpairedSurrogates::Preserve, UnpairedSurrogates::Replace] {
+                assert_eq!(
+                    units.nfd(unpaired).collect::<Vec<_>>(),
+                    utf16(&input.nfd().to_string())
+                );
+                assert_eq!(
+                    units.nfkd(unpaired).collect::<Vec<_>>(),
+                    utf16(&input.nfkd().to_string())
+                );
+                assert_eq!(
+                    units.nfc(unpaired).collect::<Vec<_>>(),
+                    utf16(&input.nfc().to_string())
+                );
+                assert_eq!(
+                    units.nfkc(unpaired).collect::<Vec<_>>(),
+                    utf16(&input.nfkc().to_string())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_unpaired_surrogates() {
+        let units = [0x61, 0x301, 0xDC00, 0x1E0B, 0x323, 0xD800];
+        assert_eq!(
+            units.nfc(UnpairedSurrogates::Preserve).collect::<Vec<_>>(),
+            [0xE1, 0xDC00, 0x1E0D, 0x307, 0xD800]
+        );
+        assert_eq!(
+            units.nfc(UnpairedSurrogates::Replace).collect::<Vec<_>>(),
+            [0xE1, 0xFFFD, 0x1E0D, 0x307, 0xFFFD]
+        );
+
+        // Unpaired surrogates block reordering, just like U+FFFD does
+        let units = [0x61, 0x307, 0xD800, 0x323];
+        assert_eq!(
+            units.nfd(UnpairedSurrogates::Preserve).collect::<Vec<_>>(),
+            [0x61, 0x307, 0xD800, 0x323]
+        );
+
+        let units = [0xD800, 0xDC00, 0xDC00, 0xD800];
+        assert_eq!(
+            units.nfd(UnpairedSurrogates::Preserve).collect::<Vec<_>>(),
+            [0xD800, 0xDC00, 0xDC00, 0xD800]
+        );
+        assert_eq!(
+            units.nfd(UnpairedSurrogates::Replace).collect::<Vec<_>>(),
+            [0xD800, 0xDC00, 0xFFFD, 0xFFFD]
+        );
+    }
+}
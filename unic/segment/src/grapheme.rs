@@ -17,32 +17,54 @@
 
 use std::cmp;
 
+use unic_common::source::{CharIndices, CodeUnitSource};
 use unic_ucd_segment::GraphemeClusterBreak as GCB;
 
 /// External iterator for grapheme clusters and byte offsets.
-#[derive(Clone, Debug)]
-pub struct GraphemeIndices<'a> {
-    start_offset: usize,
-    iter: Graphemes<'a>,
+///
+/// Also works on other `CodeUnitSource`s, with offsets in their code units.
+#[derive(Debug)]
+pub struct GraphemeIndices<'a, T: ?Sized = str> {
+    iter: Graphemes<'a, T>,
+}
+
+impl<'a, T: ?Sized> Clone for GraphemeIndices<'a, T> {
+    fn clone(&self) -> Self {
+        GraphemeIndices {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<'a, T: CodeUnitSource + ?Sized> GraphemeIndices<'a, T> {
+    /// Create new iterator for *extended grapheme clusters* of any code unit source.
+    #[inline]
+    pub fn from_source(s: &'a T) -> GraphemeIndices<'a, T> {
+        GraphemeIndices {
+            iter: Graphemes::from_source(s),
+        }
+    }
+
+    /// Create new iterator for *legacy grapheme clusters* of any code unit source.
+    #[inline]
+    pub fn from_source_legacy(s: &'a T) -> GraphemeIndices<'a, T> {
+        GraphemeIndices {
+            iter: Graphemes::from_source_legacy(s),
+        }
+    }
 }
 
 impl<'a> GraphemeIndices<'a> {
     /// Create new iterator for *extended grapheme clusters*.
     #[inline]
     pub fn new(s: &str) -> GraphemeIndices<'_> {
-        GraphemeIndices {
-            start_offset: s.as_ptr() as usize,
-            iter: Graphemes::new(s),
-        }
+        GraphemeIndices::from_source(s)
     }
 
     /// Create new iterator for *legacy grapheme clusters*.
     #[inline]
     pub fn new_legacy(s: &str) -> GraphemeIndices<'_> {
-        GraphemeIndices {
-            start_offset: s.as_ptr() as usize,
-            iter: Graphemes::new_legacy(s),
-        }
+        GraphemeIndices::from_source_legacy(s)
     }
 
     #[inline]
@@ -63,14 +85,13 @@ impl<'a> GraphemeIndices<'a> {
     }
 }
 
-impl<'a> Iterator for GraphemeIndices<'a> {
-    type Item = (usize, &'a str);
+impl<'a, T: CodeUnitSource + ?Sized> Iterator for GraphemeIndices<'a, T> {
+    type Item = (usize, &'a T);
 
     #[inline]
-    fn next(&mut self) -> Option<(usize, &'a str)> {
-        self.iter
-            .next()
-            .map(|s| (s.as_ptr() as usize - self.start_offset, s))
+    fn next(&mut self) -> Option<(usize, &'a T)> {
+        let start = self.iter.cursor.cur_cursor();
+        self.iter.next().map(|s| (start, s))
     }
 
     #[inline]
@@ -79,28 +100,46 @@ impl<'a> Iterator for GraphemeIndices<'a> {
     }
 }
 
-impl<'a> DoubleEndedIterator for GraphemeIndices<'a> {
+impl<'a, T: CodeUnitSource + ?Sized> DoubleEndedIterator for GraphemeIndices<'a, T> {
     #[inline]
-    fn next_back(&mut self) -> Option<(usize, &'a str)> {
-        self.iter
-            .next_back()
-            .map(|s| (s.as_ptr() as usize - self.start_offset, s))
+    fn next_back(&mut self) -> Option<(usize, &'a T)> {
+        let s = self.iter.next_back()?;
+        Some((self.iter.cursor_back.cur_cursor(), s))
     }
 }
 
 /// External iterator for a string's
 /// [grapheme clusters](https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries).
-#[derive(Clone, Debug)]
-pub struct Graphemes<'a> {
-    string: &'a str,
+///
+/// Also works on other `CodeUnitSource`s, such as UTF-16 code units:
+///
+/// ```rust
+/// # use unic_segment::Graphemes;
+/// let units: Vec<u16> = "a\u{1F1FA}\u{1F1F3}b".encode_utf16().collect();
+/// let lens: Vec<usize> = Graphemes::from_source(&units[..]).map(|g| g.len()).collect();
+/// assert_eq!(lens, &[1, 4, 1]);
+/// ```
+#[derive(Debug)]
+pub struct Graphemes<'a, T: ?Sized = str> {
+    string: &'a T,
     cursor: GraphemeCursor,
     cursor_back: GraphemeCursor,
 }
 
-impl<'a> Graphemes<'a> {
-    /// Create new iterator for *extended grapheme clusters*.
+impl<'a, T: ?Sized> Clone for Graphemes<'a, T> {
+    fn clone(&self) -> Self {
+        Graphemes {
+            string: self.string,
+            cursor: self.cursor.clone(),
+            cursor_back: self.cursor_back.clone(),
+        }
+    }
+}
+
+impl<'a, T: CodeUnitSource + ?Sized> Graphemes<'a, T> {
+    /// Create new iterator for *extended grapheme clusters* of any code unit source.
     #[inline]
-    pub fn new(s: &str) -> Graphemes<'_> {
+    pub fn from_source(s: &'a T) -> Graphemes<'a, T> {
         let len = s.len();
         Graphemes {
             string: s,
@@ -109,9 +148,9 @@ impl<'a> Graphemes<'a> {
         }
     }
 
-    /// Create new iterator for *legacy grapheme clusters*.
+    /// Create new iterator for *legacy grapheme clusters* of any code unit source.
     #[inline]
-    pub fn new_legacy(s: &str) -> Graphemes<'_> {
+    pub fn from_source_legacy(s: &'a T) -> Graphemes<'a, T> {
         let len = s.len();
         Graphemes {
             string: s,
@@ -120,6 +159,91 @@ impl<'a> Graphemes<'a> {
         }
     }
 
+    /// Find the last grapheme cluster boundary before `offset`, like `preceding()` of an ICU
+    /// `BreakIterator`.
+    ///
+    /// Offsets are into the whole text, whatever part of it has been iterated already. Only the
+    /// text around `offset` is looked at, so this is cheap anywhere in a long text. Returns `None`
+    /// if `offset` is past the end of the text, or not on a codepoint boundary.
+    ///
+    /// ```rust
+    /// # use unic_segment::Graphemes;
+    /// let s = "a\u{1F1F7}\u{1F1F8}b";
+    /// let graphemes = Graphemes::new(s);
+    /// assert_eq!(graphemes.preceding(9), Some(1));
+    /// assert_eq!(graphemes.preceding(1), Some(0));
+    /// assert_eq!(graphemes.preceding(0), None);
+    /// assert_eq!(graphemes.preceding(2), None);
+    /// ```
+    pub fn preceding(&self, offset: usize) -> Option<usize> {
+        let mut cursor = self.cursor_at(offset)?;
+        cursor.prev_boundary(self.string, 0).ok()?
+    }
+
+    /// Find the first grapheme cluster boundary after `offset`, like `following()` of an ICU
+    /// `BreakIterator`.
+    ///
+    /// Offsets are as for `preceding()`.
+    ///
+    /// ```rust
+    /// # use unic_segment::Graphemes;
+    /// let s = "a\u{1F1F7}\u{1F1F8}b";
+    /// let graphemes = Graphemes::new(s);
+    /// assert_eq!(graphemes.following(1), Some(9));
+    /// assert_eq!(graphemes.following(5), Some(9));
+    /// assert_eq!(graphemes.following(10), None);
+    /// ```
+    pub fn following(&self, offset: usize) -> Option<usize> {
+        let mut cursor = self.cursor_at(offset)?;
+        cursor.next_boundary(self.string, 0).ok()?
+    }
+
+    /// Whether there is a grapheme cluster boundary at `offset`.
+    ///
+    /// Offsets are as for `preceding()`; both ends of the text are boundaries, and offsets past
+    /// the end or not on a codepoint boundary are not.
+    ///
+    /// ```rust
+    /// # use unic_segment::Graphemes;
+    /// let s = "a\u{1F1F7}\u{1F1F8}b";
+    /// let graphemes = Graphemes::new(s);
+    /// assert!(graphemes.is_boundary(1));
+    /// assert!(!graphemes.is_boundary(5));
+    /// assert!(graphemes.is_boundary(10));
+    /// assert!(!graphemes.is_boundary(11));
+    /// ```
+    pub fn is_boundary(&self, offset: usize) -> bool {
+        match self.cursor_at(offset) {
+            Some(mut cursor) => cursor.is_boundary(self.string, 0) == Ok(true),
+            None => false,
+        }
+    }
+
+    // A fresh cursor at `offset` into the whole text, which is the single chunk the cursor is
+    // given.
+    fn cursor_at(&self, offset: usize) -> Option<GraphemeCursor> {
+        if !self.string.is_char_boundary(offset) {
+            return None;
+        }
+        let mut cursor = GraphemeCursor::new(offset, self.string.len());
+        cursor.is_extended = self.cursor.is_extended;
+        Some(cursor)
+    }
+}
+
+impl<'a> Graphemes<'a> {
+    /// Create new iterator for *extended grapheme clusters*.
+    #[inline]
+    pub fn new(s: &str) -> Graphemes<'_> {
+        Graphemes::from_source(s)
+    }
+
+    /// Create new iterator for *legacy grapheme clusters*.
+    #[inline]
+    pub fn new_legacy(s: &str) -> Graphemes<'_> {
+        Graphemes::from_source_legacy(s)
+    }
+
     #[inline]
     /// View the underlying data (the part yet to be iterated) as a slice of the original string.
     ///
@@ -138,8 +262,8 @@ impl<'a> Graphemes<'a> {
     }
 }
 
-impl<'a> Iterator for Graphemes<'a> {
-    type Item = &'a str;
+impl<'a, T: CodeUnitSource + ?Sized> Iterator for Graphemes<'a, T> {
+    type Item = &'a T;
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -148,19 +272,19 @@ impl<'a> Iterator for Graphemes<'a> {
     }
 
     #[inline]
-    fn next(&mut self) -> Option<&'a str> {
+    fn next(&mut self) -> Option<&'a T> {
         let start = self.cursor.cur_cursor();
         if start == self.cursor_back.cur_cursor() {
             return None;
         }
         let next = self.cursor.next_boundary(self.string, 0).unwrap().unwrap();
-        Some(&self.string[start..next])
+        Some(self.string.slice(start..next))
     }
 }
 
-impl<'a> DoubleEndedIterator for Graphemes<'a> {
+impl<'a, T: CodeUnitSource + ?Sized> DoubleEndedIterator for Graphemes<'a, T> {
     #[inline]
-    fn next_back(&mut self) -> Option<&'a str> {
+    fn next_back(&mut self) -> Option<&'a T> {
         let end = self.cursor_back.cur_cursor();
         if end == self.cursor.cur_cursor() {
             return None;
@@ -170,7 +294,7 @@ impl<'a> DoubleEndedIterator for Graphemes<'a> {
             .prev_boundary(self.string, 0)
             .unwrap()
             .unwrap();
-        Some(&self.string[prev..end])
+        Some(self.string.slice(prev..end))
     }
 }
 
@@ -449,11 +573,11 @@ impl GraphemeCursor {
     /// // That's enough to decide (it always is when context goes to the start of the string)
     /// assert_eq!(cursor.is_boundary(&flags[8..], 8), Ok(true));
     /// ```
-    pub fn provide_context(&mut self, chunk: &str, chunk_start: usize) {
+    pub fn provide_context<T: CodeUnitSource + ?Sized>(&mut self, chunk: &T, chunk_start: usize) {
         assert!(chunk_start + chunk.len() == self.pre_context_offset.unwrap());
         self.pre_context_offset = None;
         if self.is_extended && chunk_start + chunk.len() == self.offset {
-            let ch = chunk.chars().rev().next().unwrap();
+            let (ch, _) = chunk.char_before(chunk.len()).unwrap();
             if GCB::of(ch) == GCB::Prepend {
                 self.decide(false); // GB9b
                 return;
@@ -491,9 +615,9 @@ impl GraphemeCursor {
         }
     }
 
-    fn handle_regional(&mut self, chunk: &str, chunk_start: usize) {
+    fn handle_regional<T: CodeUnitSource + ?Sized>(&mut self, chunk: &T, chunk_start: usize) {
         let mut ris_count = self.ris_count.unwrap_or(0);
-        for ch in chunk.chars().rev() {
+        for (_, ch, _) in CharIndices::new(chunk, 0..chunk.len()).rev() {
             if GCB::of(ch) != GCB::RegionalIndicator {
                 self.ris_count = Some(ris_count);
                 self.decide((ris_count % 2) == 0);
@@ -509,8 +633,8 @@ impl GraphemeCursor {
         self.pre_context_offset = Some(chunk_start);
     }
 
-    fn handle_emoji(&mut self, chunk: &str, chunk_start: usize) {
-        for ch in chunk.chars().rev() {
+    fn handle_emoji<T: CodeUnitSource + ?Sized>(&mut self, chunk: &T, chunk_start: usize) {
+        for (_, ch, _) in CharIndices::new(chunk, 0..chunk.len()).rev() {
             match GCB::of(ch) {
                 GCB::Extend => (),
                 GCB::EBase | GCB::EBaseGAZ => {
@@ -557,9 +681,9 @@ impl GraphemeCursor {
     /// cursor.set_cursor(12);
     /// assert_eq!(cursor.is_boundary(flags, 0), Ok(false));
     /// ```
-    pub fn is_boundary(
+    pub fn is_boundary<T: CodeUnitSource + ?Sized>(
         &mut self,
-        chunk: &str,
+        chunk: &T,
         chunk_start: usize,
     ) -> Result<bool, GraphemeIncomplete> {
         if self.state == GraphemeState::Break {
@@ -578,7 +702,7 @@ impl GraphemeCursor {
         }
         let offset_in_chunk = self.offset - chunk_start;
         if self.cat_after.is_none() {
-            let ch = chunk[offset_in_chunk..].chars().next().unwrap();
+            let (ch, _) = chunk.char_at(offset_in_chunk).unwrap();
             self.cat_after = Some(GCB::of(ch));
         }
         if self.offset == chunk_start {
@@ -594,7 +718,7 @@ impl GraphemeCursor {
             }
         }
         if self.cat_before.is_none() {
-            let ch = chunk[..offset_in_chunk].chars().rev().next().unwrap();
+            let (ch, _) = chunk.char_before(offset_in_chunk).unwrap();
             self.cat_before = Some(GCB::of(ch));
         }
         match check_pair(self.cat_before.unwrap(), self.cat_after.unwrap()) {
@@ -608,11 +732,11 @@ impl GraphemeCursor {
                 if let Some(ris_count) = self.ris_count {
                     return self.decision((ris_count % 2) == 0);
                 }
-                self.handle_regional(&chunk[..offset_in_chunk], chunk_start);
+                self.handle_regional(chunk.slice(0..offset_in_chunk), chunk_start);
                 self.is_boundary_result()
             }
             PairResult::Emoji => {
-                self.handle_emoji(&chunk[..offset_in_chunk], chunk_start);
+                self.handle_emoji(chunk.slice(0..offset_in_chunk), chunk_start);
                 self.is_boundary_result()
             }
         }
@@ -650,23 +774,23 @@ impl GraphemeCursor {
     /// assert_eq!(cursor.next_boundary(&s[2..4], 2), Ok(Some(4)));
     /// assert_eq!(cursor.next_boundary(&s[2..4], 2), Ok(None));
     /// ```
-    pub fn next_boundary(
+    pub fn next_boundary<T: CodeUnitSource + ?Sized>(
         &mut self,
-        chunk: &str,
+        chunk: &T,
         chunk_start: usize,
     ) -> Result<Option<usize>, GraphemeIncomplete> {
         if self.offset == self.len {
             return Ok(None);
         }
-        let mut iter = chunk[self.offset - chunk_start..].chars();
-        let mut ch = iter.next().unwrap();
+        let mut iter = CharIndices::new(chunk, self.offset - chunk_start..chunk.len());
+        let (_, mut ch, mut ch_len) = iter.next().unwrap();
         loop {
             if self.resuming {
                 if self.cat_after.is_none() {
                     self.cat_after = Some(GCB::of(ch));
                 }
             } else {
-                self.offset += ch.len_utf8();
+                self.offset += ch_len;
                 self.state = GraphemeState::Unknown;
                 self.cat_before = self.cat_after.take();
                 if self.cat_before.is_none() {
@@ -677,8 +801,9 @@ impl GraphemeCursor {
                 } else {
                     self.ris_count = Some(0);
                 }
-                if let Some(next_ch) = iter.next() {
+                if let Some((_, next_ch, next_len)) = iter.next() {
                     ch = next_ch;
+                    ch_len = next_len;
                     self.cat_after = Some(GCB::of(ch));
                 } else if self.offset == self.len {
                     self.decide(true);
@@ -729,16 +854,16 @@ impl GraphemeCursor {
     /// assert_eq!(cursor.prev_boundary(&s[0..2], 0), Ok(Some(0)));
     /// assert_eq!(cursor.prev_boundary(&s[0..2], 0), Ok(None));
     /// ```
-    pub fn prev_boundary(
+    pub fn prev_boundary<T: CodeUnitSource + ?Sized>(
         &mut self,
-        chunk: &str,
+        chunk: &T,
         chunk_start: usize,
     ) -> Result<Option<usize>, GraphemeIncomplete> {
         if self.offset == 0 {
             return Ok(None);
         }
-        let mut iter = chunk[..self.offset - chunk_start].chars().rev();
-        let mut ch = iter.next().unwrap();
+        let mut iter = CharIndices::new(chunk, 0..self.offset - chunk_start).rev();
+        let (_, mut ch, mut ch_len) = iter.next().unwrap();
         loop {
             if self.offset == chunk_start {
                 self.resuming = true;
@@ -747,7 +872,7 @@ impl GraphemeCursor {
             if self.resuming {
                 self.cat_before = Some(GCB::of(ch));
             } else {
-                self.offset -= ch.len_utf8();
+                self.offset -= ch_len;
                 self.cat_after = self.cat_before.take();
                 self.state = GraphemeState::Unknown;
                 if let Some(ris_count) = self.ris_count {
@@ -757,8 +882,9 @@ impl GraphemeCursor {
                         None
                     };
                 }
-                if let Some(prev_ch) = iter.next() {
+                if let Some((_, prev_ch, prev_len)) = iter.next() {
                     ch = prev_ch;
+                    ch_len = prev_len;
                     self.cat_before = Some(GCB::of(ch));
                 } else if self.offset == 0 {
                     self.decide(true);
@@ -820,4 +946,77 @@ mod tests {
         let graphemes = Graphemes::new(input).rev().collect::<Vec<&str>>();
         assert_eq!(graphemes, &["\r", "\r\n", "\n"]);
     }
+
+    #[test]
+    fn test_graphemes_random_access() {
+        let input = "a🇺🇳\r\nb";
+        let mut graphemes = Graphemes::new(input);
+        assert_eq!(graphemes.following(0), Some(1));
+        assert_eq!(graphemes.following(5), Some(9));
+        assert_eq!(graphemes.preceding(10), Some(9));
+        assert!(!graphemes.is_boundary(10));
+
+        // Offsets are into the whole text, however much of it was iterated.
+        graphemes.next();
+        graphemes.next_back();
+        assert_eq!(graphemes.as_str(), "🇺🇳\r\n");
+        assert_eq!(graphemes.following(0), Some(1));
+        assert_eq!(graphemes.preceding(10), Some(9));
+        assert!(!graphemes.is_boundary(10));
+        assert_eq!(graphemes.following(11), Some(12));
+        assert_eq!(graphemes.following(12), None);
+
+        // Offsets past the end, or inside a codepoint
+        assert_eq!(graphemes.following(13), None);
+        assert_eq!(graphemes.preceding(13), None);
+        assert!(!graphemes.is_boundary(13));
+        assert_eq!(graphemes.following(2), None);
+        assert_eq!(graphemes.preceding(2), None);
+        assert!(!graphemes.is_boundary(2));
+    }
+
+    #[test]
+    fn test_graphemes_utf16() {
+        let input: Vec<u16> = "a̐é🇺🇳\r\n".encode_utf16().collect();
+        let graphemes = GraphemeIndices::from_source(&input[..])
+            .map(|(i, g)| (i, String::from_utf16(g).unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            graphemes,
+            &[
+                (0, "a̐".to_owned()),
+                (2, "é".to_owned()),
+                (3, "🇺🇳".to_owned()),
+                (7, "\r\n".to_owned()),
+            ]
+        );
+
+        // An unpaired surrogate is its own grapheme, and does not swallow a following mark.
+        let input = [0x61, 0xD800, 0x301, 0x62];
+        assert_eq!(
+            Graphemes::from_source(&input[..]).collect::<Vec<&[u16]>>(),
+            &[&[0x61][..], &[0xD800, 0x301], &[0x62]]
+        );
+        assert_eq!(
+            Graphemes::from_source(&input[..])
+                .rev()
+                .collect::<Vec<&[u16]>>(),
+            &[&[0x62][..], &[0xD800, 0x301], &[0x61]]
+        );
+    }
+
+    #[test]
+    fn test_graphemes_bytes() {
+        let input: &[u8] = b"e\xCC\x81\xF0\x9F\r\n";
+        assert_eq!(
+            GraphemeIndices::from_source(input).collect::<Vec<(usize, &[u8])>>(),
+            &[(0, &b"e\xCC\x81"[..]), (3, b"\xF0\x9F"), (5, b"\r\n")]
+        );
+        assert_eq!(
+            GraphemeIndices::from_source(input)
+                .rev()
+                .collect::<Vec<(usize, &[u8])>>(),
+            &[(5, &b"\r\n"[..]), (3, b"\xF0\x9F"), (0, b"e\xCC\x81")]
+        );
+    }
 }
@@ -0,0 +1,279 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! String utilities counting user-perceived characters (grapheme clusters) rather than bytes or
+//! codepoints, so that flags, emoji sequences and combining marks are never split.
+
+use std::borrow::Cow;
+use std::ops::{Bound, RangeBounds};
+
+use crate::grapheme::{GraphemeIndices, Graphemes};
+
+/// Methods on strings that operate on grapheme clusters.
+///
+/// All methods use *extended grapheme clusters*; the `_legacy` variants use *legacy grapheme
+/// clusters* instead.
+///
+/// ```rust
+/// # use unic_segment::GraphemeStrExt;
+/// let name = "🇺🇳 e\u{301}mile 👨‍👩‍👧";
+/// assert_eq!(name.grapheme_count(), 9);
+/// assert_eq!(name.grapheme_truncate(3), "🇺🇳 e\u{301}");
+/// assert_eq!(name.grapheme_ellipsize(6, "…"), "🇺🇳 e\u{301}mi…");
+/// assert_eq!(name.nth_grapheme(8), Some("👨‍👩‍👧"));
+/// ```
+pub trait GraphemeStrExt {
+    /// Number of extended grapheme clusters in the string.
+    fn grapheme_count(&self) -> usize;
+
+    /// Number of legacy grapheme clusters in the string.
+    fn grapheme_count_legacy(&self) -> usize;
+
+    /// The longest prefix of the string with at most `n` extended grapheme clusters.
+    fn grapheme_truncate(&self, n: usize) -> &str;
+
+    /// The longest prefix of the string with at most `n` legacy grapheme clusters.
+    fn grapheme_truncate_legacy(&self, n: usize) -> &str;
+
+    /// The string itself if it has at most `n` extended grapheme clusters, otherwise the string
+    /// truncated so that it has `n` extended grapheme clusters once `ellipsis` is appended.
+    ///
+    /// If `ellipsis` itself is longer than `n`, only `ellipsis` is returned.
+    fn grapheme_ellipsize(&self, n: usize, ellipsis: &str) -> Cow<'_, str>;
+
+    /// Same as `grapheme_ellipsize`, counting legacy grapheme clusters.
+    fn grapheme_ellipsize_legacy(&self, n: usize, ellipsis: &str) -> Cow<'_, str>;
+
+    /// The string with the order of its extended grapheme clusters reversed.
+    fn grapheme_reverse(&self) -> String;
+
+    /// The string with the order of its legacy grapheme clusters reversed.
+    fn grapheme_reverse_legacy(&self) -> String;
+
+    /// The `n`-th extended grapheme cluster of the string, counting from zero.
+    fn nth_grapheme(&self, n: usize) -> Option<&str>;
+
+    /// The `n`-th legacy grapheme cluster of the string, counting from zero.
+    fn nth_grapheme_legacy(&self, n: usize) -> Option<&str>;
+
+    /// The substring covering a range of extended grapheme clusters, or `None` if the range is
+    /// out of bounds.
+    fn grapheme_slice<R: RangeBounds<usize>>(&self, range: R) -> Option<&str>;
+
+    /// The substring covering a range of legacy grapheme clusters, or `None` if the range is out
+    /// of bounds.
+    fn grapheme_slice_legacy<R: RangeBounds<usize>>(&self, range: R) -> Option<&str>;
+}
+
+fn graphemes(s: &str, is_extended: bool) -> Graphemes<'_> {
+    if is_extended {
+        Graphemes::new(s)
+    } else {
+        Graphemes::new_legacy(s)
+    }
+}
+
+fn grapheme_indices(s: &str, is_extended: bool) -> GraphemeIndices<'_> {
+    if is_extended {
+        GraphemeIndices::new(s)
+    } else {
+        GraphemeIndices::new_legacy(s)
+    }
+}
+
+// Byte offset of the boundary before the `n`-th grapheme cluster, if there are at least `n`.
+fn offset_of(s: &str, n: usize, is_extended: bool) -> Option<usize> {
+    if n == 0 {
+        return Some(0);
+    }
+    grapheme_indices(s, is_extended)
+        .nth(n - 1)
+        .map(|(offset, grapheme)| offset + grapheme.len())
+}
+
+fn truncate(s: &str, n: usize, is_extended: bool) -> &str {
+    &s[..offset_of(s, n, is_extended).unwrap_or(s.len())]
+}
+
+fn ellipsize<'a>(s: &'a str, n: usize, ellipsis: &str, is_extended: bool) -> Cow<'a, str> {
+    if graphemes(s, is_extended).nth(n).is_none() {
+        return Cow::Borrowed(s);
+    }
+    let ellipsis_count = graphemes(ellipsis, is_extended).count();
+    let mut ellipsized = truncate(s, n.saturating_sub(ellipsis_count), is_extended).to_owned();
+    ellipsized.push_str(ellipsis);
+    Cow::Owned(ellipsized)
+}
+
+fn reverse(s: &str, is_extended: bool) -> String {
+    graphemes(s, is_extended).rev().collect()
+}
+
+fn slice<R: RangeBounds<usize>>(s: &str, range: R, is_extended: bool) -> Option<&str> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let start_offset = offset_of(s, start, is_extended)?;
+    let rest = &s[start_offset..];
+    let end_offset = match range.end_bound() {
+        Bound::Included(&end) if end >= start => {
+            offset_of(rest, (end - start).checked_add(1)?, is_extended)?
+        }
+        Bound::Excluded(&end) if end >= start => offset_of(rest, end - start, is_extended)?,
+        Bound::Unbounded => rest.len(),
+        _ => return None,
+    };
+    Some(&rest[..end_offset])
+}
+
+impl GraphemeStrExt for str {
+    #[inline]
+    fn grapheme_count(&self) -> usize {
+        graphemes(self, true).count()
+    }
+
+    #[inline]
+    fn grapheme_count_legacy(&self) -> usize {
+        graphemes(self, false).count()
+    }
+
+    #[inline]
+    fn grapheme_truncate(&self, n: usize) -> &str {
+        truncate(self, n, true)
+    }
+
+    #[inline]
+    fn grapheme_truncate_legacy(&self, n: usize) -> &str {
+        truncate(self, n, false)
+    }
+
+    #[inline]
+    fn grapheme_ellipsize(&self, n: usize, ellipsis: &str) -> Cow<'_, str> {
+        ellipsize(self, n, ellipsis, true)
+    }
+
+    #[inline]
+    fn grapheme_ellipsize_legacy(&self, n: usize, ellipsis: &str) -> Cow<'_, str> {
+        ellipsize(self, n, ellipsis, false)
+    }
+
+    #[inline]
+    fn grapheme_reverse(&self) -> String {
+        reverse(self, true)
+    }
+
+    #[inline]
+    fn grapheme_reverse_legacy(&self) -> String {
+        reverse(self, false)
+    }
+
+    #[inline]
+    fn nth_grapheme(&self, n: usize) -> Option<&str> {
+        graphemes(self, true).nth(n)
+    }
+
+    #[inline]
+    fn nth_grapheme_legacy(&self, n: usize) -> Option<&str> {
+        graphemes(self, false).nth(n)
+    }
+
+    #[inline]
+    fn grapheme_slice<R: RangeBounds<usize>>(&self, range: R) -> Option<&str> {
+        slice(self, range, true)
+    }
+
+    #[inline]
+    fn grapheme_slice_legacy<R: RangeBounds<usize>>(&self, range: R) -> Option<&str> {
+        slice(self, range, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Bound;
+
+    use super::GraphemeStrExt;
+
+    const FLAGS: &str = "\u{1F1F7}\u{1F1F8}\u{1F1EE}\u{1F1F4}";
+    const FAMILY: &str = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    const HINDI: &str = "हिन्दी";
+
+    #[test]
+    fn test_count() {
+        assert_eq!("".grapheme_count(), 0);
+        assert_eq!(FLAGS.grapheme_count(), 2);
+        assert_eq!(FAMILY.grapheme_count(), 1);
+        assert_eq!(HINDI.grapheme_count(), 3);
+        assert_eq!(HINDI.grapheme_count_legacy(), 5);
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(FLAGS.grapheme_truncate(1), "\u{1F1F7}\u{1F1F8}");
+        assert_eq!(FLAGS.grapheme_truncate(5), FLAGS);
+        assert_eq!(FAMILY.grapheme_truncate(0), "");
+        assert_eq!(HINDI.grapheme_truncate(1), "हि");
+        assert_eq!(HINDI.grapheme_truncate_legacy(1), "ह");
+    }
+
+    #[test]
+    fn test_ellipsize() {
+        assert_eq!("short".grapheme_ellipsize(5, "..."), "short");
+        assert_eq!("longer".grapheme_ellipsize(5, "..."), "lo...");
+        assert_eq!(
+            [FAMILY, FLAGS, FAMILY].concat().grapheme_ellipsize(3, "…"),
+            [FAMILY, "\u{1F1F7}\u{1F1F8}", "…"].concat()
+        );
+        assert_eq!("longer".grapheme_ellipsize(2, "..."), "...");
+    }
+
+    #[test]
+    fn test_reverse() {
+        assert_eq!(
+            FLAGS.grapheme_reverse(),
+            "\u{1F1EE}\u{1F1F4}\u{1F1F7}\u{1F1F8}"
+        );
+        assert_eq!("e\u{301}a".grapheme_reverse(), "ae\u{301}");
+        assert_eq!("ab\r\n".grapheme_reverse(), "\r\nba");
+    }
+
+    #[test]
+    fn test_nth_and_slice() {
+        assert_eq!(FLAGS.nth_grapheme(1), Some("\u{1F1EE}\u{1F1F4}"));
+        assert_eq!(FLAGS.nth_grapheme(2), None);
+        assert_eq!(HINDI.nth_grapheme_legacy(1), Some("ि"));
+
+        let s = ["a", FAMILY, "b", FLAGS].concat();
+        assert_eq!(
+            s.grapheme_slice(1..3),
+            Some([FAMILY, "b"].concat().as_str())
+        );
+        assert_eq!(
+            s.grapheme_slice(..=1),
+            Some(["a", FAMILY].concat().as_str())
+        );
+        assert_eq!(s.grapheme_slice(4..), Some("\u{1F1EE}\u{1F1F4}"));
+        assert_eq!(s.grapheme_slice(5..), Some(""));
+        assert_eq!(s.grapheme_slice(6..), None);
+        assert_eq!(s.grapheme_slice(2..9), None);
+        assert_eq!(
+            s.grapheme_slice((Bound::Included(3), Bound::Excluded(2))),
+            None
+        );
+        assert_eq!(
+            s.grapheme_slice((Bound::Excluded(usize::MAX), Bound::Unbounded)),
+            None
+        );
+        assert_eq!(s.grapheme_slice(..=usize::MAX), None);
+        assert_eq!(s.grapheme_slice(0..=usize::MAX), None);
+    }
+}
@@ -26,6 +26,10 @@
 //! boundaries of text element boundaries, such as user-perceived characters (a.k.a.
 //! *Grapheme Clusters)*, *Words*, and *Sentences* (last one not implemented yet).
 //!
+//! It also implements the line breaking algorithm from [Unicode® Standard Annex #14 - Unicode
+//! Line Breaking Algorithm](http://unicode.org/reports/tr14/), used for finding where lines of
+//! text can be broken, and wrapping text to a maximum display width.
+//!
 //! # Examples
 //!
 //! ```rust
@@ -78,6 +82,7 @@
 //! );
 //! ```
 
+pub use unic_common::source::{CharIndices, CodeUnitSource};
 pub use unic_ucd_segment::UNICODE_VERSION;
 
 mod pkg_info;
@@ -86,5 +91,25 @@ pub use crate::pkg_info::{PKG_DESCRIPTION, PKG_NAME, PKG_VERSION};
 mod grapheme;
 pub use crate::grapheme::{GraphemeCursor, GraphemeIncomplete, GraphemeIndices, Graphemes};
 
+mod grapheme_str;
+pub use crate::grapheme_str::GraphemeStrExt;
+
+mod line;
+pub use crate::line::{BreakOpportunity, LineBreaks};
+
+mod tailoring;
+pub use crate::tailoring::{DictionarySegmenter, TailoredWordBounds, WordListSegmenter};
+
 mod word;
-pub use crate::word::{WordBoundIndices, WordBounds, Words};
+pub use crate::word::{
+    WordBoundIndices,
+    WordBoundKinds,
+    WordBounds,
+    WordCursor,
+    WordIncomplete,
+    WordKind,
+    Words,
+};
+
+mod wrap;
+pub use crate::wrap::{display_width, WrappedLineIndices, WrappedLines};
@@ -0,0 +1,439 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Line break opportunities of a string.
+//!
+//! ## References
+//!
+//! * <https://www.unicode.org/reports/tr14/#Algorithm>
+
+use unic_ucd_category::GeneralCategory as GC;
+use unic_ucd_segment::line_break::abbr_names::*;
+use unic_ucd_segment::LineBreak;
+
+/// The kind of a line break opportunity.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BreakOpportunity {
+    /// The line must break here, such as after a newline or at the end of the text.
+    Mandatory,
+
+    /// The line may break here, such as after a space.
+    Allowed,
+}
+
+/// External iterator for the [line break
+/// opportunities](https://www.unicode.org/reports/tr14/#Algorithm) of a string.
+///
+/// Yields the byte offset where the next line would start, and whether the break is mandatory.
+/// The end of the text is always yielded, as a mandatory break.
+///
+/// ```rust
+/// # use unic_segment::{BreakOpportunity, LineBreaks};
+/// let breaks: Vec<(usize, BreakOpportunity)> = LineBreaks::new("Hi there.\nBye").collect();
+/// assert_eq!(
+///     breaks,
+///     &[
+///         (3, BreakOpportunity::Allowed),
+///         (10, BreakOpportunity::Mandatory),
+///         (13, BreakOpportunity::Mandatory),
+///     ]
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct LineBreaks<'a> {
+    string: &'a str,
+
+    /// Offset of the next codepoint to look at.
+    offset: usize,
+
+    /// The class of the last item, with Combining_Mark and ZWJ folded into the character before
+    /// them (LB9), or `None` at the start of the text.
+    prev: Option<LineBreak>,
+
+    /// The class of the item before `prev`, or `None` if `prev` is the first one.
+    prev_prev: Option<LineBreak>,
+
+    /// If `prev` is a space, the class of the last item before the spaces.
+    before_spaces: Option<LineBreak>,
+
+    /// Set if the last codepoint was a ZWJ (LB8a).
+    after_zwj: bool,
+
+    /// Set if `prev` ends an odd run of regional indicators (LB30a).
+    odd_regional_indicators: bool,
+
+    /// How `prev` ends a number (LB25).
+    number: NumberState,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum NumberState {
+    None,
+    // NU (SY | IS)*
+    Number,
+    // NU (SY | IS)* (CL | CP)
+    Closed,
+}
+
+/// `Line_Break` with the classes that the pair rules do not use resolved (LB1).
+fn line_break(ch: char) -> LineBreak {
+    match LineBreak::of(ch) {
+        AI | SG | XX => AL,
+        SA => match GC::of(ch) {
+            GC::NonspacingMark | GC::SpacingMark => CM,
+            _ => AL,
+        },
+        CJ => NS,
+        cls => cls,
+    }
+}
+
+#[inline]
+fn is_hard_break(cls: LineBreak) -> bool {
+    matches!(cls, BK | CR | LF | NL)
+}
+
+impl<'a> LineBreaks<'a> {
+    /// Create new iterator for *line break opportunities*.
+    #[inline]
+    pub fn new(s: &str) -> LineBreaks<'_> {
+        LineBreaks {
+            string: s,
+            offset: 0,
+            prev: None,
+            prev_prev: None,
+            before_spaces: None,
+            after_zwj: false,
+            odd_regional_indicators: false,
+            number: NumberState::None,
+        }
+    }
+
+    // The class of the item starting at `offset`, with the marks folded into it, and the offset
+    // after it.
+    fn item_at(&self, offset: usize) -> Option<(LineBreak, usize)> {
+        let ch = self.string[offset..].chars().next()?;
+        self.fold_marks(line_break(ch), offset + ch.len_utf8())
+    }
+
+    // The item following one of class `cls`, which ends at `end` or before marks folded into it.
+    fn item_after(&self, cls: LineBreak, end: usize) -> Option<(LineBreak, usize)> {
+        let (_, end) = self.fold_marks(cls, end)?;
+        self.item_at(end)
+    }
+
+    // Resolve the marks following an item of class `cls`, which end at `end`, as either part of it
+    // (LB9) or the item itself (LB10).
+    fn fold_marks(&self, mut cls: LineBreak, mut end: usize) -> Option<(LineBreak, usize)> {
+        match cls {
+            BK | CR | LF | NL | SP | ZW => {}
+            _ => {
+                if cls == CM || cls == ZWJ {
+                    cls = AL;
+                }
+                for next in self.string[end..].chars() {
+                    match line_break(next) {
+                        CM | ZWJ => end += next.len_utf8(),
+                        _ => break,
+                    }
+                }
+            }
+        }
+        Some((cls, end))
+    }
+
+    // Whether there is a break between the items of classes `before` and `after_cls`, the latter
+    // starting at the codepoint before `end`.
+    fn check_pair(
+        &self,
+        before: LineBreak,
+        after_cls: LineBreak,
+        end: usize,
+    ) -> Option<BreakOpportunity> {
+        use self::BreakOpportunity::*;
+
+        // The last item before any spaces
+        let base_cls = if before == SP {
+            self.before_spaces
+        } else {
+            Some(before)
+        };
+        let next = || self.item_after(after_cls, end);
+
+        match (before, after_cls) {
+            // LB4, LB5
+            (CR, LF) => return None,
+            (BK, _) | (CR, _) | (LF, _) | (NL, _) => return Some(Mandatory),
+            // LB6
+            (_, BK) | (_, CR) | (_, LF) | (_, NL) => return None,
+            // LB7
+            (_, SP) | (_, ZW) => return None,
+            _ => {}
+        }
+        // LB8
+        if base_cls == Some(ZW) {
+            return Some(Allowed);
+        }
+        // LB8a
+        if self.after_zwj && (after_cls == ID || after_cls == EB || after_cls == EM) {
+            return None;
+        }
+        match (before, after_cls) {
+            // LB11
+            (WJ, _) | (_, WJ) => return None,
+            // LB12
+            (GL, _) => return None,
+            // LB12a
+            (SP, GL) | (BA, GL) | (HY, GL) => {}
+            (_, GL) => return None,
+            // LB13
+            (_, CL) | (_, CP) | (_, EX) | (_, IS) | (_, SY) => return None,
+            _ => {}
+        }
+        // LB14
+        if base_cls == Some(OP) {
+            return None;
+        }
+        // LB15
+        if base_cls == Some(QU) && after_cls == OP {
+            return None;
+        }
+        // LB16
+        if (base_cls == Some(CL) || base_cls == Some(CP)) && after_cls == NS {
+            return None;
+        }
+        // LB17
+        if base_cls == Some(B2) && after_cls == B2 {
+            return None;
+        }
+        // LB18
+        if before == SP {
+            return Some(Allowed);
+        }
+        // LB19
+        if before == QU || after_cls == QU {
+            return None;
+        }
+        // LB20
+        if before == CB || after_cls == CB {
+            return Some(Allowed);
+        }
+        // LB21
+        match (before, after_cls) {
+            (_, BA) | (_, HY) | (_, NS) | (BB, _) => return None,
+            _ => {}
+        }
+        // LB21a
+        if self.prev_prev == Some(HL) && (before == HY || before == BA) {
+            return None;
+        }
+        // LB25, the parts that need more than the pair
+        match self.number {
+            NumberState::Number if after_cls == NU => return None,
+            NumberState::Number | NumberState::Closed if after_cls == PO || after_cls == PR => {
+                return None;
+            }
+            _ => {}
+        }
+        if (before == PO || before == PR) && after_cls == OP {
+            if let Some((item, end)) = next() {
+                if item == NU {
+                    return None;
+                }
+                if item == IS && self.item_at(end).map(|(item, _)| item) == Some(NU) {
+                    return None;
+                }
+            }
+        }
+        // LB30b
+        if before == EB && after_cls == EM {
+            return None;
+        }
+        match (before, after_cls) {
+            // LB21b
+            (SY, HL) => None,
+            // LB22
+            (AL, IN) | (HL, IN) | (EX, IN) | (ID, IN) | (EB, IN) | (EM, IN) => None,
+            (IN, IN) | (NU, IN) => None,
+            // LB23
+            (AL, NU) | (HL, NU) | (NU, AL) | (NU, HL) => None,
+            // LB23a
+            (PR, ID) | (PR, EB) | (PR, EM) | (ID, PO) | (EB, PO) | (EM, PO) => None,
+            // LB24
+            (PR, AL) | (PR, HL) | (PO, AL) | (PO, HL) => None,
+            (AL, PR) | (AL, PO) | (HL, PR) | (HL, PO) => None,
+            // LB25
+            (PO, NU) | (PR, NU) | (HY, NU) | (IS, NU) => None,
+            // LB26
+            (JL, JL) | (JL, JV) | (JL, H2) | (JL, H3) => None,
+            (JV, JV) | (JV, JT) | (H2, JV) | (H2, JT) => None,
+            (JT, JT) | (H3, JT) => None,
+            // LB27
+            (JL, IN) | (JV, IN) | (JT, IN) | (H2, IN) | (H3, IN) => None,
+            (JL, PO) | (JV, PO) | (JT, PO) | (H2, PO) | (H3, PO) => None,
+            (PR, JL) | (PR, JV) | (PR, JT) | (PR, H2) | (PR, H3) => None,
+            // LB28
+            (AL, AL) | (AL, HL) | (HL, AL) | (HL, HL) => None,
+            // LB29
+            (IS, AL) | (IS, HL) => None,
+            // LB30
+            (AL, OP) | (HL, OP) | (NU, OP) => None,
+            (CP, AL) | (CP, HL) | (CP, NU) => None,
+            // LB30a
+            (RI, RI) if self.odd_regional_indicators => None,
+            // LB31
+            _ => Some(Allowed),
+        }
+    }
+
+    fn push(&mut self, cls: LineBreak) {
+        if cls == SP && self.prev != Some(SP) {
+            self.before_spaces = self.prev;
+        }
+        self.odd_regional_indicators =
+            cls == RI && !(self.prev == Some(RI) && self.odd_regional_indicators);
+        self.number = match (self.number, cls) {
+            (_, NU) => NumberState::Number,
+            (NumberState::Number, SY) | (NumberState::Number, IS) => NumberState::Number,
+            (NumberState::Number, CL) | (NumberState::Number, CP) => NumberState::Closed,
+            _ => NumberState::None,
+        };
+        self.prev_prev = self.prev;
+        self.prev = Some(cls);
+    }
+}
+
+impl<'a> Iterator for LineBreaks<'a> {
+    type Item = (usize, BreakOpportunity);
+
+    fn next(&mut self) -> Option<(usize, BreakOpportunity)> {
+        while let Some(ch) = self.string[self.offset..].chars().next() {
+            let offset = self.offset;
+            let end = offset + ch.len_utf8();
+            self.offset = end;
+
+            let cls = line_break(ch);
+            let is_zwj = cls == ZWJ;
+            let prev = match self.prev {
+                // LB2
+                None => {
+                    let cls = if cls == CM || cls == ZWJ { AL } else { cls };
+                    self.push(cls);
+                    self.after_zwj = is_zwj;
+                    continue;
+                }
+                Some(prev) => prev,
+            };
+
+            // LB9
+            if (cls == CM || cls == ZWJ) && !is_hard_break(prev) && prev != SP && prev != ZW {
+                self.after_zwj = is_zwj;
+                continue;
+            }
+
+            // LB10
+            let cls = if cls == CM || cls == ZWJ { AL } else { cls };
+            let opportunity = self.check_pair(prev, cls, end);
+            self.push(cls);
+            self.after_zwj = is_zwj;
+            if let Some(opportunity) = opportunity {
+                return Some((offset, opportunity));
+            }
+        }
+        if self.prev.take().is_some() {
+            // LB3
+            return Some((self.string.len(), BreakOpportunity::Mandatory));
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BreakOpportunity, LineBreaks};
+
+    // The text split at the break opportunities, with mandatory breaks marked by a trailing `!`.
+    fn split(s: &str) -> Vec<String> {
+        let mut start = 0;
+        LineBreaks::new(s)
+            .map(|(end, opportunity)| {
+                let mut line = s[start..end].to_owned();
+                if opportunity == BreakOpportunity::Mandatory {
+                    line.push('!');
+                }
+                start = end;
+                line
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(LineBreaks::new("").count(), 0);
+    }
+
+    #[test]
+    fn test_spaces_and_newlines() {
+        assert_eq!(split("a  b"), &["a  ", "b!"]);
+        assert_eq!(split("a\r\nb\n"), &["a\r\n!", "b\n!"]);
+        assert_eq!(split("a\u{2028}b"), &["a\u{2028}!", "b!"]);
+        assert_eq!(split("a\u{200B}b"), &["a\u{200B}", "b!"]);
+        assert_eq!(split("a\u{A0}b c"), &["a\u{A0}b ", "c!"]);
+        assert_eq!(split(" \u{301}a"), &[" ", "\u{301}a!"]);
+    }
+
+    #[test]
+    fn test_punctuation() {
+        assert_eq!(split("well-known"), &["well-", "known!"]);
+        assert_eq!(split("(a) b!"), &["(a) ", "b!!"]);
+        assert_eq!(split("\u{201C}a\u{201D} b"), &["\u{201C}a\u{201D} ", "b!"]);
+        assert_eq!(
+            split("a \u{2014}\u{2014} b"),
+            &["a ", "\u{2014}\u{2014} ", "b!"]
+        );
+        assert_eq!(split("-1"), &["-1!"]);
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(split("$(12.5)% off"), &["$(12.5)% ", "off!"]);
+        assert_eq!(split("1,000,000 km"), &["1,000,000 ", "km!"]);
+        assert_eq!(split("x/y"), &["x/", "y!"]);
+    }
+
+    #[test]
+    fn test_east_asian() {
+        assert_eq!(split("日本語"), &["日", "本", "語!"]);
+        assert_eq!(split("日本。語"), &["日", "本。", "語!"]);
+        assert_eq!(split("「日本」"), &["「日", "本」!"]);
+        assert_eq!(split("한국어"), &["한", "국", "어!"]);
+    }
+
+    #[test]
+    fn test_emoji() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        assert_eq!(split(family), &[format!("{}!", family)]);
+        assert_eq!(
+            split("\u{1F44D}\u{1F3FD}\u{1F44D}"),
+            &["\u{1F44D}\u{1F3FD}", "\u{1F44D}!"]
+        );
+        // Not an emoji base (LB30b)
+        assert_eq!(split("\u{1FAE8}\u{1F3FB}"), &["\u{1FAE8}", "\u{1F3FB}!"]);
+        assert_eq!(
+            split("\u{1F1F7}\u{1F1F8}\u{1F1EE}\u{1F1F4}\u{1F1EE}"),
+            &["\u{1F1F7}\u{1F1F8}", "\u{1F1EE}\u{1F1F4}", "\u{1F1EE}!"]
+        );
+    }
+
+    #[test]
+    fn test_complex_context() {
+        assert_eq!(split("ภาษาไทย"), &["ภาษาไทย!"]);
+    }
+}
@@ -0,0 +1,304 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Tailored word boundaries for scripts written without spaces between words.
+//!
+//! The default word boundary rules do not find words in Thai, Lao, Khmer, Myanmar, or CJK text.
+//! These *complex context* runs are handed to a `DictionarySegmenter` instead.
+//!
+//! ## References
+//!
+//! * <https://www.unicode.org/reports/tr29/#Word_Boundary_Rules>
+
+use std::collections::{HashSet, VecDeque};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use unic_ucd_common::is_ideographic;
+use unic_ucd_segment::{LineBreak, WordBreak as WB};
+
+use crate::grapheme::GraphemeIndices;
+use crate::word::{is_hiragana, WordBounds};
+
+/// A segmenter for runs of text in scripts written without spaces between words.
+pub trait DictionarySegmenter {
+    /// Find the word boundaries inside `run`, as byte offsets in ascending order.
+    ///
+    /// The run is either all Thai, Lao, Khmer, Myanmar or similar South-East Asian script, or all
+    /// ideographs and kana. Offsets that are `0`, not less than `run.len()`, or not on a
+    /// character boundary are ignored.
+    fn boundaries(&self, run: &str) -> Vec<usize>;
+}
+
+// The kinds of runs handed to a `DictionarySegmenter`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ComplexContext {
+    SouthEastAsian,
+    Cjk,
+}
+
+impl ComplexContext {
+    fn of(segment: &str) -> Option<ComplexContext> {
+        let ch = segment.chars().next()?;
+        match WB::of(ch) {
+            WB::Katakana => Some(ComplexContext::Cjk),
+            WB::Other if is_ideographic(ch) || is_hiragana(ch) => Some(ComplexContext::Cjk),
+            _ if LineBreak::of(ch) == LineBreak::ComplexContext => {
+                Some(ComplexContext::SouthEastAsian)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// External iterator for a string's
+/// [word boundaries](https://www.unicode.org/reports/tr29/#Word_Boundaries), with complex context
+/// runs split by a `DictionarySegmenter`.
+///
+/// ```rust
+/// # use unic_segment::{TailoredWordBounds, WordListSegmenter};
+/// let thai = WordListSegmenter::from_words(&["ภาษา", "ไทย", "ง่าย"]);
+/// assert_eq!(
+///     TailoredWordBounds::new("ภาษาไทยง่าย!", &thai).collect::<Vec<&str>>(),
+///     &["ภาษา", "ไทย", "ง่าย", "!"]
+/// );
+/// ```
+#[derive(Debug)]
+pub struct TailoredWordBounds<'a, S: ?Sized> {
+    iter: WordBounds<'a>,
+    segmenter: &'a S,
+    pending: VecDeque<&'a str>,
+}
+
+impl<'a, S: DictionarySegmenter + ?Sized> TailoredWordBounds<'a, S> {
+    /// Create new iterator for *word boundaries*, using `segmenter` for complex context runs.
+    #[inline]
+    pub fn new(s: &'a str, segmenter: &'a S) -> TailoredWordBounds<'a, S> {
+        TailoredWordBounds {
+            iter: WordBounds::new(s),
+            segmenter,
+            pending: VecDeque::new(),
+        }
+    }
+
+    fn split_run(&mut self, run: &'a str) {
+        let mut boundaries = self.segmenter.boundaries(run);
+        boundaries.sort_unstable();
+        boundaries.dedup();
+
+        let mut start = 0;
+        for boundary in boundaries {
+            if boundary > start && boundary < run.len() && run.is_char_boundary(boundary) {
+                self.pending.push_back(&run[start..boundary]);
+                start = boundary;
+            }
+        }
+        self.pending.push_back(&run[start..]);
+    }
+}
+
+impl<'a, S: DictionarySegmenter + ?Sized> Iterator for TailoredWordBounds<'a, S> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if let Some(segment) = self.pending.pop_front() {
+            return Some(segment);
+        }
+
+        let rest = self.iter.as_str();
+        let segment = self.iter.next()?;
+        let context = match ComplexContext::of(segment) {
+            Some(context) => context,
+            None => return Some(segment),
+        };
+
+        // extend the run over the following segments of the same context
+        let mut len = segment.len();
+        loop {
+            let mut lookahead = self.iter.clone();
+            match lookahead.next() {
+                Some(next) if ComplexContext::of(next) == Some(context) => {
+                    len += next.len();
+                    self.iter = lookahead;
+                }
+                _ => break,
+            }
+        }
+
+        self.split_run(&rest[..len]);
+        self.pending.pop_front()
+    }
+}
+
+/// A `DictionarySegmenter` using a list of words.
+///
+/// Runs are split with *maximal matching*: the split with the fewest unknown characters is chosen,
+/// and among those the one with the fewest words, which favors longer matches. Consecutive
+/// characters not covered by any word are kept together as one segment.
+#[derive(Clone, Debug, Default)]
+pub struct WordListSegmenter {
+    words: HashSet<String>,
+    max_len: usize,
+}
+
+impl WordListSegmenter {
+    /// Create a segmenter from a list of words.
+    pub fn from_words<I>(words: I) -> WordListSegmenter
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut segmenter = WordListSegmenter::default();
+        for word in words {
+            segmenter.insert(word.as_ref());
+        }
+        segmenter
+    }
+
+    /// Load a segmenter from a plain text file with one word per line.
+    ///
+    /// Surrounding white space is trimmed, and empty lines and lines starting with `#` are
+    /// skipped.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<WordListSegmenter> {
+        let contents = fs::read_to_string(path)?;
+        Ok(WordListSegmenter::from_words(
+            contents
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#')),
+        ))
+    }
+
+    /// Add a word to the list.
+    pub fn insert(&mut self, word: &str) {
+        if !word.is_empty() {
+            self.max_len = self.max_len.max(word.len());
+            self.words.insert(word.to_owned());
+        }
+    }
+
+    /// Whether `word` is in the list.
+    pub fn contains(&self, word: &str) -> bool {
+        self.words.contains(word)
+    }
+}
+
+impl DictionarySegmenter for WordListSegmenter {
+    fn boundaries(&self, run: &str) -> Vec<usize> {
+        // Words only start and end on grapheme cluster boundaries
+        let mut positions: Vec<usize> = GraphemeIndices::new(run).map(|(i, _)| i).collect();
+        positions.push(run.len());
+
+        // best[k]: (unknown clusters, words, previous position, is word) for run[..positions[k]]
+        let mut best: Vec<Option<(usize, usize, usize, bool)>> = vec![None; positions.len()];
+        best[0] = Some((0, 0, 0, true));
+        for from in 0..positions.len() - 1 {
+            let (unknown, words, _, _) = match best[from] {
+                Some(cost) => cost,
+                None => continue,
+            };
+            let mut relax = |to: usize, cost: (usize, usize, usize, bool)| match best[to] {
+                Some(current) if (current.0, current.1) <= (cost.0, cost.1) => {}
+                _ => best[to] = Some(cost),
+            };
+
+            relax(from + 1, (unknown + 1, words + 1, from, false));
+            for to in from + 1..positions.len() {
+                if positions[to] - positions[from] > self.max_len {
+                    break;
+                }
+                if self.contains(&run[positions[from]..positions[to]]) {
+                    relax(to, (unknown, words + 1, from, true));
+                }
+            }
+        }
+
+        // walk back, merging consecutive unknown clusters
+        let mut boundaries = vec![];
+        let mut to = positions.len() - 1;
+        let mut merging = false;
+        while to > 0 {
+            let (_, _, from, is_word) = best[to].unwrap();
+            if (is_word || !merging) && to != positions.len() - 1 {
+                boundaries.push(positions[to]);
+            }
+            merging = !is_word;
+            to = from;
+        }
+        boundaries.reverse();
+        boundaries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ComplexContext, DictionarySegmenter, TailoredWordBounds, WordListSegmenter};
+
+    #[test]
+    fn test_complex_context() {
+        for run in &["ภาษา", "ພາສາ", "ភាសា", "မြန်မာ"] {
+            assert_eq!(
+                ComplexContext::of(run),
+                Some(ComplexContext::SouthEastAsian)
+            );
+        }
+        for run in &["東京", "ひらがな", "カタカナ"] {
+            assert_eq!(ComplexContext::of(run), Some(ComplexContext::Cjk));
+        }
+        for run in &["Hi", "42", " ", "한국어", ""] {
+            assert_eq!(ComplexContext::of(run), None);
+        }
+    }
+
+    #[test]
+    fn test_word_list_segmenter() {
+        let thai = WordListSegmenter::from_words(&["ไป", "ไปรษณีย์", "รษ", "ณีย์", "ที่", "ทำการ"]);
+        // longest match
+        assert_eq!(thai.boundaries("ไปรษณีย์"), &[] as &[usize]);
+        assert_eq!(thai.boundaries("ที่ทำการไปรษณีย์"), &[9, 24]);
+
+        // unknown characters are kept together
+        let segments = TailoredWordBounds::new("ที่ขขขไป", &thai).collect::<Vec<&str>>();
+        assert_eq!(segments, &["ที่", "ขขข", "ไป"]);
+    }
+
+    #[test]
+    fn test_tailored_word_bounds() {
+        let words = WordListSegmenter::from_words(&["東京", "大学", "ภาษา", "ไทย"]);
+        assert_eq!(
+            TailoredWordBounds::new("Hi, 東京大学 ภาษาไทย", &words).collect::<Vec<&str>>(),
+            &["Hi", ",", " ", "東京", "大学", " ", "ภาษา", "ไทย"]
+        );
+
+        // Without a matching word, complex context runs are kept as one segment
+        let empty = WordListSegmenter::default();
+        assert_eq!(
+            TailoredWordBounds::new("ภาษาไทย", &empty).collect::<Vec<&str>>(),
+            &["ภาษาไทย"]
+        );
+    }
+
+    #[test]
+    fn test_from_file() {
+        let path = std::env::temp_dir().join(format!(
+            "unic_segment_test_word_list_{}.txt",
+            std::process::id()
+        ));
+        std::fs::write(&path, "# Thai\nภาษา\n  ไทย  \n\n").unwrap();
+        let segmenter = WordListSegmenter::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(segmenter.contains("ภาษา"));
+        assert!(segmenter.contains("ไทย"));
+        assert!(!segmenter.contains("# Thai"));
+        assert_eq!(segmenter.boundaries("ภาษาไทย"), &["ภาษา".len()]);
+    }
+}
@@ -15,9 +15,14 @@
 //!
 //! * <https://www.unicode.org/reports/tr29/#Word_Boundaries>
 
+use std::cell::RefCell;
 use std::cmp;
 use std::iter::Filter;
 
+use unic_common::source::{CharIndices, CodeUnitSource};
+use unic_emoji_char::is_emoji;
+use unic_ucd_category::GeneralCategory;
+use unic_ucd_common::{is_alphabetic, is_ideographic, is_white_space};
 use unic_ucd_segment::WordBreak as WB;
 
 /// An iterator over the substrings of a string which, after splitting the string on [word
@@ -55,30 +60,258 @@ impl<'a> Words<'a> {
     }
 }
 
+/// The kind of a segment between two word boundaries, similar to the rule status of ICU's word
+/// break iterator.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum WordKind {
+    /// Letters, including letters of scripts without spaces between words, possibly joined by
+    /// numbers and punctuation (`can't`, `a1`).
+    Letter,
+
+    /// Numbers, possibly joined by punctuation (`32.3`).
+    Numeric,
+
+    /// Ideographic characters.
+    Ideographic,
+
+    /// Katakana and Hiragana.
+    Kana,
+
+    /// Emoji, emoji modifier sequences, emoji ZWJ sequences and flags.
+    Emoji,
+
+    /// White space, including line breaks.
+    Whitespace,
+
+    /// Punctuation.
+    Punctuation,
+
+    /// Anything else, such as symbols.
+    Other,
+}
+
+impl WordKind {
+    /// Find the kind of a segment between two word boundaries, from the `Word_Break` values of
+    /// its characters.
+    ///
+    /// Letters take precedence over numbers, and numbers over anything else, in the same way the
+    /// word boundary rules join them. Otherwise, the kind of the first character not ignored by
+    /// rule WB4 is used.
+    pub fn of(segment: &str) -> WordKind {
+        let mut kind = KindState::default();
+        for ch in segment.chars() {
+            kind.take(ch, WB::of(ch));
+        }
+        kind.kind()
+    }
+
+    /// Whether segments of this kind are words, that is, letters, numbers, ideographs or kana.
+    pub fn is_word(self) -> bool {
+        matches!(
+            self,
+            WordKind::Letter | WordKind::Numeric | WordKind::Ideographic | WordKind::Kana
+        )
+    }
+}
+
+// The Hiragana, Kana Supplement and Kana Extended-A blocks.
+pub(crate) fn is_hiragana(ch: char) -> bool {
+    matches!(ch, '\u{3040}'..='\u{309F}' | '\u{1B000}'..='\u{1B12F}')
+}
+
+fn char_kind(ch: char, cat: WB) -> WordKind {
+    match cat {
+        WB::ALetter | WB::HebrewLetter => WordKind::Letter,
+        WB::Numeric => WordKind::Numeric,
+        WB::Katakana => WordKind::Kana,
+        WB::EBase | WB::EBaseGAZ | WB::EModifier | WB::GlueAfterZwj | WB::RegionalIndicator => {
+            WordKind::Emoji
+        }
+        WB::CR | WB::LF | WB::Newline => WordKind::Whitespace,
+        _ if is_ideographic(ch) => WordKind::Ideographic,
+        _ if is_hiragana(ch) => WordKind::Kana,
+        _ if is_white_space(ch) => WordKind::Whitespace,
+        // Scripts such as Thai are not `ALetter`, as they need dictionary-based segmentation
+        _ if is_alphabetic(ch) => WordKind::Letter,
+        _ if is_emoji(ch) && !ch.is_ascii() => WordKind::Emoji,
+        _ if GeneralCategory::of(ch).is_punctuation() => WordKind::Punctuation,
+        _ => WordKind::Other,
+    }
+}
+
+// The kind of a segment, from the characters taken into it so far, in either direction.
+#[derive(Clone, Copy, Debug, Default)]
+struct KindState {
+    letter: bool,
+    numeric: bool,
+    // The first character not ignored by rule WB4, and the first character
+    first: Option<(char, WB)>,
+    first_ignored: Option<(char, WB)>,
+}
+
+impl KindState {
+    // Take a character after the ones already taken.
+    fn take(&mut self, ch: char, cat: WB) {
+        match cat {
+            WB::ALetter | WB::HebrewLetter => self.letter = true,
+            WB::Numeric => self.numeric = true,
+            WB::Extend | WB::Format | WB::ZWJ => {
+                if self.first_ignored.is_none() {
+                    self.first_ignored = Some((ch, cat));
+                }
+            }
+            _ => {
+                if self.first.is_none() {
+                    self.first = Some((ch, cat));
+                }
+            }
+        }
+    }
+
+    // Take a character before the ones already taken.
+    fn take_back(&mut self, ch: char, cat: WB) {
+        match cat {
+            WB::ALetter | WB::HebrewLetter => self.letter = true,
+            WB::Numeric => self.numeric = true,
+            WB::Extend | WB::Format | WB::ZWJ => self.first_ignored = Some((ch, cat)),
+            _ => self.first = Some((ch, cat)),
+        }
+    }
+
+    fn kind(&self) -> WordKind {
+        if self.letter {
+            WordKind::Letter
+        } else if self.numeric {
+            WordKind::Numeric
+        } else {
+            self.first
+                .or(self.first_ignored)
+                .map_or(WordKind::Other, |(ch, cat)| char_kind(ch, cat))
+        }
+    }
+}
+
+/// An iterator over the substrings of a string between [word
+/// boundaries](https://www.unicode.org/reports/tr29/#Word_Boundaries), with the `WordKind` of each.
+///
+/// ```rust
+/// # use unic_segment::{WordBoundKinds, WordKind};
+/// let words = WordBoundKinds::new("It costs 3€!")
+///     .filter(|&(_, kind)| kind.is_word())
+///     .collect::<Vec<_>>();
+/// assert_eq!(
+///     words,
+///     &[("It", WordKind::Letter), ("costs", WordKind::Letter), ("3", WordKind::Numeric)]
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct WordBoundKinds<'a> {
+    iter: WordBounds<'a>,
+}
+
+impl<'a> WordBoundKinds<'a> {
+    /// Create new iterator for *word boundaries and their kinds*.
+    #[inline]
+    pub fn new(s: &str) -> WordBoundKinds<'_> {
+        WordBoundKinds {
+            iter: WordBounds::new(s),
+        }
+    }
+
+    #[inline]
+    /// View the underlying data (the part yet to be iterated) as a slice of the original string.
+    pub fn as_str(&self) -> &'a str {
+        self.iter.as_str()
+    }
+}
+
+impl<'a> Iterator for WordBoundKinds<'a> {
+    type Item = (&'a str, WordKind);
+
+    #[inline]
+    fn next(&mut self) -> Option<(&'a str, WordKind)> {
+        self.iter.next_with_kind()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for WordBoundKinds<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(&'a str, WordKind)> {
+        self.iter.next_back_with_kind()
+    }
+}
+
 /// External iterator for a string's
 /// [word boundaries](https://www.unicode.org/reports/tr29/#Word_Boundaries).
-#[derive(Clone, Debug)]
-pub struct WordBounds<'a> {
-    string: &'a str,
+///
+/// Also works on other `CodeUnitSource`s, such as bytes that may not be valid UTF-8:
+///
+/// ```rust
+/// # use unic_segment::WordBounds;
+/// let bytes: &[u8] = b"can't\xFF stop";
+/// assert_eq!(
+///     WordBounds::from_source(bytes).collect::<Vec<&[u8]>>(),
+///     &[&b"can't"[..], b"\xFF", b" ", b"stop"]
+/// );
+/// ```
+#[derive(Debug)]
+pub struct WordBounds<'a, T: ?Sized = str> {
+    source: &'a T,
+    string: &'a T,
     cat: Option<WB>,
     catb: Option<WB>,
 }
 
+impl<'a, T: ?Sized> Clone for WordBounds<'a, T> {
+    fn clone(&self) -> Self {
+        WordBounds {
+            source: self.source,
+            string: self.string,
+            cat: self.cat,
+            catb: self.catb,
+        }
+    }
+}
+
 /// External iterator for word boundaries and byte offsets.
-#[derive(Clone, Debug)]
-pub struct WordBoundIndices<'a> {
+///
+/// Also works on other `CodeUnitSource`s, with offsets in their code units.
+#[derive(Debug)]
+pub struct WordBoundIndices<'a, T: ?Sized = str> {
     start_offset: usize,
-    iter: WordBounds<'a>,
+    iter: WordBounds<'a, T>,
+}
+
+impl<'a, T: ?Sized> Clone for WordBoundIndices<'a, T> {
+    fn clone(&self) -> Self {
+        WordBoundIndices {
+            start_offset: self.start_offset,
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<'a, T: CodeUnitSource + ?Sized> WordBoundIndices<'a, T> {
+    /// Create new iterator for *word boundries and their indices* of any code unit source.
+    #[inline]
+    pub fn from_source(s: &'a T) -> WordBoundIndices<'a, T> {
+        WordBoundIndices {
+            start_offset: 0,
+            iter: WordBounds::from_source(s),
+        }
+    }
 }
 
 impl<'a> WordBoundIndices<'a> {
     /// Create new iterator for *word boundries and their indices*.
     #[inline]
     pub fn new(s: &str) -> WordBoundIndices<'_> {
-        WordBoundIndices {
-            start_offset: s.as_ptr() as usize,
-            iter: WordBounds::new(s),
-        }
+        WordBoundIndices::from_source(s)
     }
 
     #[inline]
@@ -100,14 +333,15 @@ impl<'a> WordBoundIndices<'a> {
     }
 }
 
-impl<'a> Iterator for WordBoundIndices<'a> {
-    type Item = (usize, &'a str);
+impl<'a, T: CodeUnitSource + ?Sized> Iterator for WordBoundIndices<'a, T> {
+    type Item = (usize, &'a T);
 
     #[inline]
-    fn next(&mut self) -> Option<(usize, &'a str)> {
-        self.iter
-            .next()
-            .map(|s| (s.as_ptr() as usize - self.start_offset, s))
+    fn next(&mut self) -> Option<(usize, &'a T)> {
+        let s = self.iter.next()?;
+        let start = self.start_offset;
+        self.start_offset += s.len();
+        Some((start, s))
     }
 
     #[inline]
@@ -116,12 +350,11 @@ impl<'a> Iterator for WordBoundIndices<'a> {
     }
 }
 
-impl<'a> DoubleEndedIterator for WordBoundIndices<'a> {
+impl<'a, T: CodeUnitSource + ?Sized> DoubleEndedIterator for WordBoundIndices<'a, T> {
     #[inline]
-    fn next_back(&mut self) -> Option<(usize, &'a str)> {
-        self.iter
-            .next_back()
-            .map(|s| (s.as_ptr() as usize - self.start_offset, s))
+    fn next_back(&mut self) -> Option<(usize, &'a T)> {
+        let s = self.iter.next_back()?;
+        Some((self.start_offset + self.iter.string.len(), s))
     }
 }
 
@@ -158,8 +391,8 @@ enum RegionalState {
     Unknown,
 }
 
-impl<'a> Iterator for WordBounds<'a> {
-    type Item = &'a str;
+impl<'a, T: CodeUnitSource + ?Sized> Iterator for WordBounds<'a, T> {
+    type Item = &'a T;
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -168,8 +401,22 @@ impl<'a> Iterator for WordBounds<'a> {
     }
 
     #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.next_with_kind().map(|(s, _)| s)
+    }
+}
+
+impl<'a, T: CodeUnitSource + ?Sized> DoubleEndedIterator for WordBounds<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a T> {
+        self.next_back_with_kind().map(|(s, _)| s)
+    }
+}
+
+impl<'a, T: CodeUnitSource + ?Sized> WordBounds<'a, T> {
+    // The next segment, and its kind as found by the rules that read it.
     #[allow(clippy::match_same_arms)]
-    fn next(&mut self) -> Option<&'a str> {
+    fn next_with_kind(&mut self) -> Option<(&'a T, WordKind)> {
         use self::FormatExtendType::*;
         use self::WordBoundsState::*;
 
@@ -184,11 +431,14 @@ impl<'a> Iterator for WordBounds<'a> {
         let mut state = Start;
         let mut cat = WB::Other;
         let mut savecat = WB::Other;
+        let mut kind = KindState::default();
+        let mut prev_kind = kind;
+        let mut savekind = kind;
 
         // Whether or not the previous category was ZWJ
         // ZWJs get collapsed, so this handles precedence of WB3c over WB4
         let mut prev_zwj;
-        for (curr, ch) in self.string.char_indices() {
+        for (curr, ch, _) in CharIndices::new(self.string, 0..self.string.len()) {
             idx = curr;
             prev_zwj = cat == WB::ZWJ;
             // if there's a category cached, grab it
@@ -197,6 +447,8 @@ impl<'a> Iterator for WordBounds<'a> {
                 _ => self.cat.take().unwrap(),
             };
             take_cat = true;
+            prev_kind = kind;
+            kind.take(ch, cat);
 
             // handle rule WB4
             // just skip all format, extend, and zwj chars
@@ -285,6 +537,7 @@ impl<'a> Iterator for WordBounds<'a> {
                     WB::DoubleQuote if state == HLetter => {
                         savecat = cat;
                         saveidx = idx;
+                        savekind = prev_kind;
                         FormatExtend(RequireHLetter) // rule WB7b
                     }
                     WB::SingleQuote if state == HLetter => {
@@ -293,6 +546,7 @@ impl<'a> Iterator for WordBounds<'a> {
                     WB::MidLetter | WB::MidNumLet | WB::SingleQuote => {
                         savecat = cat;
                         saveidx = idx;
+                        savekind = prev_kind;
                         FormatExtend(RequireLetter) // rule WB6
                     }
                     _ => {
@@ -308,6 +562,7 @@ impl<'a> Iterator for WordBounds<'a> {
                     WB::MidNum | WB::MidNumLet | WB::SingleQuote => {
                         savecat = cat;
                         saveidx = idx;
+                        savekind = prev_kind;
                         FormatExtend(RequireNumeric) // rule WB12
                     }
                     _ => {
@@ -379,30 +634,33 @@ impl<'a> Iterator for WordBounds<'a> {
             // we were looking for something and didn't find it; we have to back up
             if t == RequireLetter || t == RequireHLetter || t == RequireNumeric {
                 idx = saveidx;
+                prev_kind = savekind;
                 cat = savecat;
                 take_curr = false;
             }
         }
 
         self.cat = if take_curr {
-            idx += self.string[idx..].chars().next().unwrap().len_utf8();
+            idx += self.string.char_at(idx).unwrap().1;
             None
-        } else if take_cat {
-            Some(cat)
         } else {
-            None
+            // The last character is not part of the segment
+            kind = prev_kind;
+            if take_cat {
+                Some(cat)
+            } else {
+                None
+            }
         };
 
-        let retstr = &self.string[..idx];
-        self.string = &self.string[idx..];
-        Some(retstr)
+        let retstr = self.string.slice(0..idx);
+        self.string = self.string.slice(idx..self.string.len());
+        Some((retstr, kind.kind()))
     }
-}
 
-impl<'a> DoubleEndedIterator for WordBounds<'a> {
-    #[inline]
+    // The previous segment, and its kind as found by the rules that read it.
     #[allow(clippy::cognitive_complexity)]
-    fn next_back(&mut self) -> Option<&'a str> {
+    fn next_back_with_kind(&mut self) -> Option<(&'a T, WordKind)> {
         use self::FormatExtendType::*;
         use self::WordBoundsState::*;
         if self.string.is_empty() {
@@ -412,14 +670,17 @@ impl<'a> DoubleEndedIterator for WordBounds<'a> {
         let mut take_curr = true;
         let mut take_cat = true;
         let mut idx = self.string.len();
-        idx -= self.string.chars().next_back().unwrap().len_utf8();
+        idx -= self.string.char_before(idx).unwrap().1;
         let mut previdx = idx;
         let mut saveidx = idx;
         let mut state = Start;
         let mut savestate = Start;
         let mut cat = WB::Other;
+        let mut kind = KindState::default();
+        let mut prev_kind = kind;
+        let mut savekind = kind;
 
-        for (curr, ch) in self.string.char_indices().rev() {
+        for (curr, ch, _) in CharIndices::new(self.string, 0..self.string.len()).rev() {
             previdx = idx;
             idx = curr;
 
@@ -429,6 +690,8 @@ impl<'a> DoubleEndedIterator for WordBounds<'a> {
                 _ => self.catb.take().unwrap(),
             };
             take_cat = true;
+            prev_kind = kind;
+            kind.take_back(ch, cat);
 
             // backward iterator over word boundaries. Mostly the same as the forward
             // iterator, with two weirdnesses:
@@ -444,6 +707,7 @@ impl<'a> DoubleEndedIterator for WordBounds<'a> {
                     _ => true,
                 } {
                     saveidx = previdx;
+                    savekind = prev_kind;
                     savestate = state;
                     state = FormatExtend(AcceptNone);
                 }
@@ -455,6 +719,7 @@ impl<'a> DoubleEndedIterator for WordBounds<'a> {
                 // finished a scan of some Format|Extend chars, restore previous state
                 state = savestate;
                 previdx = saveidx;
+                prev_kind = savekind;
                 take_cat = false;
             }
 
@@ -472,6 +737,7 @@ impl<'a> DoubleEndedIterator for WordBounds<'a> {
                     WB::Extend | WB::Format | WB::ZWJ => FormatExtend(AcceptAny),
                     WB::SingleQuote => {
                         saveidx = idx;
+                        savekind = kind;
                         FormatExtend(AcceptQLetter) // rule WB7a
                     }
                     WB::EModifier => Emoji, // rule WB14
@@ -505,10 +771,12 @@ impl<'a> DoubleEndedIterator for WordBounds<'a> {
                     WB::ExtendNumLet => ExtendNumLet, // rule WB13b
                     WB::DoubleQuote if state == HLetter => {
                         saveidx = previdx;
+                        savekind = prev_kind;
                         FormatExtend(RequireHLetter) // rule WB7c
                     }
                     WB::MidLetter | WB::MidNumLet | WB::SingleQuote => {
                         saveidx = previdx;
+                        savekind = prev_kind;
                         FormatExtend(RequireLetter) // rule WB7
                     }
                     _ => {
@@ -523,6 +791,7 @@ impl<'a> DoubleEndedIterator for WordBounds<'a> {
                     WB::ExtendNumLet => ExtendNumLet, // rule WB13b
                     WB::MidNum | WB::MidNumLet | WB::SingleQuote => {
                         saveidx = previdx;
+                        savekind = prev_kind;
                         FormatExtend(RequireNumeric) // rule WB11
                     }
                     _ => {
@@ -553,10 +822,9 @@ impl<'a> DoubleEndedIterator for WordBounds<'a> {
                     // rule WB13c
                     WB::RegionalIndicator => {
                         if regional_state == RegionalState::Unknown {
-                            let count = self.string[..previdx]
-                                .chars()
+                            let count = CharIndices::new(self.string, 0..previdx)
                                 .rev()
-                                .map(WB::of)
+                                .map(|(_, ch, _)| WB::of(ch))
                                 .filter(|&c| !(c == WB::ZWJ || c == WB::Extend || c == WB::Format))
                                 .take_while(|&c| c == WB::RegionalIndicator)
                                 .count();
@@ -606,6 +874,7 @@ impl<'a> DoubleEndedIterator for WordBounds<'a> {
                 || t == AcceptQLetter
             {
                 previdx = saveidx;
+                prev_kind = savekind;
                 take_cat = false;
                 take_curr = false;
             }
@@ -614,6 +883,8 @@ impl<'a> DoubleEndedIterator for WordBounds<'a> {
         self.catb = if take_curr {
             None
         } else {
+            // The last character is not part of the segment
+            kind = prev_kind;
             idx = previdx;
             if take_cat {
                 Some(cat)
@@ -622,46 +893,92 @@ impl<'a> DoubleEndedIterator for WordBounds<'a> {
             }
         };
 
-        let retstr = &self.string[idx..];
-        self.string = &self.string[..idx];
-        Some(retstr)
+        let retstr = self.string.slice(idx..self.string.len());
+        self.string = self.string.slice(0..idx);
+        Some((retstr, kind.kind()))
     }
 }
 
-impl<'a> WordBounds<'a> {
-    /// Create new iterator for *word boundries*.
+impl<'a, T: CodeUnitSource + ?Sized> WordBounds<'a, T> {
+    /// Create new iterator for *word boundries* of any code unit source.
     #[inline]
-    pub fn new(s: &str) -> WordBounds<'_> {
+    pub fn from_source(s: &'a T) -> WordBounds<'a, T> {
         WordBounds {
+            source: s,
             string: s,
             cat: None,
             catb: None,
         }
     }
 
-    #[inline]
-    /// View the underlying data (the part yet to be iterated) as a slice of the original string.
+    /// Find the last word boundary before `offset`, like `preceding()` of an ICU
+    /// `BreakIterator`.
+    ///
+    /// Offsets are into the whole text, whatever part of it has been iterated already. Only the
+    /// text around `offset` is looked at, so this is cheap anywhere in a long text. Returns `None`
+    /// if `offset` is past the end of the text, or not on a codepoint boundary.
     ///
     /// ```rust
     /// # use unic_segment::WordBounds;
-    /// let mut iter = WordBounds::new("Hello world");
-    /// assert_eq!(iter.as_str(), "Hello world");
+    /// let bounds = WordBounds::new("can't stop");
+    /// assert_eq!(bounds.preceding(8), Some(6));
+    /// assert_eq!(bounds.preceding(5), Some(0));
+    /// assert_eq!(bounds.preceding(0), None);
+    /// assert_eq!(bounds.preceding(11), None);
+    /// ```
+    pub fn preceding(&self, offset: usize) -> Option<usize> {
+        self.cursor_at(offset)?.prev_boundary(self.source, 0).ok()?
+    }
+
+    /// Find the first word boundary after `offset`, like `following()` of an ICU
+    /// `BreakIterator`.
     ///
-    /// iter.next();
-    /// assert_eq!(iter.as_str(), " world");
+    /// Offsets are as for `preceding()`.
     ///
-    /// iter.next();
-    /// assert_eq!(iter.as_str(), "world");
+    /// ```rust
+    /// # use unic_segment::WordBounds;
+    /// let bounds = WordBounds::new("can't stop");
+    /// assert_eq!(bounds.following(2), Some(5));
+    /// assert_eq!(bounds.following(5), Some(6));
+    /// assert_eq!(bounds.following(10), None);
     /// ```
-    pub fn as_str(&self) -> &'a str {
-        self.string
+    pub fn following(&self, offset: usize) -> Option<usize> {
+        self.cursor_at(offset)?.next_boundary(self.source, 0).ok()?
+    }
+
+    /// Whether there is a word boundary at `offset`.
+    ///
+    /// Offsets are as for `preceding()`; both ends of the text are boundaries, and offsets past
+    /// the end or not on a codepoint boundary are not.
+    ///
+    /// ```rust
+    /// # use unic_segment::WordBounds;
+    /// let bounds = WordBounds::new("can't stop");
+    /// assert!(!bounds.is_boundary(3));
+    /// assert!(bounds.is_boundary(5));
+    /// assert!(bounds.is_boundary(10));
+    /// assert!(!bounds.is_boundary(11));
+    /// ```
+    pub fn is_boundary(&self, offset: usize) -> bool {
+        match self.cursor_at(offset) {
+            Some(cursor) => cursor.is_boundary(self.source, 0) == Ok(true),
+            None => false,
+        }
+    }
+
+    // A fresh cursor at `offset`, for the whole text given as a single chunk.
+    fn cursor_at(&self, offset: usize) -> Option<WordCursor> {
+        if !self.source.is_char_boundary(offset) {
+            return None;
+        }
+        Some(WordCursor::new(offset, self.source.len()))
     }
 
     #[inline]
     fn get_next_cat(&self, idx: usize) -> Option<WB> {
-        let nidx = idx + self.string[idx..].chars().next().unwrap().len_utf8();
+        let nidx = idx + self.string.char_at(idx).unwrap().1;
         if nidx < self.string.len() {
-            let nch = self.string[nidx..].chars().next().unwrap();
+            let (nch, _) = self.string.char_at(nidx).unwrap();
             Some(WB::of(nch))
         } else {
             None
@@ -671,7 +988,7 @@ impl<'a> WordBounds<'a> {
     #[inline]
     fn get_prev_cat(&self, idx: usize) -> Option<WB> {
         if idx > 0 {
-            let nch = self.string[..idx].chars().next_back().unwrap();
+            let (nch, _) = self.string.char_before(idx).unwrap();
             Some(WB::of(nch))
         } else {
             None
@@ -679,9 +996,702 @@ impl<'a> WordBounds<'a> {
     }
 }
 
+impl<'a> WordBounds<'a> {
+    /// Create new iterator for *word boundries*.
+    #[inline]
+    pub fn new(s: &str) -> WordBounds<'_> {
+        WordBounds::from_source(s)
+    }
+
+    #[inline]
+    /// View the underlying data (the part yet to be iterated) as a slice of the original string.
+    ///
+    /// ```rust
+    /// # use unic_segment::WordBounds;
+    /// let mut iter = WordBounds::new("Hello world");
+    /// assert_eq!(iter.as_str(), "Hello world");
+    ///
+    /// iter.next();
+    /// assert_eq!(iter.as_str(), " world");
+    ///
+    /// iter.next();
+    /// assert_eq!(iter.as_str(), "world");
+    /// ```
+    pub fn as_str(&self) -> &'a str {
+        self.string
+    }
+}
+
+// An enum describing information about a potential word boundary.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum WordState {
+    // No information is known.
+    Unknown,
+    // It is known to not be a boundary.
+    NotBreak,
+    // It is known to be a boundary.
+    Break,
+}
+
+/// Cursor-based segmenter for word boundaries.
+///
+/// This is the word boundary counterpart of `GraphemeCursor`, for text that is not available as
+/// one contiguous `&str`, such as ropes. It follows the same protocol: the cursor only holds the
+/// offset and the length of the text, and methods take the chunk containing the offset, returning
+/// `WordIncomplete` when more of the text is needed.
+#[derive(Clone, Debug)]
+pub struct WordCursor {
+    /// Current cursor position.
+    offset: usize,
+
+    /// Total length of the string.
+    len: usize,
+
+    /// What is known of the text around the cursor, kept across calls to `is_boundary`.
+    context: RefCell<WordContext>,
+
+    /// Set if a call to `prev_boundary` or `next_boundary` was suspended due to needing more input.
+    resuming: bool,
+}
+
+/// State derived from the text around the cursor position.
+#[derive(Clone, Debug)]
+struct WordContext {
+    /// Total length of the string.
+    len: usize,
+
+    /// Information about the potential boundary at `offset`.
+    state: WordState,
+
+    /// Codepoint immediately preceding cursor, if known.
+    char_before: Option<char>,
+
+    /// Codepoint immediately after cursor, if known.
+    char_after: Option<char>,
+
+    /// Categories preceding the cursor, nearest first, with Extend, Format and ZWJ folded into the
+    /// character before them (WB4).
+    lookbehind: Vec<WB>,
+
+    /// Offset up to which the text preceding the cursor has been scanned into `lookbehind`.
+    lookbehind_offset: usize,
+
+    /// Set if the codepoints scanned last were Extend, Format or ZWJ not yet attached to a
+    /// preceding character.
+    lookbehind_ignorable: bool,
+
+    /// Categories following the cursor, nearest first, with Extend, Format and ZWJ skipped (WB4).
+    lookahead: Vec<WB>,
+
+    /// Offset up to which the text following the cursor has been scanned into `lookahead`.
+    lookahead_offset: usize,
+
+    /// If set, at least one more codepoint immediately preceding this offset is needed to resolve
+    /// whether there's a boundary at `offset`.
+    pre_context_offset: Option<usize>,
+
+    /// If set, at least one more codepoint immediately following this offset is needed to resolve
+    /// whether there's a boundary at `offset`.
+    post_context_offset: Option<usize>,
+}
+
+/// An error return indicating that not enough content was available in the
+/// provided chunk to satisfy the query, and that more content must be provided.
+#[derive(Debug, Eq, PartialEq)]
+pub enum WordIncomplete {
+    /// More pre-context is needed. The caller should call `provide_context`
+    /// with a chunk ending at the offset given, then retry the query. This
+    /// will only be returned if the `chunk_start` parameter is nonzero.
+    PreContext(usize),
+
+    /// More post-context is needed. The caller should call `provide_context`
+    /// with a chunk starting at the offset given, then retry the query. This
+    /// will only be returned if the chunk ends before the `len` parameter
+    /// provided on creation of the cursor.
+    PostContext(usize),
+
+    /// When requesting `prev_boundary`, the cursor is moving past the beginning
+    /// of the current chunk, so the chunk before that is requested. This will
+    /// only be returned if the `chunk_start` parameter is nonzero.
+    PrevChunk,
+
+    /// When requesting `next_boundary`, the cursor is moving past the end of the
+    /// current chunk, so the chunk after that is requested. This will only be
+    /// returned if the chunk ends before the `len` parameter provided on
+    /// creation of the cursor.
+    NextChunk,
+
+    /// An error returned when the chunk given does not contain the cursor position.
+    InvalidOffset,
+}
+
+fn is_ignorable(cat: WB) -> bool {
+    cat == WB::Extend || cat == WB::Format || cat == WB::ZWJ
+}
+
+#[inline]
+fn is_newline(cat: WB) -> bool {
+    cat == WB::CR || cat == WB::LF || cat == WB::Newline
+}
+
+#[inline]
+fn is_ahletter(cat: Option<WB>) -> bool {
+    cat == Some(WB::ALetter) || cat == Some(WB::HebrewLetter)
+}
+
+impl WordCursor {
+    /// Create a new cursor. The string and initial offset are given at creation
+    /// time, but the contents of the string are not.
+    ///
+    /// The `offset` parameter must be on a codepoint boundary.
+    ///
+    /// ```rust
+    /// # use unic_segment::WordCursor;
+    /// let s = "Hello world";
+    /// let mut cursor = WordCursor::new(0, s.len());
+    /// assert_eq!(cursor.next_boundary(s, 0), Ok(Some(5)));
+    /// ```
+    pub fn new(offset: usize, len: usize) -> WordCursor {
+        WordCursor {
+            offset,
+            len,
+            context: RefCell::new(WordContext::new(offset, len)),
+            resuming: false,
+        }
+    }
+
+    /// Set the cursor to a new location in the same string.
+    ///
+    /// ```rust
+    /// # use unic_segment::WordCursor;
+    /// let s = "Hello world";
+    /// let mut cursor = WordCursor::new(0, s.len());
+    /// assert_eq!(cursor.cur_cursor(), 0);
+    /// cursor.set_cursor(6);
+    /// assert_eq!(cursor.cur_cursor(), 6);
+    /// ```
+    pub fn set_cursor(&mut self, offset: usize) {
+        if offset != self.offset {
+            self.offset = offset;
+            self.resuming = false;
+            self.context.get_mut().reset(offset);
+        }
+    }
+
+    /// The current offset of the cursor. Equal to the last value provided to
+    /// `new()` or `set_cursor()`, or returned from `next_boundary()` or
+    /// `prev_boundary()`.
+    ///
+    /// ```rust
+    /// # use unic_segment::WordCursor;
+    /// let s = "can't stop";
+    /// let mut cursor = WordCursor::new(0, s.len());
+    /// assert_eq!(cursor.next_boundary(s, 0), Ok(Some(5)));
+    /// assert_eq!(cursor.cur_cursor(), 5);
+    /// ```
+    pub fn cur_cursor(&self) -> usize {
+        self.offset
+    }
+
+    /// Provide additional context when it is needed to decide a boundary.
+    /// The chunk must end at the offset given in a `WordIncomplete::PreContext`
+    /// request, or start at the offset given in a `WordIncomplete::PostContext`
+    /// request.
+    ///
+    /// ```rust
+    /// # use unic_segment::{WordCursor, WordIncomplete};
+    /// let s = "can't";
+    /// let mut cursor = WordCursor::new(3, s.len());
+    ///
+    /// // Whether the apostrophe joins two letters depends on the letter after it (WB6).
+    /// assert_eq!(cursor.is_boundary(&s[..4], 0), Err(WordIncomplete::PostContext(4)));
+    ///
+    /// cursor.provide_context(&s[4..], 4);
+    /// assert_eq!(cursor.is_boundary(&s[..4], 0), Ok(false));
+    /// ```
+    pub fn provide_context<T: CodeUnitSource + ?Sized>(&mut self, chunk: &T, chunk_start: usize) {
+        let context = self.context.get_mut();
+        if context.pre_context_offset == Some(chunk_start + chunk.len()) {
+            context.pre_context_offset = None;
+            if context.char_before.is_none() {
+                let (ch, _) = chunk.char_before(chunk.len()).unwrap();
+                context.char_before = Some(ch);
+            }
+            let depth = context.lookbehind.len() + 1;
+            context.scan_lookbehind(chunk, chunk_start, cmp::max(depth, 2));
+        } else if context.post_context_offset == Some(chunk_start) {
+            context.post_context_offset = None;
+            if context.char_after.is_none() {
+                let (ch, _) = chunk.char_at(0).unwrap();
+                context.char_after = Some(ch);
+            }
+            context.scan_lookahead(chunk, chunk_start, 2);
+        } else {
+            panic!("context chunk does not match the requested offset");
+        }
+    }
+
+    /// Determine whether the current cursor location is a word boundary.
+    /// Only a part of the string need be supplied. If `chunk_start` is nonzero or
+    /// the length of `chunk` is not equal to `len` on creation, then this method
+    /// may return `WordIncomplete::PreContext` or `WordIncomplete::PostContext`.
+    /// The caller should then call `provide_context` with the requested chunk,
+    /// then retry calling this method.
+    ///
+    /// Unlike grapheme clusters, word boundaries may depend on text after the
+    /// cursor (WB6, WB7b and WB12) as well as on an unbounded amount of text
+    /// before it (WB4 skip-over, and the Regional Indicator parity of WB15 and
+    /// WB16).
+    ///
+    /// All calls should have consistent chunk contents (ie, if a chunk provides
+    /// content for a given slice, all further chunks covering that slice must have
+    /// the same content for it).
+    ///
+    /// ```rust
+    /// # use unic_segment::WordCursor;
+    /// let s = "can't stop";
+    /// let mut cursor = WordCursor::new(3, s.len());
+    /// assert_eq!(cursor.is_boundary(s, 0), Ok(false));
+    /// cursor.set_cursor(5);
+    /// assert_eq!(cursor.is_boundary(s, 0), Ok(true));
+    /// ```
+    pub fn is_boundary<T: CodeUnitSource + ?Sized>(
+        &self,
+        chunk: &T,
+        chunk_start: usize,
+    ) -> Result<bool, WordIncomplete> {
+        self.context
+            .borrow_mut()
+            .is_boundary(self.offset, chunk, chunk_start)
+    }
+
+    /// Find the next boundary after the current cursor position. Only a part of
+    /// the string need be supplied. If the chunk is incomplete, then this
+    /// method might return `WordIncomplete::PreContext`,
+    /// `WordIncomplete::PostContext` or `WordIncomplete::NextChunk`. In the
+    /// former cases, the caller should call `provide_context` with the requested
+    /// chunk, then retry. In the latter case, the caller should provide the
+    /// chunk following the one given, then retry.
+    ///
+    /// See `is_boundary` for expectations on the provided chunk.
+    ///
+    /// ```rust
+    /// # use unic_segment::WordCursor;
+    /// let s = "The quick fox";
+    /// let mut cursor = WordCursor::new(0, s.len());
+    /// assert_eq!(cursor.next_boundary(s, 0), Ok(Some(3)));
+    /// assert_eq!(cursor.next_boundary(s, 0), Ok(Some(4)));
+    /// assert_eq!(cursor.next_boundary(s, 0), Ok(Some(9)));
+    /// ```
+    ///
+    /// And an example that uses partial strings:
+    ///
+    /// ```rust
+    /// # use unic_segment::{WordCursor, WordIncomplete};
+    /// let s = "ab cd";
+    /// let mut cursor = WordCursor::new(0, s.len());
+    /// assert_eq!(cursor.next_boundary(&s[..2], 0), Err(WordIncomplete::NextChunk));
+    /// assert_eq!(cursor.next_boundary(&s[2..], 2), Ok(Some(2)));
+    /// assert_eq!(cursor.next_boundary(&s[2..], 2), Ok(Some(3)));
+    /// assert_eq!(cursor.next_boundary(&s[2..], 2), Ok(Some(5)));
+    /// assert_eq!(cursor.next_boundary(&s[2..], 2), Ok(None));
+    /// ```
+    pub fn next_boundary<T: CodeUnitSource + ?Sized>(
+        &mut self,
+        chunk: &T,
+        chunk_start: usize,
+    ) -> Result<Option<usize>, WordIncomplete> {
+        if self.offset == self.len {
+            return Ok(None);
+        }
+        if self.offset < chunk_start {
+            return Err(WordIncomplete::InvalidOffset);
+        }
+        loop {
+            if !self.resuming {
+                if self.offset >= chunk_start + chunk.len() {
+                    return Err(WordIncomplete::NextChunk);
+                }
+                let (ch, len) = chunk.char_at(self.offset - chunk_start).unwrap();
+                self.offset += len;
+                let context = self.context.get_mut();
+                context.reset(self.offset);
+                context.char_before = Some(ch);
+                self.resuming = true;
+                if self.offset == chunk_start + chunk.len() && self.offset != self.len {
+                    // gather what we can of the context before the next chunk is provided
+                    context.scan_lookbehind(chunk, chunk_start, 2);
+                    return Err(WordIncomplete::NextChunk);
+                }
+            }
+            if self.is_boundary(chunk, chunk_start)? {
+                self.resuming = false;
+                return Ok(Some(self.offset));
+            }
+            self.resuming = false;
+        }
+    }
+
+    /// Find the previous boundary before the current cursor position. Only a
+    /// part of the string need be supplied. If the chunk is incomplete, then
+    /// this method might return `WordIncomplete::PreContext`,
+    /// `WordIncomplete::PostContext` or `WordIncomplete::PrevChunk`. In the
+    /// former cases, the caller should call `provide_context` with the requested
+    /// chunk, then retry. In the latter case, the caller should provide the
+    /// chunk preceding the one given, then retry.
+    ///
+    /// See `is_boundary` for expectations on the provided chunk.
+    ///
+    /// ```rust
+    /// # use unic_segment::WordCursor;
+    /// let s = "The quick fox";
+    /// let mut cursor = WordCursor::new(s.len(), s.len());
+    /// assert_eq!(cursor.prev_boundary(s, 0), Ok(Some(10)));
+    /// assert_eq!(cursor.prev_boundary(s, 0), Ok(Some(9)));
+    /// assert_eq!(cursor.prev_boundary(s, 0), Ok(Some(4)));
+    /// ```
+    ///
+    /// And an example that uses partial strings:
+    ///
+    /// ```rust
+    /// # use unic_segment::{WordCursor, WordIncomplete};
+    /// let s = "ab cd";
+    /// let mut cursor = WordCursor::new(s.len(), s.len());
+    /// assert_eq!(cursor.prev_boundary(&s[3..], 3), Err(WordIncomplete::PrevChunk));
+    /// assert_eq!(cursor.prev_boundary(&s[..3], 0), Ok(Some(3)));
+    /// assert_eq!(cursor.prev_boundary(&s[..3], 0), Ok(Some(2)));
+    /// assert_eq!(cursor.prev_boundary(&s[..3], 0), Ok(Some(0)));
+    /// assert_eq!(cursor.prev_boundary(&s[..3], 0), Ok(None));
+    /// ```
+    pub fn prev_boundary<T: CodeUnitSource + ?Sized>(
+        &mut self,
+        chunk: &T,
+        chunk_start: usize,
+    ) -> Result<Option<usize>, WordIncomplete> {
+        if self.offset == 0 {
+            return Ok(None);
+        }
+        if self.offset > chunk_start + chunk.len() {
+            return Err(WordIncomplete::InvalidOffset);
+        }
+        loop {
+            if !self.resuming {
+                if self.offset <= chunk_start {
+                    return Err(WordIncomplete::PrevChunk);
+                }
+                let (ch, len) = chunk.char_before(self.offset - chunk_start).unwrap();
+                self.offset -= len;
+                let context = self.context.get_mut();
+                context.reset(self.offset);
+                context.char_after = Some(ch);
+                self.resuming = true;
+                if self.offset == chunk_start && self.offset != 0 {
+                    // gather what we can of the context after the previous chunk is provided
+                    context.scan_lookahead(chunk, chunk_start, 2);
+                    return Err(WordIncomplete::PrevChunk);
+                }
+            }
+            if self.is_boundary(chunk, chunk_start)? {
+                self.resuming = false;
+                return Ok(Some(self.offset));
+            }
+            self.resuming = false;
+        }
+    }
+}
+
+impl WordContext {
+    fn new(offset: usize, len: usize) -> WordContext {
+        let mut context = WordContext {
+            len,
+            state: WordState::Unknown,
+            char_before: None,
+            char_after: None,
+            lookbehind: Vec::new(),
+            lookbehind_offset: offset,
+            lookbehind_ignorable: false,
+            lookahead: Vec::new(),
+            lookahead_offset: offset,
+            pre_context_offset: None,
+            post_context_offset: None,
+        };
+        context.reset(offset);
+        context
+    }
+
+    // reset state derived from text around cursor
+    fn reset(&mut self, offset: usize) {
+        self.state = if offset == 0 || offset == self.len {
+            WordState::Break
+        } else {
+            WordState::Unknown
+        };
+        self.char_before = None;
+        self.char_after = None;
+        self.lookbehind.clear();
+        self.lookbehind_offset = offset;
+        self.lookbehind_ignorable = false;
+        self.lookahead.clear();
+        self.lookahead_offset = offset;
+        self.pre_context_offset = None;
+        self.post_context_offset = None;
+    }
+
+    // Scan backwards from `lookbehind_offset` until `depth` categories are known, the start of the
+    // string or a newline is reached, or the chunk is exhausted. A run of Regional Indicators
+    // directly before the cursor is always scanned to its end, for WB15 and WB16.
+    fn scan_lookbehind<T: CodeUnitSource + ?Sized>(
+        &mut self,
+        chunk: &T,
+        chunk_start: usize,
+        depth: usize,
+    ) {
+        let satisfied = |cats: &Vec<WB>| {
+            cats.len() >= depth
+                && !(cats[0] == WB::RegionalIndicator
+                    && cats.last() == Some(&WB::RegionalIndicator))
+        };
+        let mut iter = CharIndices::new(chunk, 0..self.lookbehind_offset - chunk_start).rev();
+        while self.lookbehind_offset > 0 && !satisfied(&self.lookbehind) {
+            let (ch, len) = match iter.next() {
+                Some((_, ch, len)) => (ch, len),
+                None => return,
+            };
+            self.lookbehind_offset -= len;
+            let cat = WB::of(ch);
+            if is_ignorable(cat) {
+                self.lookbehind_ignorable = true;
+                continue;
+            }
+            // rule WB4
+            // Extend, Format and ZWJ after a newline do not attach to it
+            if is_newline(cat) {
+                if self.lookbehind_ignorable {
+                    self.lookbehind.push(WB::Other);
+                }
+                self.lookbehind.push(cat);
+                self.lookbehind_offset = 0;
+            } else {
+                self.lookbehind.push(cat);
+            }
+            self.lookbehind_ignorable = false;
+        }
+        if self.lookbehind_offset == 0 && self.lookbehind_ignorable {
+            self.lookbehind.push(WB::Other);
+            self.lookbehind_ignorable = false;
+        }
+    }
+
+    // Scan forwards from `lookahead_offset` until `depth` categories are known, the end of the
+    // string is reached, or the chunk is exhausted.
+    fn scan_lookahead<T: CodeUnitSource + ?Sized>(
+        &mut self,
+        chunk: &T,
+        chunk_start: usize,
+        depth: usize,
+    ) {
+        let mut iter = CharIndices::new(chunk, self.lookahead_offset - chunk_start..chunk.len());
+        while self.lookahead_offset < self.len && self.lookahead.len() < depth {
+            let (ch, len) = match iter.next() {
+                Some((_, ch, len)) => (ch, len),
+                None => return,
+            };
+            self.lookahead_offset += len;
+            let cat = WB::of(ch);
+            // rule WB4
+            if self.lookahead.is_empty() || !is_ignorable(cat) {
+                self.lookahead.push(cat);
+            }
+        }
+    }
+
+    // The `index`-th category before the cursor, after WB4 folding. `None` is start of text.
+    fn lookbehind<T: CodeUnitSource + ?Sized>(
+        &mut self,
+        index: usize,
+        chunk: &T,
+        chunk_start: usize,
+    ) -> Result<Option<WB>, WordIncomplete> {
+        if index >= self.lookbehind.len() && self.lookbehind_offset > 0 {
+            if self.lookbehind_offset > chunk_start
+                && self.lookbehind_offset <= chunk_start + chunk.len()
+            {
+                self.scan_lookbehind(chunk, chunk_start, index + 1);
+            }
+            if index >= self.lookbehind.len() && self.lookbehind_offset > 0 {
+                self.pre_context_offset = Some(self.lookbehind_offset);
+                return Err(WordIncomplete::PreContext(self.lookbehind_offset));
+            }
+        }
+        Ok(self.lookbehind.get(index).cloned())
+    }
+
+    // The `index`-th category after the cursor, after WB4 skipping. `None` is end of text.
+    fn lookahead<T: CodeUnitSource + ?Sized>(
+        &mut self,
+        index: usize,
+        chunk: &T,
+        chunk_start: usize,
+    ) -> Result<Option<WB>, WordIncomplete> {
+        if index >= self.lookahead.len() && self.lookahead_offset < self.len {
+            if self.lookahead_offset >= chunk_start
+                && self.lookahead_offset < chunk_start + chunk.len()
+            {
+                self.scan_lookahead(chunk, chunk_start, index + 1);
+            }
+            if index >= self.lookahead.len() && self.lookahead_offset < self.len {
+                self.post_context_offset = Some(self.lookahead_offset);
+                return Err(WordIncomplete::PostContext(self.lookahead_offset));
+            }
+        }
+        Ok(self.lookahead.get(index).cloned())
+    }
+
+    fn decision(&mut self, is_break: bool) -> Result<bool, WordIncomplete> {
+        self.state = if is_break {
+            WordState::Break
+        } else {
+            WordState::NotBreak
+        };
+        Ok(is_break)
+    }
+
+    #[allow(clippy::match_same_arms)]
+    fn check_rules<T: CodeUnitSource + ?Sized>(
+        &mut self,
+        chunk: &T,
+        chunk_start: usize,
+    ) -> Result<bool, WordIncomplete> {
+        let before = WB::of(self.char_before.unwrap());
+        let after = WB::of(self.char_after.unwrap());
+
+        match (before, after) {
+            (WB::CR, WB::LF) => return self.decision(false), // rule WB3
+            (WB::CR, _) | (WB::LF, _) | (WB::Newline, _) => return self.decision(true), // WB3a
+            (_, WB::CR) | (_, WB::LF) | (_, WB::Newline) => return self.decision(true), // WB3b
+            (WB::ZWJ, WB::GlueAfterZwj) | (WB::ZWJ, WB::EBaseGAZ) => return self.decision(false), // WB3c
+            (_, WB::Extend) | (_, WB::Format) | (_, WB::ZWJ) => return self.decision(false), // WB4
+            _ => {}
+        }
+
+        let before = self.lookbehind(0, chunk, chunk_start)?.unwrap();
+        let is_break = match (before, after) {
+            (WB::ALetter, WB::ALetter)
+            | (WB::ALetter, WB::HebrewLetter)
+            | (WB::HebrewLetter, WB::ALetter)
+            | (WB::HebrewLetter, WB::HebrewLetter) => false, // rule WB5
+            (WB::HebrewLetter, WB::SingleQuote) => false, // rule WB7a
+            (WB::ALetter, WB::MidLetter)
+            | (WB::ALetter, WB::MidNumLet)
+            | (WB::ALetter, WB::SingleQuote)
+            | (WB::HebrewLetter, WB::MidLetter)
+            | (WB::HebrewLetter, WB::MidNumLet) => {
+                // rule WB6
+                !is_ahletter(self.lookahead(1, chunk, chunk_start)?)
+            }
+            (WB::MidLetter, WB::ALetter)
+            | (WB::MidLetter, WB::HebrewLetter)
+            | (WB::MidNumLet, WB::ALetter)
+            | (WB::MidNumLet, WB::HebrewLetter)
+            | (WB::SingleQuote, WB::ALetter)
+            | (WB::SingleQuote, WB::HebrewLetter) => {
+                // rule WB7
+                !is_ahletter(self.lookbehind(1, chunk, chunk_start)?)
+            }
+            (WB::HebrewLetter, WB::DoubleQuote) => {
+                // rule WB7b
+                self.lookahead(1, chunk, chunk_start)? != Some(WB::HebrewLetter)
+            }
+            (WB::DoubleQuote, WB::HebrewLetter) => {
+                // rule WB7c
+                self.lookbehind(1, chunk, chunk_start)? != Some(WB::HebrewLetter)
+            }
+            (WB::Numeric, WB::Numeric) => false, // rule WB8
+            (WB::ALetter, WB::Numeric) | (WB::HebrewLetter, WB::Numeric) => false, // rule WB9
+            (WB::Numeric, WB::ALetter) | (WB::Numeric, WB::HebrewLetter) => false, // rule WB10
+            (WB::MidNum, WB::Numeric)
+            | (WB::MidNumLet, WB::Numeric)
+            | (WB::SingleQuote, WB::Numeric) => {
+                // rule WB11
+                self.lookbehind(1, chunk, chunk_start)? != Some(WB::Numeric)
+            }
+            (WB::Numeric, WB::MidNum)
+            | (WB::Numeric, WB::MidNumLet)
+            | (WB::Numeric, WB::SingleQuote) => {
+                // rule WB12
+                self.lookahead(1, chunk, chunk_start)? != Some(WB::Numeric)
+            }
+            (WB::Katakana, WB::Katakana) => false, // rule WB13
+            (WB::ALetter, WB::ExtendNumLet)
+            | (WB::HebrewLetter, WB::ExtendNumLet)
+            | (WB::Numeric, WB::ExtendNumLet)
+            | (WB::Katakana, WB::ExtendNumLet)
+            | (WB::ExtendNumLet, WB::ExtendNumLet) => false, // rule WB13a
+            (WB::ExtendNumLet, WB::ALetter)
+            | (WB::ExtendNumLet, WB::HebrewLetter)
+            | (WB::ExtendNumLet, WB::Numeric)
+            | (WB::ExtendNumLet, WB::Katakana) => false, // rule WB13b
+            (WB::EBase, WB::EModifier) | (WB::EBaseGAZ, WB::EModifier) => false, // rule WB14
+            (WB::RegionalIndicator, WB::RegionalIndicator) => {
+                // rule WB15, WB16
+                let mut ris_count = 1;
+                while self.lookbehind(ris_count, chunk, chunk_start)? == Some(WB::RegionalIndicator)
+                {
+                    ris_count += 1;
+                }
+                ris_count % 2 == 0
+            }
+            _ => true, // rule WB999
+        };
+        self.decision(is_break)
+    }
+
+    // Whether there is a boundary at `offset`, the cursor position.
+    fn is_boundary<T: CodeUnitSource + ?Sized>(
+        &mut self,
+        offset: usize,
+        chunk: &T,
+        chunk_start: usize,
+    ) -> Result<bool, WordIncomplete> {
+        if self.state == WordState::Break {
+            return Ok(true);
+        }
+        if self.state == WordState::NotBreak {
+            return Ok(false);
+        }
+        if offset < chunk_start || offset > chunk_start + chunk.len() {
+            return Err(WordIncomplete::InvalidOffset);
+        }
+        if let Some(pre_context_offset) = self.pre_context_offset {
+            return Err(WordIncomplete::PreContext(pre_context_offset));
+        }
+        if let Some(post_context_offset) = self.post_context_offset {
+            return Err(WordIncomplete::PostContext(post_context_offset));
+        }
+        let offset_in_chunk = offset - chunk_start;
+        if self.char_before.is_none() {
+            if offset == chunk_start {
+                self.pre_context_offset = Some(chunk_start);
+                return Err(WordIncomplete::PreContext(chunk_start));
+            }
+            let (ch, _) = chunk.char_before(offset_in_chunk).unwrap();
+            self.char_before = Some(ch);
+        }
+        if self.char_after.is_none() {
+            if offset == chunk_start + chunk.len() {
+                self.post_context_offset = Some(offset);
+                return Err(WordIncomplete::PostContext(offset));
+            }
+            let (ch, _) = chunk.char_at(offset_in_chunk).unwrap();
+            self.char_after = Some(ch);
+        }
+        self.check_rules(chunk, chunk_start)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{WordBounds, Words};
+    use super::{WordBoundIndices, WordBoundKinds, WordBounds, WordKind, Words};
     use unic_ucd_common::is_alphanumeric;
 
     #[test]
@@ -703,4 +1713,124 @@ mod tests {
             &["The", "quick", "brown", "fox", "can't", "jump", "32.3", "feet", "right"]
         );
     }
+
+    #[test]
+    fn test_word_bound_kinds() {
+        use super::WordKind::*;
+
+        assert_eq!(
+            WordBoundKinds::new("Brr, it's 29.3°F!\n").collect::<Vec<(&str, WordKind)>>(),
+            &[
+                ("Brr", Letter),
+                (",", Punctuation),
+                (" ", Whitespace),
+                ("it's", Letter),
+                (" ", Whitespace),
+                ("29.3", Numeric),
+                ("°", Other),
+                ("F", Letter),
+                ("!", Punctuation),
+                ("\n", Whitespace),
+            ]
+        );
+
+        assert_eq!(
+            WordBoundKinds::new("東京へカタカナ🇯🇵👍🏽").collect::<Vec<(&str, WordKind)>>(),
+            &[
+                ("東", Ideographic),
+                ("京", Ideographic),
+                ("へ", Kana),
+                ("カタカナ", Kana),
+                ("🇯🇵", Emoji),
+                ("👍🏽", Emoji),
+            ]
+        );
+
+        // The same kinds are found backwards, including for segments the rules back out of
+        for &text in &["Brr, it's 29.3°F!\n", "a'.'b 1,' \u{301}x_\u{200d}"] {
+            let mut kinds = WordBoundKinds::new(text).rev().collect::<Vec<_>>();
+            kinds.reverse();
+            assert_eq!(kinds, WordBoundKinds::new(text).collect::<Vec<_>>());
+            for (segment, kind) in kinds {
+                assert_eq!(kind, WordKind::of(segment), "{:?}", segment);
+            }
+        }
+
+        assert_eq!(WordKind::of("a1"), Letter);
+        assert_eq!(WordKind::of("1_a"), Letter);
+        assert_eq!(WordKind::of("ภาษาไทย"), Letter);
+        assert_eq!(WordKind::of("\u{301}"), Other);
+        assert_eq!(WordKind::of(""), Other);
+    }
+
+    #[test]
+    fn test_word_bounds_random_access() {
+        let input = "The quick (\"brown\")  fox";
+        let mut bounds = WordBounds::new(input);
+        assert_eq!(bounds.preceding(7), Some(4));
+        assert_eq!(bounds.following(7), Some(9));
+        assert!(!bounds.is_boundary(22));
+        assert!(bounds.is_boundary(21));
+
+        // Offsets are into the whole text, however much of it was iterated.
+        bounds.next();
+        bounds.next();
+        bounds.next_back();
+        assert_eq!(bounds.as_str(), "quick (\"brown\")  ");
+        assert_eq!(bounds.preceding(3), Some(0));
+        assert_eq!(bounds.following(3), Some(4));
+        assert_eq!(bounds.following(21), Some(24));
+        assert!(!bounds.is_boundary(22));
+
+        // Offsets past the end, or inside a codepoint
+        assert_eq!(bounds.preceding(25), None);
+        assert_eq!(bounds.following(25), None);
+        assert!(!bounds.is_boundary(25));
+        let bounds = WordBounds::new("naïve");
+        assert_eq!(bounds.preceding(3), None);
+        assert_eq!(bounds.following(3), None);
+        assert!(!bounds.is_boundary(3));
+    }
+
+    #[test]
+    fn test_word_bounds_utf16() {
+        let input: Vec<u16> = "can't stop 32.3".encode_utf16().collect();
+        let words = WordBoundIndices::from_source(&input[..])
+            .map(|(i, w)| (i, String::from_utf16(w).unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            words,
+            &[
+                (0, "can't".to_owned()),
+                (5, " ".to_owned()),
+                (6, "stop".to_owned()),
+                (10, " ".to_owned()),
+                (11, "32.3".to_owned()),
+            ]
+        );
+        let rev = WordBoundIndices::from_source(&input[..])
+            .rev()
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        assert_eq!(rev, &[11, 10, 6, 5, 0]);
+    }
+
+    #[test]
+    fn test_word_bounds_bytes() {
+        let input: &[u8] = b"ab\xFFcd e";
+        assert_eq!(
+            WordBoundIndices::from_source(input).collect::<Vec<(usize, &[u8])>>(),
+            &[
+                (0, &b"ab"[..]),
+                (2, b"\xFF"),
+                (3, b"cd"),
+                (5, b" "),
+                (6, b"e")
+            ]
+        );
+        assert_eq!(
+            WordBounds::from_source(input).rev().collect::<Vec<&[u8]>>(),
+            &[&b"e"[..], b" ", b"cd", b"\xFF", b"ab"]
+        );
+    }
 }
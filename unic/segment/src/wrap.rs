@@ -0,0 +1,285 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Wrapping text into lines of a maximum display width.
+//!
+//! Lines are broken at the [line break opportunities](struct.LineBreaks.html) of the text, with
+//! the width of each grapheme cluster derived from its `East_Asian_Width`. Nothing is hyphenated:
+//! a word that does not fit on a line of its own is broken between grapheme clusters instead.
+
+use unic_emoji_char::{is_emoji, is_emoji_presentation};
+use unic_ucd_category::GeneralCategory as GC;
+use unic_ucd_common::is_white_space;
+use unic_ucd_east_asian_width::EastAsianWidth;
+use unic_ucd_segment::LineBreak;
+
+use crate::grapheme::Graphemes;
+use crate::line::{BreakOpportunity, LineBreaks};
+
+const VARIATION_SELECTOR_16: char = '\u{FE0F}';
+
+/// The number of columns taken by a string on a monospace display.
+///
+/// Each grapheme cluster takes two columns if it is wide, one if it is narrow, and none if it is
+/// a control or a lone mark. Wide clusters are the ones starting with a `Wide` or `Fullwidth`
+/// character, or presented as emoji; `Ambiguous` characters are counted as narrow.
+///
+/// ```rust
+/// # use unic_segment::display_width;
+/// assert_eq!(display_width("abc"), 3);
+/// assert_eq!(display_width("日本語"), 6);
+/// assert_eq!(display_width("e\u{301}"), 1);
+/// assert_eq!(display_width("\u{1F1FA}\u{1F1F3}"), 2);
+/// ```
+pub fn display_width(s: &str) -> usize {
+    Graphemes::new(s).map(grapheme_width).sum()
+}
+
+fn grapheme_width(grapheme: &str) -> usize {
+    let mut chars = grapheme.chars();
+    let first = match chars.next() {
+        Some(ch) => ch,
+        None => return 0,
+    };
+    match GC::of(first) {
+        GC::Control
+        | GC::Format
+        | GC::LineSeparator
+        | GC::ParagraphSeparator
+        | GC::NonspacingMark
+        | GC::EnclosingMark => return 0,
+        _ => {}
+    }
+    match EastAsianWidth::of(first) {
+        EastAsianWidth::Wide | EastAsianWidth::Fullwidth => 2,
+        _ if is_emoji_presentation(first) => 2,
+        _ if is_emoji(first) && chars.any(|ch| ch == VARIATION_SELECTOR_16) => 2,
+        _ => 1,
+    }
+}
+
+/// The line with the newline ending it, if any, removed.
+fn strip_terminator(line: &str) -> &str {
+    if let Some(line) = line.strip_suffix("\r\n") {
+        return line;
+    }
+    match line.chars().next_back() {
+        Some(ch) => match LineBreak::of(ch) {
+            LineBreak::MandatoryBreak
+            | LineBreak::CarriageReturn
+            | LineBreak::LineFeed
+            | LineBreak::NextLine => &line[..line.len() - ch.len_utf8()],
+            _ => line,
+        },
+        None => line,
+    }
+}
+
+/// External iterator for the lines of a string wrapped to a maximum display width, and their
+/// byte offsets.
+///
+/// Each line is broken at the last line break opportunity that keeps it within the maximum width,
+/// as measured by [`display_width`](fn.display_width.html). Whitespace at the end of a line is
+/// kept in it, but not counted in its width. The newline ending a line, if any, is not part of it.
+///
+/// A line can be reordered for display with `unic-bidi`, by passing the line's range, `offset..
+/// offset + line.len()`, to `BidiInfo::reorder_line`.
+///
+/// ```rust
+/// # use unic_segment::WrappedLineIndices;
+/// assert_eq!(
+///     WrappedLineIndices::new("The quick brown fox\njumps", 10).collect::<Vec<(usize, &str)>>(),
+///     &[(0, "The quick "), (10, "brown fox"), (20, "jumps")]
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct WrappedLineIndices<'a> {
+    string: &'a str,
+    max_width: usize,
+    breaks: LineBreaks<'a>,
+
+    /// Offset of the start of the next line.
+    start: usize,
+
+    /// A break opportunity seen but not yet used, because the text before it overflowed a line.
+    pending: Option<(usize, BreakOpportunity)>,
+}
+
+impl<'a> WrappedLineIndices<'a> {
+    /// Create new iterator for *wrapped lines* and their offsets.
+    #[inline]
+    pub fn new(s: &str, max_width: usize) -> WrappedLineIndices<'_> {
+        WrappedLineIndices {
+            string: s,
+            max_width,
+            breaks: LineBreaks::new(s),
+            start: 0,
+            pending: None,
+        }
+    }
+
+    fn line(&mut self, end: usize, opportunity: BreakOpportunity) -> (usize, &'a str) {
+        let start = self.start;
+        self.start = end;
+        let line = &self.string[start..end];
+        match opportunity {
+            BreakOpportunity::Mandatory => (start, strip_terminator(line)),
+            BreakOpportunity::Allowed => (start, line),
+        }
+    }
+}
+
+impl<'a> Iterator for WrappedLineIndices<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<(usize, &'a str)> {
+        // Offset and width of the line up to the last opportunity that fits
+        let mut end = self.start;
+        let mut width = 0;
+        loop {
+            let (offset, opportunity) = self.pending.take().or_else(|| self.breaks.next())?;
+            let segment = &self.string[end..offset];
+            let content = segment.trim_end_matches(is_white_space);
+
+            if width + display_width(content) <= self.max_width {
+                width += display_width(segment);
+                end = offset;
+                if opportunity == BreakOpportunity::Mandatory {
+                    return Some(self.line(end, opportunity));
+                }
+                continue;
+            }
+
+            if end > self.start {
+                self.pending = Some((offset, opportunity));
+                return Some(self.line(end, BreakOpportunity::Allowed));
+            }
+
+            // Emergency break: not even the first segment fits, so break it between grapheme
+            // clusters, keeping at least one on the line.
+            let mut split = 0;
+            for grapheme in Graphemes::new(content) {
+                let grapheme_width = grapheme_width(grapheme);
+                if split > 0 && width + grapheme_width > self.max_width {
+                    break;
+                }
+                width += grapheme_width;
+                split += grapheme.len();
+            }
+            if split == content.len() {
+                return Some(self.line(offset, opportunity));
+            }
+            self.pending = Some((offset, opportunity));
+            return Some(self.line(end + split, BreakOpportunity::Allowed));
+        }
+    }
+}
+
+/// External iterator for the lines of a string wrapped to a maximum display width.
+///
+/// See [`WrappedLineIndices`](struct.WrappedLineIndices.html) for how lines are broken.
+///
+/// ```rust
+/// # use unic_segment::WrappedLines;
+/// assert_eq!(
+///     WrappedLines::new("日本語のテキスト", 6).collect::<Vec<&str>>(),
+///     &["日本語", "のテキ", "スト"]
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct WrappedLines<'a> {
+    iter: WrappedLineIndices<'a>,
+}
+
+impl<'a> WrappedLines<'a> {
+    /// Create new iterator for *wrapped lines*.
+    #[inline]
+    pub fn new(s: &str, max_width: usize) -> WrappedLines<'_> {
+        WrappedLines {
+            iter: WrappedLineIndices::new(s, max_width),
+        }
+    }
+}
+
+impl<'a> Iterator for WrappedLines<'a> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        self.iter.next().map(|(_, line)| line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{display_width, WrappedLineIndices, WrappedLines};
+
+    fn wrap(s: &str, max_width: usize) -> Vec<&str> {
+        WrappedLines::new(s, max_width).collect()
+    }
+
+    #[test]
+    fn test_display_width() {
+        assert_eq!(display_width(""), 0);
+        assert_eq!(display_width("a\tb\r\n"), 2);
+        assert_eq!(display_width("\u{301}"), 0);
+        assert_eq!(display_width("ｱｲ"), 2);
+        assert_eq!(display_width("ＡＢ"), 4);
+        assert_eq!(display_width("\u{B1}"), 1);
+        assert_eq!(
+            display_width("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}"),
+            2
+        );
+        assert_eq!(display_width("\u{2764}"), 1);
+        assert_eq!(display_width("\u{2764}\u{FE0F}"), 2);
+    }
+
+    #[test]
+    fn test_wrap_spaces() {
+        assert_eq!(wrap("", 10), Vec::<&str>::new());
+        assert_eq!(wrap("aaa bbb ccc", 7), &["aaa bbb ", "ccc"]);
+        assert_eq!(wrap("aaa bbb    ccc", 7), &["aaa bbb    ", "ccc"]);
+        assert_eq!(wrap("aaa bbb ccc", 6), &["aaa ", "bbb ", "ccc"]);
+        assert_eq!(wrap("well-known words", 6), &["well-", "known ", "words"]);
+    }
+
+    #[test]
+    fn test_wrap_newlines() {
+        assert_eq!(wrap("a\n\nb\r\n", 10), &["a", "", "b"]);
+        assert_eq!(wrap("aaa bbb\r\nccc", 5), &["aaa ", "bbb", "ccc"]);
+    }
+
+    #[test]
+    fn test_wrap_wide() {
+        assert_eq!(wrap("日本語", 4), &["日本", "語"]);
+        assert_eq!(wrap("日本語", 5), &["日本", "語"]);
+        assert_eq!(wrap("日本。語", 4), &["日", "本。", "語"]);
+    }
+
+    #[test]
+    fn test_wrap_emergency() {
+        assert_eq!(wrap("abcdefg hi", 3), &["abc", "def", "g ", "hi"]);
+        assert_eq!(wrap("ae\u{301}iou", 2), &["ae\u{301}", "io", "u"]);
+        assert_eq!(wrap("日本語", 1), &["日", "本", "語"]);
+        assert_eq!(wrap("a b", 0), &["a ", "b"]);
+    }
+
+    #[test]
+    fn test_wrap_indices() {
+        let s = "aaa bbb\nccc";
+        for (offset, line) in WrappedLineIndices::new(s, 3) {
+            assert_eq!(&s[offset..offset + line.len()], line);
+        }
+        assert_eq!(
+            WrappedLineIndices::new(s, 3).collect::<Vec<(usize, &str)>>(),
+            &[(0, "aaa "), (4, "bbb"), (8, "ccc")]
+        );
+    }
+}
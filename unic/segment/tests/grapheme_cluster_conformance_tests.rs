@@ -42,5 +42,39 @@ fn test_graphemes_conformance() {
         assert!(Graphemes::new_legacy(input)
             .rev()
             .eq(legacy_graphemes.iter().rev().cloned()));
+
+        // test random access at every codepoint boundary
+        test_random_access(&Graphemes::new(input), input, graphemes);
+        test_random_access(&Graphemes::new_legacy(input), input, legacy_graphemes);
+    }
+}
+
+fn test_random_access(iter: &Graphemes, input: &str, graphemes: &[&str]) {
+    let mut boundaries = vec![0];
+    for g in graphemes {
+        boundaries.push(boundaries.last().unwrap() + g.len());
+    }
+    for (offset, _) in input.char_indices().chain(Some((input.len(), ' '))) {
+        assert_eq!(
+            iter.is_boundary(offset),
+            boundaries.contains(&offset),
+            "is_boundary({}) for {:?}",
+            offset,
+            input
+        );
+        assert_eq!(
+            iter.preceding(offset),
+            boundaries.iter().rev().cloned().find(|&b| b < offset),
+            "preceding({}) for {:?}",
+            offset,
+            input
+        );
+        assert_eq!(
+            iter.following(offset),
+            boundaries.iter().cloned().find(|&b| b > offset),
+            "following({}) for {:?}",
+            offset,
+            input
+        );
     }
 }
@@ -0,0 +1,48 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use unic_segment::{BreakOpportunity, LineBreaks};
+
+type TestData = &'static [(&'static str, &'static [&'static str])];
+
+const TEST_DATA: TestData = include!("tables/line_break_test_data.rsv");
+
+#[test]
+fn test_lines_conformance() {
+    for &(input, lines) in TEST_DATA.iter() {
+        // generate offsets from line string lengths
+        let mut offsets = vec![];
+        for i in lines.iter().cloned().map(|s| s.len()).scan(0, |t, n| {
+            *t += n;
+            Some(*t)
+        }) {
+            offsets.push(i);
+        }
+
+        // collect into vector for better diagnostics in failure case
+        let breaks = LineBreaks::new(input).collect::<Vec<_>>();
+        assert_eq!(
+            breaks.iter().map(|&(offset, _)| offset).collect::<Vec<_>>(),
+            offsets,
+            "Line break opportunities test for testcase ({:?}, {:?}) failed.",
+            input,
+            lines
+        );
+
+        // the end of the text is always a mandatory break
+        assert_eq!(
+            breaks.last().map(|&(_, opportunity)| opportunity),
+            Some(BreakOpportunity::Mandatory),
+            "End of text test for testcase ({:?}, {:?}) failed.",
+            input,
+            lines
+        );
+    }
+}
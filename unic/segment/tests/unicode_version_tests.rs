@@ -0,0 +1,27 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use unic_segment;
+use unic_ucd_category;
+use unic_ucd_east_asian_width;
+
+#[test]
+fn test_version_against_data_versions() {
+    // Line breaking reads General_Category and East_Asian_Width along with the segmentation
+    // properties, so they all come from the same version of the UCD.
+    assert_eq!(
+        unic_ucd_category::UNICODE_VERSION,
+        unic_segment::UNICODE_VERSION
+    );
+    assert_eq!(
+        unic_ucd_east_asian_width::UNICODE_VERSION,
+        unic_segment::UNICODE_VERSION
+    );
+}
@@ -9,7 +9,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use unic_segment::{WordBoundIndices, WordBounds};
+use unic_segment::{WordBoundIndices, WordBounds, WordCursor, WordIncomplete};
 
 type TestData = &'static [(&'static str, &'static [&'static str])];
 
@@ -74,5 +74,99 @@ fn test_words_conformance() {
             indices.iter().rev().cloned(),
             "Reverse word indices"
         );
+
+        // test cursor over one chunk per character, forward and backward
+        let mut boundaries = indices.clone();
+        boundaries.push(input.len());
+        assert_!(
+            cursor_next_boundaries(input).into_iter(),
+            boundaries[1..].iter().cloned(),
+            "Forward word cursor"
+        );
+        assert_!(
+            cursor_prev_boundaries(input).into_iter(),
+            boundaries[..boundaries.len() - 1].iter().rev().cloned(),
+            "Reverse word cursor"
+        );
+
+        // test random access at every codepoint boundary
+        let bounds = WordBounds::new(input);
+        for (offset, _) in input.char_indices().chain(Some((input.len(), ' '))) {
+            assert_eq!(
+                bounds.is_boundary(offset),
+                boundaries.contains(&offset),
+                "is_boundary({}) for testcase ({:?}, {:?}) failed.",
+                offset,
+                input,
+                words
+            );
+            assert_eq!(
+                bounds.preceding(offset),
+                boundaries.iter().rev().cloned().find(|&b| b < offset),
+                "preceding({}) for testcase ({:?}, {:?}) failed.",
+                offset,
+                input,
+                words
+            );
+            assert_eq!(
+                bounds.following(offset),
+                boundaries.iter().cloned().find(|&b| b > offset),
+                "following({}) for testcase ({:?}, {:?}) failed.",
+                offset,
+                input,
+                words
+            );
+        }
+    }
+}
+
+fn char_chunks(input: &str) -> Vec<(usize, &str)> {
+    input
+        .char_indices()
+        .map(|(i, ch)| (i, &input[i..i + ch.len_utf8()]))
+        .collect()
+}
+
+fn provide_context(cursor: &mut WordCursor, chunks: &[(usize, &str)], err: WordIncomplete) {
+    let &(start, chunk) = match err {
+        WordIncomplete::PreContext(end) => chunks
+            .iter()
+            .find(|&&(start, chunk)| start + chunk.len() == end)
+            .unwrap(),
+        WordIncomplete::PostContext(start) => chunks.iter().find(|c| c.0 == start).unwrap(),
+        err => panic!("unexpected {:?}", err),
+    };
+    cursor.provide_context(chunk, start);
+}
+
+fn cursor_next_boundaries(input: &str) -> Vec<usize> {
+    let chunks = char_chunks(input);
+    let mut cursor = WordCursor::new(0, input.len());
+    let mut boundaries = vec![];
+    let mut index = 0;
+    loop {
+        let (start, chunk) = chunks[index];
+        match cursor.next_boundary(chunk, start) {
+            Ok(Some(boundary)) => boundaries.push(boundary),
+            Ok(None) => return boundaries,
+            Err(WordIncomplete::NextChunk) => index += 1,
+            Err(err) => provide_context(&mut cursor, &chunks, err),
+        }
+    }
+}
+
+fn cursor_prev_boundaries(input: &str) -> Vec<usize> {
+    let chunks = char_chunks(input);
+    let mut cursor = WordCursor::new(input.len(), input.len());
+    let mut boundaries = vec![];
+    let mut index = chunks.len() - 1;
+    loop {
+        let (start, chunk) = chunks[index];
+        match cursor.prev_boundary(chunk, start) {
+            Ok(Some(boundary)) => boundaries.push(boundary),
+            Ok(None) => return boundaries,
+            Err(WordIncomplete::PrevChunk) => index -= 1,
+            Err(err) => provide_context(&mut cursor, &chunks, err),
+        }
     }
 }
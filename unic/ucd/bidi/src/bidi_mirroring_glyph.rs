@@ -0,0 +1,63 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `Bidi_Mirroring_Glyph` Character Property.
+//!
+//! ## References
+//!
+//! * <https://www.unicode.org/reports/tr44/#Bidi_Mirroring_Glyph>
+//! * <https://www.unicode.org/reports/tr9/#L4>
+
+mod data {
+    use unic_char_property::tables::CharDataTable;
+    pub const BIDI_MIRRORING_GLYPH_TABLE: CharDataTable<char> =
+        include!("../tables/bidi_mirroring_glyph.rsv");
+}
+
+/// The [`Bidi_Mirroring_Glyph`](https://www.unicode.org/reports/tr44/#Bidi_Mirroring_Glyph) of
+/// the character: another character whose glyph is the mirror image of its glyph.
+///
+/// Returns `None` if the character is not mirrored in bidirectional context, or if there is no
+/// character that could stand for its mirrored glyph.
+pub fn bidi_mirroring_glyph(ch: char) -> Option<char> {
+    data::BIDI_MIRRORING_GLYPH_TABLE.find(ch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::bidi_mirroring_glyph;
+    use crate::is_bidi_mirrored;
+
+    #[test]
+    fn test_values() {
+        assert_eq!(bidi_mirroring_glyph('a'), None);
+        assert_eq!(bidi_mirroring_glyph('('), Some(')'));
+        assert_eq!(bidi_mirroring_glyph(')'), Some('('));
+        assert_eq!(bidi_mirroring_glyph('<'), Some('>'));
+        assert_eq!(bidi_mirroring_glyph('\u{AB}'), Some('\u{BB}'));
+        assert_eq!(bidi_mirroring_glyph('\u{2208}'), Some('\u{220B}'));
+        assert_eq!(bidi_mirroring_glyph('\u{2264}'), Some('\u{2265}'));
+        assert_eq!(bidi_mirroring_glyph('\u{FF62}'), Some('\u{FF63}'));
+
+        // Mirrored, but without a mirroring glyph
+        assert!(is_bidi_mirrored('\u{2211}'));
+        assert_eq!(bidi_mirroring_glyph('\u{2211}'), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for ch in (0..0x11_0000).filter_map(core::char::from_u32) {
+            if let Some(glyph) = bidi_mirroring_glyph(ch) {
+                assert!(is_bidi_mirrored(ch));
+                assert_eq!(bidi_mirroring_glyph(glyph), Some(ch));
+            }
+        }
+    }
+}
@@ -0,0 +1,142 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `Bidi_Paired_Bracket_Type` Character Property.
+//!
+//! ## References
+//!
+//! * <https://www.unicode.org/reports/tr44/#Bidi_Paired_Bracket_Type>
+//! * <https://www.unicode.org/reports/tr9/#BD14>
+
+use unic_char_property::TotalCharProperty;
+
+char_property! {
+    /// Represents the Unicode character
+    /// [`Bidi_Paired_Bracket_Type`](https://www.unicode.org/reports/tr44/#Bidi_Paired_Bracket_Type)
+    /// property.
+    ///
+    /// The opening and closing paired brackets are matched up by the Unicode Bidirectional
+    /// Algorithm, using the [`Bidi_Paired_Bracket`](fn.bidi_paired_bracket.html) of each.
+    ///
+    /// * <https://www.unicode.org/reports/tr9/#BD14>
+    /// * <https://www.unicode.org/reports/tr9/#BD15>
+    pub enum BidiPairedBracketType {
+        abbr => "bpt";
+        long => "Bidi_Paired_Bracket_Type";
+        human => "Bidirectional Paired Bracket Type";
+
+        /// An opening paired bracket
+        Open {
+            abbr => o,
+            long => Open,
+            human => "Open",
+        }
+
+        /// A closing paired bracket
+        Close {
+            abbr => c,
+            long => Close,
+            human => "Close",
+        }
+
+        /// Not a paired bracket
+        None {
+            abbr => n,
+            long => None,
+            human => "None",
+        }
+    }
+
+    /// Abbreviated name aliases for the
+    /// [`Bidi_Paired_Bracket_Type`](https://www.unicode.org/reports/tr44/#Bidi_Paired_Bracket_Type)
+    /// property.
+    pub mod abbr_names for abbr;
+
+    /// Long name aliases for the
+    /// [`Bidi_Paired_Bracket_Type`](https://www.unicode.org/reports/tr44/#Bidi_Paired_Bracket_Type)
+    /// property.
+    pub mod long_names for long;
+}
+
+impl TotalCharProperty for BidiPairedBracketType {
+    fn of(ch: char) -> Self {
+        Self::of(ch)
+    }
+}
+
+impl Default for BidiPairedBracketType {
+    #[inline]
+    fn default() -> Self {
+        BidiPairedBracketType::None
+    }
+}
+
+mod data {
+    use super::abbr_names as BPT;
+    use unic_char_property::tables::CharDataTable;
+    pub const BIDI_PAIRED_BRACKET_TYPE_TABLE: CharDataTable<super::BidiPairedBracketType> =
+        include!("../tables/bidi_paired_bracket_type.rsv");
+
+    pub const BIDI_PAIRED_BRACKET_TABLE: CharDataTable<char> =
+        include!("../tables/bidi_paired_bracket.rsv");
+}
+
+impl BidiPairedBracketType {
+    /// Find the character `Bidi_Paired_Bracket_Type` property value.
+    pub fn of(ch: char) -> BidiPairedBracketType {
+        data::BIDI_PAIRED_BRACKET_TYPE_TABLE.find_or_default(ch)
+    }
+}
+
+/// The [`Bidi_Paired_Bracket`](https://www.unicode.org/reports/tr44/#Bidi_Paired_Bracket) of the
+/// character: the closing bracket of an opening paired bracket, and the other way around.
+///
+/// Returns `None` if the character is not a paired bracket.
+pub fn bidi_paired_bracket(ch: char) -> Option<char> {
+    data::BIDI_PAIRED_BRACKET_TABLE.find(ch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bidi_paired_bracket, BidiPairedBracketType as BPT};
+
+    #[test]
+    fn test_values() {
+        assert_eq!(BPT::of('a'), BPT::None);
+        assert_eq!(BPT::of('('), BPT::Open);
+        assert_eq!(BPT::of(')'), BPT::Close);
+        assert_eq!(BPT::of('<'), BPT::None);
+        assert_eq!(BPT::of('\u{2329}'), BPT::Open);
+        assert_eq!(BPT::of('\u{3009}'), BPT::Close);
+        assert_eq!(BPT::of('\u{FF63}'), BPT::Close);
+        assert_eq!(BPT::of('\u{10FFFF}'), BPT::None);
+    }
+
+    #[test]
+    fn test_pairs() {
+        assert_eq!(bidi_paired_bracket('a'), None);
+        assert_eq!(bidi_paired_bracket('<'), None);
+        assert_eq!(bidi_paired_bracket('('), Some(')'));
+        assert_eq!(bidi_paired_bracket(']'), Some('['));
+        assert_eq!(bidi_paired_bracket('{'), Some('}'));
+        assert_eq!(bidi_paired_bracket('\u{298D}'), Some('\u{2990}'));
+        assert_eq!(bidi_paired_bracket('\u{298E}'), Some('\u{298F}'));
+        assert_eq!(bidi_paired_bracket('\u{2329}'), Some('\u{232A}'));
+        assert_eq!(bidi_paired_bracket('\u{3008}'), Some('\u{3009}'));
+    }
+
+    #[test]
+    fn test_abbr_name() {
+        use unic_char_property::EnumeratedCharProperty;
+
+        assert_eq!(BPT::Open.abbr_name(), "o");
+        assert_eq!(BPT::Close.long_name(), "Close");
+    }
+}
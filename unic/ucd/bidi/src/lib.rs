@@ -42,6 +42,12 @@ pub use crate::bidi_control::{is_bidi_control, BidiControl};
 pub mod bidi_mirrored;
 pub use crate::bidi_mirrored::{is_bidi_mirrored, BidiMirrored};
 
+pub mod bidi_mirroring_glyph;
+pub use crate::bidi_mirroring_glyph::bidi_mirroring_glyph;
+
+pub mod bidi_paired_bracket_type;
+pub use crate::bidi_paired_bracket_type::{bidi_paired_bracket, BidiPairedBracketType};
+
 use unic_ucd_version::UnicodeVersion;
 
 /// The [Unicode version](https://www.unicode.org/versions/) of data
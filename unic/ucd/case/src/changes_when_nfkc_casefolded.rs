@@ -0,0 +1,67 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `Changes_When_NFKC_Casefolded` Character Property.
+
+char_property! {
+    /// Represents values of the Unicode character property
+    /// [`Changes_When_NFKC_Casefolded`](https://www.unicode.org/reports/tr44/#CWKCF).
+    ///
+    /// The value is `true` for characters that change under NFKC_Casefold mapping (case folding,
+    /// NFKC normalization, and removal of default ignorable code points), `false` otherwise.
+    pub struct ChangesWhenNFKCCasefolded(bool) {
+        abbr => "CWKCF";
+        long => "Changes_When_NFKC_Casefolded";
+        human => "Changes When NFKC Casefolded";
+
+        data_table_path => "../tables/changes_when_nfkc_casefolded.rsv";
+    }
+
+    /// Return `true` for characters that change under NFKC_Casefold mapping, `false` otherwise.
+    pub fn changes_when_nfkc_casefolded(char) -> bool;
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_values() {
+        use super::changes_when_nfkc_casefolded;
+
+        // ASCII
+        assert_eq!(changes_when_nfkc_casefolded('\u{0020}'), false);
+        assert_eq!(changes_when_nfkc_casefolded('\u{0030}'), false);
+        assert_eq!(changes_when_nfkc_casefolded('\u{0040}'), false);
+        assert_eq!(changes_when_nfkc_casefolded('\u{0041}'), true);
+        assert_eq!(changes_when_nfkc_casefolded('\u{005a}'), true);
+        assert_eq!(changes_when_nfkc_casefolded('\u{0061}'), false);
+        assert_eq!(changes_when_nfkc_casefolded('\u{007f}'), false);
+
+        // Other BMP
+        assert_eq!(changes_when_nfkc_casefolded('\u{00a0}'), true);
+        assert_eq!(changes_when_nfkc_casefolded('\u{00ad}'), true);
+        assert_eq!(changes_when_nfkc_casefolded('\u{00df}'), true);
+        assert_eq!(changes_when_nfkc_casefolded('\u{00e9}'), false);
+        assert_eq!(changes_when_nfkc_casefolded('\u{200b}'), true);
+        assert_eq!(changes_when_nfkc_casefolded('\u{2126}'), true);
+        assert_eq!(changes_when_nfkc_casefolded('\u{4e00}'), false);
+        assert_eq!(changes_when_nfkc_casefolded('\u{ff21}'), true);
+
+        // Other Planes
+        assert_eq!(changes_when_nfkc_casefolded('\u{10000}'), false);
+        assert_eq!(changes_when_nfkc_casefolded('\u{1d400}'), true);
+        assert_eq!(changes_when_nfkc_casefolded('\u{20000}'), false);
+        assert_eq!(changes_when_nfkc_casefolded('\u{e0001}'), true);
+        assert_eq!(changes_when_nfkc_casefolded('\u{e0fff}'), true);
+
+        // Private-Use Area
+        assert_eq!(changes_when_nfkc_casefolded('\u{f0000}'), false);
+        assert_eq!(changes_when_nfkc_casefolded('\u{10ffff}'), false);
+    }
+}
@@ -60,6 +60,12 @@ pub use crate::changes_when_casefolded::{changes_when_casefolded, ChangesWhenCas
 pub mod changes_when_casemapped;
 pub use crate::changes_when_casemapped::{changes_when_casemapped, ChangesWhenCasemapped};
 
+pub mod changes_when_nfkc_casefolded;
+pub use crate::changes_when_nfkc_casefolded::{
+    changes_when_nfkc_casefolded,
+    ChangesWhenNFKCCasefolded,
+};
+
 use unic_ucd_version::UnicodeVersion;
 
 /// The [Unicode version](https://www.unicode.org/versions/) of data
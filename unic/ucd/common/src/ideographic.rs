@@ -0,0 +1,65 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `Ideographic` Character Property.
+
+char_property! {
+    /// Represents values of the Unicode character property
+    /// [*Ideographic*](https://www.unicode.org/reports/tr44/#Ideographic).
+    ///
+    /// The value is `true` for CJKV ideographs and related characters, `false` otherwise.
+    pub struct Ideographic(bool) {
+        abbr => "Ideo";
+        long => "Ideographic";
+        human => "Ideographic";
+
+        data_table_path => "../tables/ideographic.rsv";
+    }
+
+    /// Return `true` for ideographic characters, `false` otherwise.
+    pub fn is_ideographic(char) -> bool;
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_values() {
+        use super::is_ideographic;
+
+        // ASCII
+        assert_eq!(is_ideographic('\u{0020}'), false);
+        assert_eq!(is_ideographic('\u{0041}'), false);
+
+        // CJK Symbols and Punctuation
+        assert_eq!(is_ideographic('\u{3005}'), false);
+        assert_eq!(is_ideographic('\u{3006}'), true);
+        assert_eq!(is_ideographic('\u{3007}'), true);
+        assert_eq!(is_ideographic('\u{3008}'), false);
+
+        // Kana
+        assert_eq!(is_ideographic('\u{3042}'), false);
+        assert_eq!(is_ideographic('\u{30a2}'), false);
+
+        // CJK Unified Ideographs
+        assert_eq!(is_ideographic('\u{4e00}'), true);
+        assert_eq!(is_ideographic('\u{9fea}'), true);
+        assert_eq!(is_ideographic('\u{9feb}'), false);
+
+        // CJK Compatibility Ideographs
+        assert_eq!(is_ideographic('\u{f900}'), true);
+
+        // Other Planes
+        assert_eq!(is_ideographic('\u{17000}'), true);
+        assert_eq!(is_ideographic('\u{20000}'), true);
+        assert_eq!(is_ideographic('\u{2fa1d}'), true);
+        assert_eq!(is_ideographic('\u{2fa1e}'), false);
+        assert_eq!(is_ideographic('\u{e0000}'), false);
+    }
+}
@@ -40,6 +40,9 @@ pub use crate::alphabetic::{is_alphabetic, Alphabetic};
 pub mod white_space;
 pub use crate::white_space::{is_white_space, WhiteSpace};
 
+pub mod ideographic;
+pub use crate::ideographic::{is_ideographic, Ideographic};
+
 // == Non-UCD-defined: methods only ==
 
 pub mod alphanumeric;
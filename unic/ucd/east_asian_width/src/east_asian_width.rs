@@ -0,0 +1,194 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `East_Asian_Width` Character Property.
+//!
+//! ## References
+//!
+//! * <https://www.unicode.org/reports/tr44/#East_Asian_Width>
+//! * <https://www.unicode.org/reports/tr11/>
+
+use unic_char_property::TotalCharProperty;
+
+char_property! {
+    /// Represents the Unicode character
+    /// [`East_Asian_Width`](https://www.unicode.org/reports/tr44/#East_Asian_Width) property.
+    ///
+    /// ## References
+    ///
+    /// * <https://www.unicode.org/reports/tr44/#East_Asian_Width>
+    /// * <https://www.unicode.org/reports/tr11/#ED1>
+    pub enum EastAsianWidth {
+        abbr => "ea";
+        long => "East_Asian_Width";
+        human => "East Asian Width";
+
+        /// Characters that are wide in East Asian legacy character sets and narrow elsewhere,
+        /// such as U+00B1 PLUS-MINUS SIGN.
+        Ambiguous {
+            abbr => A,
+            long => Ambiguous,
+            human => "Ambiguous",
+        }
+
+        /// Compatibility characters with a narrow counterpart, such as U+FF21 FULLWIDTH LATIN
+        /// CAPITAL LETTER A.
+        Fullwidth {
+            abbr => F,
+            long => Fullwidth,
+            human => "Fullwidth",
+        }
+
+        /// Compatibility characters with a wide counterpart, such as U+FF71 HALFWIDTH KATAKANA
+        /// LETTER A.
+        Halfwidth {
+            abbr => H,
+            long => Halfwidth,
+            human => "Halfwidth",
+        }
+
+        /// Characters that do not occur in East Asian legacy character sets.
+        Neutral {
+            abbr => N,
+            long => Neutral,
+            human => "Neutral",
+        }
+
+        /// Characters with a wide counterpart, such as U+0041 LATIN CAPITAL LETTER A.
+        Narrow {
+            abbr => Na,
+            long => Narrow,
+            human => "Narrow",
+        }
+
+        /// Characters that are always wide, such as CJK ideographs.
+        Wide {
+            abbr => W,
+            long => Wide,
+            human => "Wide",
+        }
+    }
+
+    /// Abbreviated name aliases for the
+    /// [`East_Asian_Width`](https://www.unicode.org/reports/tr44/#East_Asian_Width)
+    /// property.
+    pub mod abbr_names for abbr;
+
+    /// Long name aliases for the
+    /// [`East_Asian_Width`](https://www.unicode.org/reports/tr44/#East_Asian_Width)
+    /// property.
+    pub mod long_names for long;
+}
+
+impl TotalCharProperty for EastAsianWidth {
+    fn of(ch: char) -> Self {
+        Self::of(ch)
+    }
+}
+
+/// UCD/EastAsianWidth.txt: "All code points, assigned or unassigned, that are not listed
+/// explicitly are given the value N."
+impl Default for EastAsianWidth {
+    #[inline]
+    fn default() -> Self {
+        EastAsianWidth::Neutral
+    }
+}
+
+mod data {
+    use super::abbr_names::*;
+    use unic_char_property::tables::CharDataTable;
+    pub const EAST_ASIAN_WIDTH_TABLE: CharDataTable<super::EastAsianWidth> =
+        include!("../tables/east_asian_width.rsv");
+}
+
+impl EastAsianWidth {
+    /// Find the character `East_Asian_Width` property value.
+    pub fn of(ch: char) -> EastAsianWidth {
+        data::EAST_ASIAN_WIDTH_TABLE.find_or_default(ch)
+    }
+
+    /// Whether the character takes two columns in East Asian typography, regardless of
+    /// context: `Wide` or `Fullwidth`.
+    #[inline]
+    pub fn is_wide(self) -> bool {
+        self == EastAsianWidth::Wide || self == EastAsianWidth::Fullwidth
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EastAsianWidth as EA;
+    use unic_char_property::EnumeratedCharProperty;
+
+    #[test]
+    fn test_values() {
+        // ASCII
+        assert_eq!(EA::of('\u{0000}'), EA::Neutral);
+        assert_eq!(EA::of('\u{0041}'), EA::Narrow);
+        assert_eq!(EA::of('\u{0020}'), EA::Narrow);
+
+        // Latin-1 Supplement
+        assert_eq!(EA::of('\u{00A1}'), EA::Ambiguous);
+        assert_eq!(EA::of('\u{00B1}'), EA::Ambiguous);
+        assert_eq!(EA::of('\u{00E2}'), EA::Neutral);
+
+        // Combining marks
+        assert_eq!(EA::of('\u{0300}'), EA::Ambiguous);
+
+        // CJK
+        assert_eq!(EA::of('\u{3000}'), EA::Fullwidth);
+        assert_eq!(EA::of('\u{3042}'), EA::Wide);
+        assert_eq!(EA::of('\u{4E00}'), EA::Wide);
+        assert_eq!(EA::of('\u{AC00}'), EA::Wide);
+
+        // Halfwidth and Fullwidth Forms
+        assert_eq!(EA::of('\u{FF21}'), EA::Fullwidth);
+        assert_eq!(EA::of('\u{FF71}'), EA::Halfwidth);
+
+        // Emoji
+        assert_eq!(EA::of('\u{231A}'), EA::Wide);
+        assert_eq!(EA::of('\u{1F600}'), EA::Wide);
+        assert_eq!(EA::of('\u{2764}'), EA::Neutral);
+
+        // Unassigned in CJK blocks
+        assert_eq!(EA::of('\u{2FFFD}'), EA::Wide);
+        assert_eq!(EA::of('\u{E0000}'), EA::Neutral);
+
+        // Private use
+        assert_eq!(EA::of('\u{E000}'), EA::Ambiguous);
+    }
+
+    #[test]
+    fn test_is_wide() {
+        assert!(EA::Wide.is_wide());
+        assert!(EA::Fullwidth.is_wide());
+        assert!(!EA::Ambiguous.is_wide());
+        assert!(!EA::Halfwidth.is_wide());
+        assert!(!EA::Narrow.is_wide());
+    }
+
+    #[test]
+    fn test_abbr_name() {
+        assert_eq!(EA::Ambiguous.abbr_name(), "A");
+        assert_eq!(EA::Narrow.abbr_name(), "Na");
+    }
+
+    #[test]
+    fn test_long_name() {
+        assert_eq!(EA::Fullwidth.long_name(), "Fullwidth");
+        assert_eq!(EA::Neutral.long_name(), "Neutral");
+    }
+
+    #[test]
+    fn test_human_name() {
+        assert_eq!(EA::Halfwidth.human_name(), "Halfwidth");
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `Joining_Type` Character Property.
+//!
+//! ## References
+//!
+//! * <https://www.unicode.org/reports/tr44/#Joining_Type>
+//! * <https://www.unicode.org/versions/Unicode10.0.0/ch09.pdf#G7462>
+
+use unic_char_property::TotalCharProperty;
+
+char_property! {
+    /// Represents the Unicode character
+    /// [`Joining_Type`](https://www.unicode.org/reports/tr44/#Joining_Type) property.
+    ///
+    /// This is the type of cursive joining of a character with its neighbors, in scripts such as
+    /// Arabic and Syriac.
+    ///
+    /// ## References
+    ///
+    /// * <https://www.unicode.org/reports/tr44/#Joining_Type>
+    /// * <https://www.unicode.org/versions/Unicode10.0.0/ch09.pdf#G7462>
+    pub enum JoiningType {
+        abbr => "jt";
+        long => "Joining_Type";
+        human => "Joining Type";
+
+        /// Characters that cause joining of their neighbors, such as U+200D ZERO WIDTH JOINER.
+        JoinCausing {
+            abbr => C,
+            long => Join_Causing,
+            human => "Join Causing",
+        }
+
+        /// Characters that join on both sides, such as U+0628 ARABIC LETTER BEH.
+        DualJoining {
+            abbr => D,
+            long => Dual_Joining,
+            human => "Dual Joining",
+        }
+
+        /// Characters that join only with the character on their left, in visual order.
+        LeftJoining {
+            abbr => L,
+            long => Left_Joining,
+            human => "Left Joining",
+        }
+
+        /// Characters that join only with the character on their right, in visual order, such as
+        /// U+0627 ARABIC LETTER ALEF.
+        RightJoining {
+            abbr => R,
+            long => Right_Joining,
+            human => "Right Joining",
+        }
+
+        /// Characters that are skipped over when joining their neighbors, such as most
+        /// combining marks.
+        Transparent {
+            abbr => T,
+            long => Transparent,
+            human => "Transparent",
+        }
+
+        /// Characters that do not join, such as U+0020 SPACE and U+200C ZERO WIDTH NON-JOINER.
+        NonJoining {
+            abbr => U,
+            long => Non_Joining,
+            human => "Non Joining",
+        }
+    }
+
+    /// Abbreviated name aliases for the
+    /// [`Joining_Type`](https://www.unicode.org/reports/tr44/#Joining_Type)
+    /// property.
+    pub mod abbr_names for abbr;
+
+    /// Long name aliases for the
+    /// [`Joining_Type`](https://www.unicode.org/reports/tr44/#Joining_Type)
+    /// property.
+    pub mod long_names for long;
+}
+
+impl TotalCharProperty for JoiningType {
+    fn of(ch: char) -> Self {
+        Self::of(ch)
+    }
+}
+
+/// UCD/extracted/DerivedJoiningType.txt: "All code points not explicitly listed for Joining_Type
+/// have the value Non_Joining (U)."
+impl Default for JoiningType {
+    #[inline]
+    fn default() -> Self {
+        JoiningType::NonJoining
+    }
+}
+
+mod data {
+    use super::abbr_names::*;
+    use unic_char_property::tables::CharDataTable;
+    pub const JOINING_TYPE_TABLE: CharDataTable<super::JoiningType> =
+        include!("../tables/joining_type.rsv");
+}
+
+impl JoiningType {
+    /// Find the character `Joining_Type` property value.
+    pub fn of(ch: char) -> JoiningType {
+        data::JOINING_TYPE_TABLE.find_or_default(ch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JoiningType as JT;
+    use unic_char_property::EnumeratedCharProperty;
+
+    #[test]
+    fn test_values() {
+        // ASCII
+        assert_eq!(JT::of('\u{0000}'), JT::NonJoining);
+        assert_eq!(JT::of('\u{0041}'), JT::NonJoining);
+
+        // Arabic
+        assert_eq!(JT::of('\u{0627}'), JT::RightJoining);
+        assert_eq!(JT::of('\u{0628}'), JT::DualJoining);
+        assert_eq!(JT::of('\u{0640}'), JT::JoinCausing);
+        assert_eq!(JT::of('\u{064B}'), JT::Transparent);
+
+        // Syriac
+        assert_eq!(JT::of('\u{0710}'), JT::RightJoining);
+        assert_eq!(JT::of('\u{0712}'), JT::DualJoining);
+
+        // Mongolian
+        assert_eq!(JT::of('\u{1807}'), JT::DualJoining);
+
+        // Manichaean
+        assert_eq!(JT::of('\u{10ACD}'), JT::LeftJoining);
+
+        // Syriac Supplement, new in Unicode 10.0
+        assert_eq!(JT::of('\u{0860}'), JT::DualJoining);
+
+        // Joiners
+        assert_eq!(JT::of('\u{200C}'), JT::NonJoining);
+        assert_eq!(JT::of('\u{200D}'), JT::JoinCausing);
+
+        // Combining marks and format characters
+        assert_eq!(JT::of('\u{0300}'), JT::Transparent);
+        assert_eq!(JT::of('\u{00AD}'), JT::Transparent);
+
+        // Unassigned
+        assert_eq!(JT::of('\u{10FFFF}'), JT::NonJoining);
+    }
+
+    #[test]
+    fn test_abbr_name() {
+        assert_eq!(JT::DualJoining.abbr_name(), "D");
+        assert_eq!(JT::NonJoining.abbr_name(), "U");
+    }
+
+    #[test]
+    fn test_long_name() {
+        assert_eq!(JT::JoinCausing.long_name(), "Join_Causing");
+        assert_eq!(JT::Transparent.long_name(), "Transparent");
+    }
+
+    #[test]
+    fn test_human_name() {
+        assert_eq!(JT::RightJoining.human_name(), "Right Joining");
+    }
+}
@@ -11,6 +11,8 @@
 
 use unic_char_property::tables::CharDataTable;
 
+use crate::DecompositionType;
+
 pub mod data {
     use crate::decomposition_type::long_names::*;
     use crate::DecompositionType;
@@ -43,3 +45,43 @@ pub fn compatibility_decomposition(c: char) -> Option<&'static [char]> {
         .find(c)
         .map(|it| it.1)
 }
+
+/// Raw `Decomposition_Mapping` of the character, together with its `Decomposition_Type`.
+///
+/// The mapping is returned as listed in the UCD, without recursive application. Hangul syllables
+/// are decomposed algorithmically and have no mapping here; use `decompose_canonical` for those.
+pub fn decomposition_mapping(c: char) -> Option<(DecompositionType, &'static [char])> {
+    canonical_decomposition(c)
+        .map(|mapping| (DecompositionType::Canonical, mapping))
+        .or_else(|| data::COMPATIBILITY_DECOMPOSITION_MAPPING.find(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decomposition_mapping;
+    use crate::DecompositionType;
+
+    #[test]
+    fn test_decomposition_mapping() {
+        assert_eq!(decomposition_mapping('A'), None);
+        assert_eq!(
+            decomposition_mapping('\u{00c5}'),
+            Some((DecompositionType::Canonical, &['A', '\u{030a}'][..]))
+        );
+        // Singleton, not applied recursively
+        assert_eq!(
+            decomposition_mapping('\u{212b}'),
+            Some((DecompositionType::Canonical, &['\u{00c5}'][..]))
+        );
+        assert_eq!(
+            decomposition_mapping('\u{fb01}'),
+            Some((DecompositionType::Compat, &['f', 'i'][..]))
+        );
+        assert_eq!(
+            decomposition_mapping('\u{00bd}'),
+            Some((DecompositionType::Fraction, &['1', '\u{2044}', '2'][..]))
+        );
+        // Hangul syllables are algorithmic
+        assert_eq!(decomposition_mapping('\u{ac00}'), None);
+    }
+}
@@ -0,0 +1,45 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `Composition_Exclusion` Character Property.
+
+char_property! {
+    /// Represents values of the Unicode character property
+    /// [`Composition_Exclusion`](https://www.unicode.org/reports/tr44/#Composition_Exclusion).
+    ///
+    /// The value is `true` for characters explicitly listed in `CompositionExclusions.txt`, `false`
+    /// otherwise.
+    pub struct CompositionExclusion(bool) {
+        abbr => "CE";
+        long => "Composition_Exclusion";
+        human => "Composition Exclusion";
+
+        data_table_path => "../tables/composition_exclusion.rsv";
+    }
+
+    /// Return `true` for characters explicitly excluded from composition, `false` otherwise.
+    pub fn composition_exclusion(char) -> bool;
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_values() {
+        use super::composition_exclusion;
+
+        assert_eq!(composition_exclusion('\u{0041}'), false);
+        assert_eq!(composition_exclusion('\u{0958}'), true);
+        assert_eq!(composition_exclusion('\u{0344}'), false);
+        assert_eq!(composition_exclusion('\u{2adc}'), true);
+        assert_eq!(composition_exclusion('\u{fb1d}'), true);
+        assert_eq!(composition_exclusion('\u{2f800}'), false);
+        assert_eq!(composition_exclusion('\u{1d15e}'), true);
+    }
+}
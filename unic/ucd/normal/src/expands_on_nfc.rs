@@ -0,0 +1,44 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `Expands_On_NFC` Character Property.
+
+char_property! {
+    /// Represents values of the Unicode character property
+    /// [`Expands_On_NFC`](https://www.unicode.org/reports/tr44/#Expands_On_NFC).
+    ///
+    /// The value is `true` for characters whose NFC form contains more than one character, `false`
+    /// otherwise.
+    pub struct ExpandsOnNFC(bool) {
+        abbr => "XO_NFC";
+        long => "Expands_On_NFC";
+        human => "Expands On NFC";
+
+        data_table_path => "../tables/expands_on_nfc.rsv";
+    }
+
+    /// Return `true` for characters that expand to more than one character under NFC, `false` otherwise.
+    pub fn expands_on_nfc(char) -> bool;
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_values() {
+        use super::expands_on_nfc;
+
+        assert_eq!(expands_on_nfc('\u{0041}'), false);
+        assert_eq!(expands_on_nfc('\u{00c5}'), false);
+        assert_eq!(expands_on_nfc('\u{0344}'), true);
+        assert_eq!(expands_on_nfc('\u{0958}'), true);
+        assert_eq!(expands_on_nfc('\u{ac00}'), false);
+        assert_eq!(expands_on_nfc('\u{fb01}'), false);
+    }
+}
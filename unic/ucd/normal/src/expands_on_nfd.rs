@@ -0,0 +1,44 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `Expands_On_NFD` Character Property.
+
+char_property! {
+    /// Represents values of the Unicode character property
+    /// [`Expands_On_NFD`](https://www.unicode.org/reports/tr44/#Expands_On_NFD).
+    ///
+    /// The value is `true` for characters whose NFD form contains more than one character, `false`
+    /// otherwise.
+    pub struct ExpandsOnNFD(bool) {
+        abbr => "XO_NFD";
+        long => "Expands_On_NFD";
+        human => "Expands On NFD";
+
+        data_table_path => "../tables/expands_on_nfd.rsv";
+    }
+
+    /// Return `true` for characters that expand to more than one character under NFD, `false` otherwise.
+    pub fn expands_on_nfd(char) -> bool;
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_values() {
+        use super::expands_on_nfd;
+
+        assert_eq!(expands_on_nfd('\u{0041}'), false);
+        assert_eq!(expands_on_nfd('\u{00c5}'), true);
+        assert_eq!(expands_on_nfd('\u{212b}'), true);
+        assert_eq!(expands_on_nfd('\u{ac00}'), true);
+        assert_eq!(expands_on_nfd('\u{fb01}'), false);
+        assert_eq!(expands_on_nfd('\u{1d15e}'), true);
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `Expands_On_NFKC` Character Property.
+
+char_property! {
+    /// Represents values of the Unicode character property
+    /// [`Expands_On_NFKC`](https://www.unicode.org/reports/tr44/#Expands_On_NFKC).
+    ///
+    /// The value is `true` for characters whose NFKC form contains more than one character, `false`
+    /// otherwise.
+    pub struct ExpandsOnNFKC(bool) {
+        abbr => "XO_NFKC";
+        long => "Expands_On_NFKC";
+        human => "Expands On NFKC";
+
+        data_table_path => "../tables/expands_on_nfkc.rsv";
+    }
+
+    /// Return `true` for characters that expand to more than one character under NFKC, `false` otherwise.
+    pub fn expands_on_nfkc(char) -> bool;
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_values() {
+        use super::expands_on_nfkc;
+
+        assert_eq!(expands_on_nfkc('\u{0041}'), false);
+        assert_eq!(expands_on_nfkc('\u{00c5}'), false);
+        assert_eq!(expands_on_nfkc('\u{ac00}'), false);
+        assert_eq!(expands_on_nfkc('\u{fb01}'), true);
+        assert_eq!(expands_on_nfkc('\u{fdfa}'), true);
+        assert_eq!(expands_on_nfkc('\u{2474}'), true);
+    }
+}
@@ -0,0 +1,45 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `Full_Composition_Exclusion` Character Property.
+
+char_property! {
+    /// Represents values of the Unicode character property
+    /// [`Full_Composition_Exclusion`](https://www.unicode.org/reports/tr44/#Full_Composition_Exclusion).
+    ///
+    /// The value is `true` for characters that are never produced by canonical composition: explicit
+    /// composition exclusions, singleton decompositions and non-starter decompositions.
+    pub struct FullCompositionExclusion(bool) {
+        abbr => "Comp_Ex";
+        long => "Full_Composition_Exclusion";
+        human => "Full Composition Exclusion";
+
+        data_table_path => "../tables/full_composition_exclusion.rsv";
+    }
+
+    /// Return `true` for characters excluded from canonical composition, `false` otherwise.
+    pub fn full_composition_exclusion(char) -> bool;
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_values() {
+        use super::full_composition_exclusion;
+
+        assert_eq!(full_composition_exclusion('\u{0041}'), false);
+        assert_eq!(full_composition_exclusion('\u{00c5}'), false);
+        assert_eq!(full_composition_exclusion('\u{0958}'), true);
+        assert_eq!(full_composition_exclusion('\u{0340}'), true);
+        assert_eq!(full_composition_exclusion('\u{0344}'), true);
+        assert_eq!(full_composition_exclusion('\u{212b}'), true);
+        assert_eq!(full_composition_exclusion('\u{2f800}'), true);
+    }
+}
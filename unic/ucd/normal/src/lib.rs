@@ -50,6 +50,7 @@ pub use crate::composition::{
     canonical_composition,
     canonical_decomposition,
     compatibility_decomposition,
+    decomposition_mapping,
 };
 
 mod decomposition;
@@ -61,6 +62,30 @@ pub use crate::gen_cat::is_combining_mark;
 mod decomposition_type;
 pub use crate::decomposition_type::DecompositionType;
 
+mod nfkc_casefold;
+pub use crate::nfkc_casefold::nfkc_casefold;
+
+pub mod nfc_quick_check;
+pub use crate::nfc_quick_check::NFCQuickCheck;
+
+pub mod composition_exclusion;
+pub use crate::composition_exclusion::{composition_exclusion, CompositionExclusion};
+
+pub mod full_composition_exclusion;
+pub use crate::full_composition_exclusion::{full_composition_exclusion, FullCompositionExclusion};
+
+pub mod expands_on_nfd;
+pub use crate::expands_on_nfd::{expands_on_nfd, ExpandsOnNFD};
+
+pub mod expands_on_nfc;
+pub use crate::expands_on_nfc::{expands_on_nfc, ExpandsOnNFC};
+
+pub mod expands_on_nfkd;
+pub use crate::expands_on_nfkd::{expands_on_nfkd, ExpandsOnNFKD};
+
+pub mod expands_on_nfkc;
+pub use crate::expands_on_nfkc::{expands_on_nfkc, ExpandsOnNFKC};
+
 use unic_ucd_hangul::compose_syllable;
 
 /// Compose two characters into a single character, if possible.
@@ -0,0 +1,129 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `NFC_Quick_Check` Character Property.
+//!
+//! ## References
+//!
+//! * <https://www.unicode.org/reports/tr44/#NFC_QC>
+//! * <https://www.unicode.org/reports/tr15/#Detecting_Normalization_Forms>
+
+use unic_char_property::TotalCharProperty;
+
+char_property! {
+    /// Represents the Unicode character
+    /// [`NFC_Quick_Check`](https://www.unicode.org/reports/tr44/#NFC_QC)
+    /// property.
+    ///
+    /// ## References
+    ///
+    /// * <https://www.unicode.org/reports/tr44/#NFC_QC>
+    /// * <https://www.unicode.org/reports/tr15/#Detecting_Normalization_Forms>
+    pub enum NFCQuickCheck {
+        abbr => "NFC_QC";
+        long => "NFC_Quick_Check";
+        human => "NFC Quick Check";
+
+        /// The character may occur in NFC, with no restrictions.
+        Yes {
+            abbr => Y,
+            long => Yes,
+            human => "Yes",
+        }
+
+        /// The character cannot ever occur in NFC.
+        No {
+            abbr => N,
+            long => No,
+            human => "No",
+        }
+
+        /// The character may occur in NFC, depending on the context, as it may compose with a
+        /// preceding character.
+        Maybe {
+            abbr => M,
+            long => Maybe,
+            human => "Maybe",
+        }
+    }
+
+    /// Abbreviated name aliases for the
+    /// [`NFC_Quick_Check`](https://www.unicode.org/reports/tr44/#NFC_QC)
+    /// property.
+    pub mod abbr_names for abbr;
+
+    /// Long name aliases for the
+    /// [`NFC_Quick_Check`](https://www.unicode.org/reports/tr44/#NFC_QC)
+    /// property.
+    pub mod long_names for long;
+}
+
+impl TotalCharProperty for NFCQuickCheck {
+    fn of(ch: char) -> Self {
+        Self::of(ch)
+    }
+}
+
+impl Default for NFCQuickCheck {
+    fn default() -> Self {
+        NFCQuickCheck::Yes
+    }
+}
+
+mod data {
+    use super::abbr_names as NFC_QC;
+    use unic_char_property::tables::CharDataTable;
+    pub const NFC_QUICK_CHECK_TABLE: CharDataTable<super::NFCQuickCheck> =
+        include!("../tables/nfc_quick_check.rsv");
+}
+
+impl NFCQuickCheck {
+    /// Find the character `NFC_Quick_Check` property value.
+    pub fn of(ch: char) -> NFCQuickCheck {
+        data::NFC_QUICK_CHECK_TABLE.find_or_default(ch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NFCQuickCheck as NFC_QC;
+
+    #[test]
+    fn test_values() {
+        // ASCII
+        assert_eq!(NFC_QC::of('\u{0000}'), NFC_QC::Yes);
+        assert_eq!(NFC_QC::of('\u{0041}'), NFC_QC::Yes);
+        assert_eq!(NFC_QC::of('\u{007f}'), NFC_QC::Yes);
+
+        // Composed and combining characters
+        assert_eq!(NFC_QC::of('\u{00c5}'), NFC_QC::Yes);
+        assert_eq!(NFC_QC::of('\u{0300}'), NFC_QC::Maybe);
+        assert_eq!(NFC_QC::of('\u{0305}'), NFC_QC::Yes);
+        assert_eq!(NFC_QC::of('\u{0340}'), NFC_QC::No);
+        assert_eq!(NFC_QC::of('\u{0344}'), NFC_QC::No);
+
+        // Singletons and composition exclusions
+        assert_eq!(NFC_QC::of('\u{212b}'), NFC_QC::No);
+        assert_eq!(NFC_QC::of('\u{2126}'), NFC_QC::No);
+        assert_eq!(NFC_QC::of('\u{0958}'), NFC_QC::No);
+        assert_eq!(NFC_QC::of('\u{fb1d}'), NFC_QC::No);
+        assert_eq!(NFC_QC::of('\u{2f800}'), NFC_QC::No);
+
+        // Hangul
+        assert_eq!(NFC_QC::of('\u{1100}'), NFC_QC::Yes);
+        assert_eq!(NFC_QC::of('\u{1161}'), NFC_QC::Maybe);
+        assert_eq!(NFC_QC::of('\u{11a8}'), NFC_QC::Maybe);
+        assert_eq!(NFC_QC::of('\u{ac00}'), NFC_QC::Yes);
+
+        // Other Planes
+        assert_eq!(NFC_QC::of('\u{10000}'), NFC_QC::Yes);
+        assert_eq!(NFC_QC::of('\u{10ffff}'), NFC_QC::Yes);
+    }
+}
@@ -0,0 +1,55 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use unic_char_property::tables::CharDataTable;
+
+const NFKC_CASEFOLD_MAPPING: CharDataTable<&[char]> =
+    include!("../tables/nfkc_casefold_mapping.rsv");
+
+/// [`NFKC_Casefold`](https://www.unicode.org/reports/tr44/#NFKC_Casefold) mapping of the
+/// character, or `None` if the character maps to itself.
+///
+/// Default ignorable code points map to an empty slice.
+///
+/// Note that, when applied to strings, the mapping of each character must be followed by NFC
+/// normalization, as characters may interact.
+pub fn nfkc_casefold(c: char) -> Option<&'static [char]> {
+    NFKC_CASEFOLD_MAPPING.find(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::nfkc_casefold;
+
+    #[test]
+    fn test_nfkc_casefold() {
+        // Identity
+        assert_eq!(nfkc_casefold('a'), None);
+        assert_eq!(nfkc_casefold('\u{e9}'), None);
+        assert_eq!(nfkc_casefold('\u{4e00}'), None);
+
+        // Case folding
+        assert_eq!(nfkc_casefold('A'), Some(&['a'][..]));
+        assert_eq!(nfkc_casefold('\u{c9}'), Some(&['\u{e9}'][..]));
+        assert_eq!(nfkc_casefold('\u{df}'), Some(&['s', 's'][..]));
+
+        // Compatibility
+        assert_eq!(nfkc_casefold('\u{2126}'), Some(&['\u{3c9}'][..]));
+        assert_eq!(nfkc_casefold('\u{ff21}'), Some(&['a'][..]));
+        assert_eq!(nfkc_casefold('\u{2160}'), Some(&['i'][..]));
+        assert_eq!(nfkc_casefold('\u{bd}'), Some(&['1', '\u{2044}', '2'][..]));
+
+        // Default ignorables
+        assert_eq!(nfkc_casefold('\u{ad}'), Some(&[][..]));
+        assert_eq!(nfkc_casefold('\u{200b}'), Some(&[][..]));
+        assert_eq!(nfkc_casefold('\u{fe0f}'), Some(&[][..]));
+        assert_eq!(nfkc_casefold('\u{e0fff}'), Some(&[][..]));
+    }
+}
@@ -35,6 +35,9 @@ pub use crate::pkg_info::{PKG_DESCRIPTION, PKG_NAME, PKG_VERSION};
 pub mod grapheme_cluster_break;
 pub use crate::grapheme_cluster_break::GraphemeClusterBreak;
 
+pub mod line_break;
+pub use crate::line_break::LineBreak;
+
 pub mod sentence_break;
 pub use crate::sentence_break::SentenceBreak;
 
@@ -0,0 +1,467 @@
+// Copyright 2017 The UNIC Project Developers.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unicode `Line_Break` Character Property.
+//!
+//! ## References
+//!
+//! * <https://www.unicode.org/reports/tr44/#Line_Break>
+//! * <https://www.unicode.org/reports/tr14/#Properties>
+
+use unic_char_property::TotalCharProperty;
+
+char_property! {
+    /// Represents the Unicode character
+    /// [`Line_Break`](https://www.unicode.org/reports/tr44/#Line_Break)
+    /// property.
+    ///
+    /// ## References
+    ///
+    /// * <https://www.unicode.org/reports/tr44/#Line_Break>
+    /// * <https://www.unicode.org/reports/tr14/#Table1>
+    pub enum LineBreak {
+        abbr => "lb";
+        long => "Line_Break";
+        human => "Line Break";
+
+        /// Cause a line break after them, such as U+2028 LINE SEPARATOR.
+        MandatoryBreak {
+            abbr => BK,
+            long => Mandatory_Break,
+            human => "Mandatory Break",
+        }
+
+        /// U+000D CARRIAGE RETURN (CR).
+        CarriageReturn {
+            abbr => CR,
+            long => Carriage_Return,
+            human => "Carriage Return",
+        }
+
+        /// U+000A LINE FEED (LF).
+        LineFeed {
+            abbr => LF,
+            long => Line_Feed,
+            human => "Line Feed",
+        }
+
+        /// Combining marks and control codes that take the class of the preceding character.
+        CombiningMark {
+            abbr => CM,
+            long => Combining_Mark,
+            human => "Attached/Combining Mark",
+        }
+
+        /// U+0085 NEXT LINE (NEL).
+        NextLine {
+            abbr => NL,
+            long => Next_Line,
+            human => "Next Line",
+        }
+
+        /// Surrogate code points, which never occur in a `char`.
+        Surrogate {
+            abbr => SG,
+            long => Surrogate,
+            human => "Surrogate",
+        }
+
+        /// Prohibit line breaks before and after them, such as U+2060 WORD JOINER.
+        WordJoiner {
+            abbr => WJ,
+            long => Word_Joiner,
+            human => "Word Joiner",
+        }
+
+        /// U+200B ZERO WIDTH SPACE, a break opportunity.
+        ZWSpace {
+            abbr => ZW,
+            long => ZWSpace,
+            human => "Zero Width Space",
+        }
+
+        /// Prohibit line breaks before and after them, such as U+00A0 NO-BREAK SPACE.
+        Glue {
+            abbr => GL,
+            long => Glue,
+            human => "Non-breaking (\"Glue\")",
+        }
+
+        /// U+0020 SPACE, which enables indirect break opportunities.
+        Space {
+            abbr => SP,
+            long => Space,
+            human => "Space",
+        }
+
+        /// U+200D ZERO WIDTH JOINER, which prohibits a line break after it.
+        ZWJ {
+            abbr => ZWJ,
+            long => ZWJ,
+            human => "Zero Width Joiner",
+        }
+
+        /// Break opportunity before and after, except between two of them, such as U+2014 EM DASH.
+        BreakBoth {
+            abbr => B2,
+            long => Break_Both,
+            human => "Break Opportunity Before and After",
+        }
+
+        /// Generally provide a break opportunity after them, such as U+2010 HYPHEN.
+        BreakAfter {
+            abbr => BA,
+            long => Break_After,
+            human => "Break After",
+        }
+
+        /// Generally provide a break opportunity before them, such as U+00B4 ACUTE ACCENT.
+        BreakBefore {
+            abbr => BB,
+            long => Break_Before,
+            human => "Break Before",
+        }
+
+        /// U+002D HYPHEN-MINUS, a break opportunity after it, except before digits.
+        Hyphen {
+            abbr => HY,
+            long => Hyphen,
+            human => "Hyphen",
+        }
+
+        /// Objects whose break opportunities are provided externally, such as U+FFFC OBJECT REPLACEMENT CHARACTER.
+        ContingentBreak {
+            abbr => CB,
+            long => Contingent_Break,
+            human => "Contingent Break Opportunity",
+        }
+
+        /// Closing punctuation, which prohibits a line break before it.
+        ClosePunctuation {
+            abbr => CL,
+            long => Close_Punctuation,
+            human => "Close Punctuation",
+        }
+
+        /// U+0029 RIGHT PARENTHESIS and U+005D RIGHT SQUARE BRACKET.
+        CloseParenthesis {
+            abbr => CP,
+            long => Close_Parenthesis,
+            human => "Close Parenthesis",
+        }
+
+        /// Prohibit a line break before them, such as U+0021 EXCLAMATION MARK.
+        Exclamation {
+            abbr => EX,
+            long => Exclamation,
+            human => "Exclamation/Interrogation",
+        }
+
+        /// Leaders, such as U+2026 HORIZONTAL ELLIPSIS, which prohibit a line break between two of them.
+        Inseparable {
+            abbr => IN,
+            long => Inseparable,
+            human => "Inseparable",
+        }
+
+        /// Characters that may not start a line, such as small kana.
+        Nonstarter {
+            abbr => NS,
+            long => Nonstarter,
+            human => "Nonstarter",
+        }
+
+        /// Opening punctuation, which prohibits a line break after it.
+        OpenPunctuation {
+            abbr => OP,
+            long => Open_Punctuation,
+            human => "Open Punctuation",
+        }
+
+        /// Ambiguous quotation marks, which behave like both opening and closing punctuation.
+        Quotation {
+            abbr => QU,
+            long => Quotation,
+            human => "Quotation",
+        }
+
+        /// Separators in numbers, such as U+002C COMMA.
+        InfixNumeric {
+            abbr => IS,
+            long => Infix_Numeric,
+            human => "Infix Numeric Separator",
+        }
+
+        /// Digits.
+        Numeric {
+            abbr => NU,
+            long => Numeric,
+            human => "Numeric",
+        }
+
+        /// Characters that follow numbers, such as U+0025 PERCENT SIGN.
+        PostfixNumeric {
+            abbr => PO,
+            long => Postfix_Numeric,
+            human => "Postfix Numeric",
+        }
+
+        /// Characters that precede numbers, such as U+0024 DOLLAR SIGN.
+        PrefixNumeric {
+            abbr => PR,
+            long => Prefix_Numeric,
+            human => "Prefix Numeric",
+        }
+
+        /// U+002F SOLIDUS, a break opportunity after it, except before digits.
+        BreakSymbols {
+            abbr => SY,
+            long => Break_Symbols,
+            human => "Symbols Allowing Break After",
+        }
+
+        /// Characters that act like `Alphabetic` or `Ideographic` depending on context.
+        Ambiguous {
+            abbr => AI,
+            long => Ambiguous,
+            human => "Ambiguous (Alphabetic or Ideographic)",
+        }
+
+        /// Ordinary alphabetic characters and symbols.
+        Alphabetic {
+            abbr => AL,
+            long => Alphabetic,
+            human => "Ordinary Alphabetic and Symbol Characters",
+        }
+
+        /// Small kana, which are `Nonstarter` in strict line breaking.
+        ConditionalJapaneseStarter {
+            abbr => CJ,
+            long => Conditional_Japanese_Starter,
+            human => "Conditional Japanese Starter",
+        }
+
+        /// Emoji that can be followed by an emoji modifier.
+        EBase {
+            abbr => EB,
+            long => E_Base,
+            human => "Emoji Base",
+        }
+
+        /// Emoji skin tone modifiers.
+        EModifier {
+            abbr => EM,
+            long => E_Modifier,
+            human => "Emoji Modifier",
+        }
+
+        /// Precomposed Hangul syllables of the form LV.
+        H2 {
+            abbr => H2,
+            long => H2,
+            human => "Hangul LV Syllable",
+        }
+
+        /// Precomposed Hangul syllables of the form LVT.
+        H3 {
+            abbr => H3,
+            long => H3,
+            human => "Hangul LVT Syllable",
+        }
+
+        /// Hebrew letters.
+        HebrewLetter {
+            abbr => HL,
+            long => Hebrew_Letter,
+            human => "Hebrew Letter",
+        }
+
+        /// Characters that allow breaks before and after them, such as CJK ideographs.
+        Ideographic {
+            abbr => ID,
+            long => Ideographic,
+            human => "Ideographic",
+        }
+
+        /// Conjoining Hangul leading consonants.
+        JL {
+            abbr => JL,
+            long => JL,
+            human => "Hangul L Jamo",
+        }
+
+        /// Conjoining Hangul vowels.
+        JV {
+            abbr => JV,
+            long => JV,
+            human => "Hangul V Jamo",
+        }
+
+        /// Conjoining Hangul trailing consonants.
+        JT {
+            abbr => JT,
+            long => JT,
+            human => "Hangul T Jamo",
+        }
+
+        /// Regional indicator symbols, which pair into flags.
+        RegionalIndicator {
+            abbr => RI,
+            long => Regional_Indicator,
+            human => "Regional Indicator",
+        }
+
+        /// Scripts written without spaces, such as Thai, which need dictionary-based breaking.
+        ComplexContext {
+            abbr => SA,
+            long => Complex_Context,
+            human => "Complex Context Dependent (South East Asian)",
+        }
+
+        /// Unassigned and private-use code points, and characters of unknown behavior.
+        Unknown {
+            abbr => XX,
+            long => Unknown,
+            human => "Unknown",
+        }
+    }
+
+    /// Abbreviated name aliases for the
+    /// [`Line_Break`](https://www.unicode.org/reports/tr44/#Line_Break)
+    /// property.
+    ///
+    /// ## See Also
+    ///
+    /// * <https://www.unicode.org/reports/tr14/#Table1>
+    pub mod abbr_names for abbr;
+
+    /// Long name aliases for the
+    /// [`Line_Break`](https://www.unicode.org/reports/tr44/#Line_Break)
+    /// property.
+    ///
+    /// ## See Also
+    ///
+    /// * <https://www.unicode.org/reports/tr14/#Table1>
+    pub mod long_names for long;
+}
+
+impl TotalCharProperty for LineBreak {
+    fn of(ch: char) -> Self {
+        Self::of(ch)
+    }
+}
+
+/// UCD/LineBreak.txt: "All code points, assigned and unassigned, that are not listed explicitly
+/// are given the value XX", apart from a few ranges that default to `ID` or `PR`.
+impl Default for LineBreak {
+    fn default() -> Self {
+        LineBreak::Unknown
+    }
+}
+
+mod data {
+    use super::abbr_names::*;
+    use unic_char_property::tables::CharDataTable;
+    pub const LINE_BREAK_TABLE: CharDataTable<super::LineBreak> =
+        include!("../tables/line_break.rsv");
+}
+
+impl LineBreak {
+    /// Find the character `Line_Break` property value.
+    pub fn of(ch: char) -> LineBreak {
+        data::LINE_BREAK_TABLE.find_or_default(ch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LineBreak as LB;
+    use unic_char_property::EnumeratedCharProperty;
+
+    #[test]
+    fn test_ascii() {
+        assert_eq!(LB::of('\u{0000}'), LB::CombiningMark);
+        assert_eq!(LB::of('\u{0009}'), LB::BreakAfter);
+        assert_eq!(LB::of('\u{000A}'), LB::LineFeed);
+        assert_eq!(LB::of('\u{000D}'), LB::CarriageReturn);
+        assert_eq!(LB::of('\u{0020}'), LB::Space);
+        assert_eq!(LB::of('\u{0021}'), LB::Exclamation);
+        assert_eq!(LB::of('\u{0022}'), LB::Quotation);
+        assert_eq!(LB::of('\u{0024}'), LB::PrefixNumeric);
+        assert_eq!(LB::of('\u{0025}'), LB::PostfixNumeric);
+        assert_eq!(LB::of('\u{0028}'), LB::OpenPunctuation);
+        assert_eq!(LB::of('\u{0029}'), LB::CloseParenthesis);
+        assert_eq!(LB::of('\u{002C}'), LB::InfixNumeric);
+        assert_eq!(LB::of('\u{002D}'), LB::Hyphen);
+        assert_eq!(LB::of('\u{002F}'), LB::BreakSymbols);
+        assert_eq!(LB::of('\u{0030}'), LB::Numeric);
+        assert_eq!(LB::of('\u{0041}'), LB::Alphabetic);
+        assert_eq!(LB::of('\u{007D}'), LB::ClosePunctuation);
+    }
+
+    #[test]
+    fn test_bmp() {
+        assert_eq!(LB::of('\u{0085}'), LB::NextLine);
+        assert_eq!(LB::of('\u{00A0}'), LB::Glue);
+        assert_eq!(LB::of('\u{00AD}'), LB::BreakAfter);
+        assert_eq!(LB::of('\u{0300}'), LB::CombiningMark);
+        assert_eq!(LB::of('\u{05D0}'), LB::HebrewLetter);
+        assert_eq!(LB::of('\u{0E01}'), LB::ComplexContext);
+        assert_eq!(LB::of('\u{1100}'), LB::JL);
+        assert_eq!(LB::of('\u{1160}'), LB::JV);
+        assert_eq!(LB::of('\u{11A8}'), LB::JT);
+        assert_eq!(LB::of('\u{1B44}'), LB::CombiningMark);
+        assert_eq!(LB::of('\u{200B}'), LB::ZWSpace);
+        assert_eq!(LB::of('\u{200D}'), LB::ZWJ);
+        assert_eq!(LB::of('\u{2014}'), LB::BreakBoth);
+        assert_eq!(LB::of('\u{2024}'), LB::Inseparable);
+        assert_eq!(LB::of('\u{2028}'), LB::MandatoryBreak);
+        assert_eq!(LB::of('\u{2060}'), LB::WordJoiner);
+        assert_eq!(LB::of('\u{20AC}'), LB::PrefixNumeric);
+        assert_eq!(LB::of('\u{20CF}'), LB::PrefixNumeric);
+        assert_eq!(LB::of('\u{261D}'), LB::EBase);
+        assert_eq!(LB::of('\u{3041}'), LB::ConditionalJapaneseStarter);
+        assert_eq!(LB::of('\u{3042}'), LB::Ideographic);
+        assert_eq!(LB::of('\u{4E00}'), LB::Ideographic);
+        assert_eq!(LB::of('\u{AC00}'), LB::H2);
+        assert_eq!(LB::of('\u{AC01}'), LB::H3);
+        assert_eq!(LB::of('\u{E000}'), LB::Unknown);
+        assert_eq!(LB::of('\u{FFFC}'), LB::ContingentBreak);
+    }
+
+    #[test]
+    fn test_smp() {
+        assert_eq!(LB::of('\u{11013}'), LB::Alphabetic);
+        assert_eq!(LB::of('\u{1F1E6}'), LB::RegionalIndicator);
+        assert_eq!(LB::of('\u{1F3FB}'), LB::EModifier);
+        assert_eq!(LB::of('\u{1F600}'), LB::Ideographic);
+        assert_eq!(LB::of('\u{1FFFD}'), LB::Ideographic);
+        assert_eq!(LB::of('\u{20000}'), LB::Ideographic);
+        assert_eq!(LB::of('\u{E0001}'), LB::CombiningMark);
+        assert_eq!(LB::of('\u{10FFFF}'), LB::Unknown);
+    }
+
+    #[test]
+    fn test_abbr_name() {
+        assert_eq!(LB::Alphabetic.abbr_name(), "AL");
+        assert_eq!(LB::ZWSpace.abbr_name(), "ZW");
+    }
+
+    #[test]
+    fn test_long_name() {
+        assert_eq!(LB::Alphabetic.long_name(), "Alphabetic");
+        assert_eq!(LB::ZWSpace.long_name(), "ZWSpace");
+    }
+
+    #[test]
+    fn test_human_name() {
+        assert_eq!(LB::Glue.human_name(), "Non-breaking (\"Glue\")");
+    }
+}
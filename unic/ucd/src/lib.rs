@@ -32,8 +32,10 @@ pub use unic_ucd_bidi as bidi;
 pub use unic_ucd_block as block;
 pub use unic_ucd_case as case;
 pub use unic_ucd_category as category;
+pub use unic_ucd_east_asian_width as east_asian_width;
 pub use unic_ucd_hangul as hangul;
 pub use unic_ucd_ident as ident;
+pub use unic_ucd_joining as joining;
 pub use unic_ucd_name as name;
 pub use unic_ucd_name_aliases as name_aliases;
 pub use unic_ucd_normal as normal;
@@ -75,13 +77,17 @@ pub use crate::category::GeneralCategory;
 
 pub use crate::common::{is_alphabetic, is_white_space, Alphabetic, WhiteSpace};
 
+pub use crate::east_asian_width::EastAsianWidth;
+
+pub use crate::joining::JoiningType;
+
 pub use crate::name::Name;
 
 pub use crate::normal::CanonicalCombiningClass;
 
 pub use crate::name_aliases::{name_aliases_of, NameAliasType};
 
-pub use crate::segment::{GraphemeClusterBreak, SentenceBreak, WordBreak};
+pub use crate::segment::{GraphemeClusterBreak, LineBreak, SentenceBreak, WordBreak};
 
 mod pkg_info;
 pub use crate::pkg_info::{PKG_DESCRIPTION, PKG_NAME, PKG_VERSION};